@@ -0,0 +1,567 @@
+# Type stubs for the cryptoparam extension module.
+#
+# Generated from the Rust sources by the stubgen module; regenerate with
+# `CRYPTOPARAM_UPDATE_STUBS=1 cargo test committed_stubs`, do not edit by hand.
+
+import os
+from decimal import Decimal
+from fractions import Fraction
+from typing import Any, Dict, List, Optional, Tuple, Union
+
+# σ arguments: any real number, or a string such as "3.19" or "16/5"
+SigmaLike = Union[float, Fraction, Decimal, str]
+
+class LweParams:
+    """LWE problem parameters"""
+
+    n: int
+    q: int
+    sigma: float
+    def __init__(self, n: int, q: int, sigma: SigmaLike) -> None: ...
+    @staticmethod
+    def kyber512_flattened() -> LweParams:
+        """Kyber-512 as plain LWE (n = 512)"""
+    @staticmethod
+    def kyber768_flattened() -> LweParams:
+        """Kyber-768 as plain LWE (n = 768)"""
+    @staticmethod
+    def kyber1024_flattened() -> LweParams:
+        """Kyber-1024 as plain LWE (n = 1024)"""
+    @staticmethod
+    def frodo640() -> LweParams:
+        """FrodoKEM-640 LWE parameters"""
+    @staticmethod
+    def tfhe_lwe_default() -> LweParams:
+        """TFHE library default LWE key (n = 630, q = 2^32)"""
+    def with_n(self, n: int) -> LweParams:
+        """Copy with `n` replaced"""
+    def with_q(self, q: int) -> LweParams:
+        """Copy with `q` replaced"""
+    def with_sigma(self, sigma: SigmaLike) -> LweParams:
+        """Copy with `sigma` replaced"""
+    def replace(self, *, n: Optional[int] = None, q: Optional[int] = None, sigma: Optional[SigmaLike] = None) -> LweParams:
+        """Copy with the given fields replaced, like `dataclasses.replace`; LweParams stays immutable so it can key dicts"""
+    def to_dict(self) -> Dict[str, Any]:
+        """{'n', 'q', 'sigma'}"""
+    @staticmethod
+    def from_dict(d: Dict[str, Any]) -> LweParams:
+        """Inverse of `to_dict`; extra keys are ignored"""
+    def __copy__(self) -> LweParams: ...
+    def __deepcopy__(self, _memo: Dict[str, Any]) -> LweParams:
+        """Same as `__copy__`: the fields are plain values"""
+    def __reduce__(self) -> Tuple[Any, Tuple[int, int, float]]:
+        """Rebuilt via the constructor, so pickles are validated on load"""
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class SecurityEstimate:
+    """Security estimation result"""
+
+    classical_bits: float
+    beta: Optional[int]
+    attack: str
+    d: int
+    m: int
+    n: int
+    q: int
+    log2_q: float
+    sigma: float
+    noise: Optional[str]
+    secret: Optional[str]
+    max_samples: Optional[int]
+    quantum_bits: Optional[float]
+    provenance: Provenance
+    warnings: List[EstimateWarning]
+    trace: Optional[List[SearchStep]]
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def _repr_html_(self) -> str:
+        """Jupyter rich display"""
+    def explain(self) -> str:
+        """Step-by-step derivation: embedding, success condition, block size, GSA slope and cost"""
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __lt__(self, other: SecurityEstimate) -> bool: ...
+    def __le__(self, other: SecurityEstimate) -> bool: ...
+    def __gt__(self, other: SecurityEstimate) -> bool: ...
+    def __ge__(self, other: SecurityEstimate) -> bool: ...
+    def __hash__(self) -> int: ...
+    def to_dict(self) -> Dict[str, Any]:
+        """Every field by name, ready for json.dumps or a DataFrame row"""
+    @staticmethod
+    def from_dict(d: Dict[str, Any]) -> SecurityEstimate:
+        """Inverse of `to_dict`; `log2_q` defaults to log2(q), a missing `provenance` to this build's core-SVP model stamped now and missing `warnings` are recomputed; `noise`, `secret`, `max_samples`, `quantum_bits` and `trace` are optional"""
+    def __copy__(self) -> SecurityEstimate: ...
+    def __deepcopy__(self, _memo: Dict[str, Any]) -> SecurityEstimate:
+        """Deep already: warnings, trace and provenance are owned Rust values, not shared Python objects"""
+    def __reduce__(self) -> Tuple[Any, Tuple[Dict[str, Any]]]:
+        """Pickled as `from_dict(to_dict())`"""
+
+class Provenance:
+    """Model configuration that produced an estimate"""
+
+    crate_version: str
+    cost_model: str
+    attacks: List[str]
+    timestamp: int
+    def to_dict(self) -> Dict[str, Any]: ...
+    def __repr__(self) -> str: ...
+
+class EstimateWarning:
+    """One caveat about an estimate; `EstimateWarning` in Python, where `Warning` is a builtin"""
+
+    code: str
+    message: str
+    def to_dict(self) -> Dict[str, Any]: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+
+class SearchStep:
+    """One candidate of the (m, β) search"""
+
+    m: int
+    d: int
+    beta: int
+    bits: float
+    def to_dict(self) -> Dict[str, Any]: ...
+    def __repr__(self) -> str: ...
+
+class CryptoParamError(ValueError): ...
+class InvalidParameters(CryptoParamError): ...
+class UnsupportedDistribution(CryptoParamError): ...
+class SearchFailed(CryptoParamError): ...
+class CryptoParamWarning(UserWarning): ...
+
+def estimate_lwe(n: int, q: int, sigma: SigmaLike, sieving: bool = False, trace: bool = False, samples: Optional[int] = None) -> SecurityEstimate:
+    """Estimate plain LWE security."""
+
+def estimate(params: Any, sieving: bool = False) -> SecurityEstimate:
+    """Estimate security from LweParams object, or a {'n', 'q', 'sigma'} dict."""
+
+def estimate_many(params: List[Any], sieving: bool = False, n_threads: Optional[int] = None) -> List[SecurityEstimate]:
+    """Estimate many parameter sets in parallel."""
+
+class Estimator:
+    """Estimation options applied to every call"""
+
+    def __init__(self, cost_model: str = "core-svp", attacks: Optional[List[str]] = None, m_max: Optional[int] = None, secret: str = "gaussian", quantum: bool = False, success_probability: float = 1.0) -> None:
+        """Configure estimates once, then call `estimate` repeatedly."""
+    @property
+    def cost_model(self) -> str: ...
+    @property
+    def attacks(self) -> List[str]: ...
+    @property
+    def m_max(self) -> Optional[int]: ...
+    @property
+    def secret(self) -> str: ...
+    @property
+    def quantum(self) -> bool: ...
+    @property
+    def success_probability(self) -> float: ...
+    def estimate(self, params: Any) -> SecurityEstimate:
+        """Estimate one parameter set, given as LweParams or (n, q, sigma)"""
+    def estimate_lwe(self, n: int, q: int, sigma: SigmaLike) -> SecurityEstimate:
+        """Estimate (n, q, sigma) given as numbers"""
+    def estimate_many(self, params: List[Any], n_threads: Optional[int] = None) -> List[SecurityEstimate]:
+        """Estimate a list of parameter sets in parallel, releasing the GIL"""
+    def __repr__(self) -> str: ...
+
+class Config:
+    """Defaults installed by a `with cryptoparam.config(...)` block"""
+
+    @property
+    def cost_model(self) -> Optional[str]: ...
+    @property
+    def quantum(self) -> bool: ...
+    def __enter__(self) -> Config: ...
+    def __exit__(self, _exc_type: Any, _exc_value: Any, _traceback: Any) -> bool: ...
+    def __repr__(self) -> str: ...
+
+def config(model: Optional[str] = None, quantum: Optional[bool] = None) -> Config:
+    """Set estimate defaults for the duration of a `with` block."""
+
+def estimate_small_secret(n: int, q: int, sigma: SigmaLike, secret: str, sieving: bool = False) -> SecurityEstimate:
+    """Estimate LWE security with a small secret."""
+
+def get_delta(beta: int) -> float:
+    """Get root Hermite factor for BKZ block size."""
+
+def get_beta(target_delta: float) -> Optional[int]:
+    """Get BKZ block size needed for target delta, None when out of reach."""
+
+def get_bkz_cost(beta: int, sieving: bool = False) -> float:
+    """Get BKZ cost in bits."""
+
+class SearchResult:
+    """Outcome of a parameter search"""
+
+    n: int
+    q: int
+    sigma: float
+    target_bits: float
+    effective_target_bits: float
+    margin: str
+    estimate: SecurityEstimate
+    h: Optional[int]
+    failure_log2: Optional[float]
+    trace: Optional[List[TraceEntry]]
+    def __repr__(self) -> str: ...
+    def trace_summary(self) -> Optional[str]:
+        """One-line summary of the audit trail, e.g. for review notes"""
+
+class TraceEntry:
+    """One candidate examined by a search"""
+
+    n: int
+    q: int
+    sigma: float
+    bits: float
+    beta: Optional[int]
+    accepted: bool
+    def __repr__(self) -> str: ...
+
+def search_n(q: int, sigma: SigmaLike, target_bits: float, margin: Optional[str] = None, sieving: bool = False, audit: bool = False, progress: Optional[Any] = None, checkpoint: Optional[Union[str, os.PathLike]] = None, resume: bool = False) -> SearchResult:
+    """Find the smallest n reaching a target security level."""
+
+def solve(vary: str, target_bits: float, n: Optional[int] = None, q: Optional[int] = None, sigma: Optional[SigmaLike] = None, margin: Optional[str] = None, sieving: bool = False, audit: bool = False, progress: Optional[Any] = None, checkpoint: Optional[Union[str, os.PathLike]] = None, resume: bool = False) -> SearchResult:
+    """Solve for one parameter so security reaches a target."""
+
+def search_joint(q_values: List[int], sigma_values: List[SigmaLike], target_bits: float, max_failure_log2: float = -128.0, message_bits: int = 256, template: str = "lwe", max_ciphertext_bytes: Optional[float] = None, max_public_key_bytes: Optional[float] = None, margin: Optional[str] = None, sieving: bool = False, progress: Optional[Any] = None, n_threads: Optional[int] = None, checkpoint: Optional[Union[str, os.PathLike]] = None, resume: bool = False) -> SearchResult:
+    """Find parameters meeting both a security target and a failure bound."""
+
+class SearchOutcome:
+    """Answer of `cryptoparam.search`: the chosen parameters and the runner-ups"""
+
+    vary: str
+    result: SearchResult
+    runner_ups: List[SearchResult]
+    @property
+    def params(self) -> LweParams: ...
+    @property
+    def estimate(self) -> SecurityEstimate: ...
+    @property
+    def bits(self) -> float: ...
+    @property
+    def target_bits(self) -> float: ...
+    @property
+    def margin(self) -> str:
+        """Margin that was applied, e.g. '+16 bits'"""
+    @property
+    def margin_bits(self) -> float:
+        """Bits above the requested target"""
+    def to_dict(self) -> Dict[str, Any]:
+        """{'vary', 'n', 'q', 'sigma', 'bits', 'target_bits', 'margin', 'margin_bits', 'failure_log2', 'runner_ups'}, runner-ups as (n, q, sigma, bits) tuples"""
+    def __repr__(self) -> str: ...
+
+def search(target_bits: float, fix: Dict[str, Any], vary: str = "n", constraints: Optional[Dict[str, Any]] = None, margin: Optional[str] = None, sieving: bool = False, runner_ups: int = 3) -> SearchOutcome:
+    """Solve for one parameter with the others fixed, Python-style."""
+
+def estimate_async(n: int, q: int, sigma: SigmaLike, sieving: bool = False, trace: bool = False, samples: Optional[int] = None) -> Any:
+    """Estimate plain LWE security without blocking the event loop."""
+
+def search_async(q: int, sigma: SigmaLike, target_bits: float, margin: Optional[str] = None, sieving: bool = False, audit: bool = False) -> Any:
+    """Find the smallest n reaching a target without blocking the event loop."""
+
+def failure_probability(n: int, q: int, sigma: SigmaLike, message_bits: int = 256) -> float:
+    """log2 of the decryption failure probability."""
+
+class AttackCost:
+    """Cost of one attack"""
+
+    attack: str
+    bits: float
+    beta: int
+    d: int
+    samples: int
+    memory_bits: float
+    def __init__(self, attack: str, bits: float, beta: int = 0, d: int = 0, samples: int = 0, memory_bits: Optional[float] = None) -> None:
+        """Cost of one attack, as returned by a registered attack's cost function; `memory_bits` defaults to the sieve memory for `beta`"""
+    def __repr__(self) -> str: ...
+
+def attacks(n: int, q: int, sigma: SigmaLike, sieving: bool = False) -> List[AttackCost]:
+    """Cost of every implemented attack on an LWE parameter set."""
+
+def register_attack(name: str, cost: Any) -> None:
+    """Register a Python function as an additional LWE attack."""
+
+def unregister_attack(name: str) -> bool:
+    """Remove a registered attack; returns whether it was registered"""
+
+class SchemeDescriptor:
+    """Parameter set with a claimed security level"""
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def problem(self) -> str: ...
+    @property
+    def n(self) -> int: ...
+    @property
+    def k(self) -> int: ...
+    @property
+    def q(self) -> int: ...
+    @property
+    def log_q(self) -> Optional[float]: ...
+    @property
+    def sigma(self) -> Optional[float]: ...
+    @property
+    def eta(self) -> Optional[int]: ...
+    @property
+    def claimed_bits(self) -> float: ...
+    @property
+    def category(self) -> Optional[int]: ...
+    @staticmethod
+    def from_dict(d: Dict[str, Any]) -> SchemeDescriptor:
+        """Descriptor from a dict with the keys of a descriptor file, checked against the schema; unknown keys are rejected"""
+    def verify(self, sieving: bool = False, model: Optional[str] = None) -> VerifyReport:
+        """Run the attack suite against the claimed level"""
+    def to_lattice_estimator(self) -> str:
+        """lattice-estimator `LWE.Parameters(...)` snippet"""
+    def to_sage_script(self, sieving: bool = False) -> str:
+        """Sage script comparing this crate with the lattice-estimator"""
+    def __copy__(self) -> SchemeDescriptor: ...
+    def __deepcopy__(self, _memo: Dict[str, Any]) -> SchemeDescriptor:
+        """Same as `__copy__`; the SIS side is owned, not shared"""
+    def __repr__(self) -> str: ...
+
+class AttackBits:
+    """Bits of one attack in the suite"""
+
+    attack: str
+    bits: float
+    beta: Optional[int]
+    def __copy__(self) -> AttackBits: ...
+    def __deepcopy__(self, _memo: Dict[str, Any]) -> AttackBits: ...
+    def __repr__(self) -> str: ...
+
+class VerifyReport:
+    """Outcome of checking a descriptor against its claim"""
+
+    name: str
+    claimed_bits: float
+    estimated_bits: float
+    margin_bits: float
+    claimed_category: Optional[int]
+    derived_category: int
+    model: str
+    passed: bool
+    attacks: List[AttackBits]
+    def __copy__(self) -> VerifyReport: ...
+    def __deepcopy__(self, _memo: Dict[str, Any]) -> VerifyReport:
+        """Same as `__copy__`; `attacks` is rebuilt, not shared"""
+    def __repr__(self) -> str: ...
+
+def load_descriptor(path: str) -> SchemeDescriptor:
+    """Load a scheme descriptor from a JSON or TOML file."""
+
+def verify(path: Any, sieving: bool = False, model: Optional[str] = None) -> VerifyReport:
+    """Verify a descriptor file's claimed security level."""
+
+def descriptor_schema() -> str:
+    """JSON Schema of the scheme-descriptor format, as a JSON string."""
+
+def import_seal(source: str) -> SchemeDescriptor:
+    """Import SEAL encryption parameters."""
+
+def import_openfhe(source: str) -> SchemeDescriptor:
+    """Import OpenFHE CCParams."""
+
+def from_lattice_estimator(snippet: str, claimed_bits: float = 128.0) -> SchemeDescriptor:
+    """Parse a lattice-estimator LWE.Parameters snippet."""
+
+def sage_script(n: int, q: int, sigma: SigmaLike, sieving: bool = False, path: Optional[str] = None) -> str:
+    """Sage script cross-checking an LWE parameter set with the lattice-estimator."""
+
+def to_markdown(estimates: List[SecurityEstimate], names: Optional[List[str]] = None) -> str:
+    """Render estimates as a GitHub-flavored markdown table."""
+
+def to_latex(estimates: List[SecurityEstimate], names: Optional[List[str]] = None, columns: Optional[List[str]] = None) -> str:
+    """Render estimates as a booktabs LaTeX table."""
+
+def html_report(n: int, q: int, sigma: SigmaLike, sieving: bool = False, path: Optional[str] = None) -> str:
+    """Generate a self-contained HTML report for an LWE parameter set."""
+
+class BgvParams:
+    """Recommended BFV/BGV parameters"""
+
+    n: int
+    t: int
+    depth: int
+    log_q: float
+    level_bits: float
+    target_bits: float
+    secure: bool
+    estimate: SecurityEstimate
+    def __repr__(self) -> str: ...
+
+def bgv_params(plaintext_bits: int, depth: int, slots: int = 0, sigma: SigmaLike = 3.19, target_bits: float = 128.0, sieving: bool = False) -> BgvParams:
+    """Recommend a BFV/BGV plaintext modulus and q budget for a depth."""
+
+class CkksParams:
+    """Recommended CKKS parameters"""
+
+    n: int
+    slots: int
+    depth: int
+    scale_bits: int
+    precision_bits: float
+    noise_bits: float
+    chain: ModulusChain
+    def __repr__(self) -> str: ...
+
+def ckks_params(precision_bits: float, slots: int, depth: int, integer_bits: int = 20, sigma: SigmaLike = 3.19, target_bits: float = 128.0, sieving: bool = False) -> CkksParams:
+    """Recommend CKKS parameters for a precision, slot count and depth."""
+
+class ModulusChain:
+    """Proposed RNS modulus chain"""
+
+    n: int
+    depth: int
+    level_bits: List[int]
+    special_bits: List[int]
+    total_log_q: float
+    target_bits: float
+    secure: bool
+    estimate: SecurityEstimate
+    def __repr__(self) -> str: ...
+
+def modulus_chain(n: int, depth: int, scale_bits: int = 40, base_bits: int = 60, special_primes: int = 1, special_bits: int = 60, sigma: SigmaLike = 3.19, target_bits: float = 128.0, sieving: bool = False) -> ModulusChain:
+    """Propose an RNS modulus chain and check its security."""
+
+def curve_n(q: int, sigma: SigmaLike, n_min: int, n_max: int, sieving: bool = False, max_points: int = ..., n_threads: Optional[int] = None) -> List[Tuple[int, float, Optional[int]]]:
+    """Security-vs-n curve with every β step resolved."""
+
+def curve_log_q(n: int, sigma: SigmaLike, log_q_min: float, log_q_max: float, sieving: bool = False, max_points: int = ..., n_threads: Optional[int] = None) -> List[Tuple[float, float, Optional[int]]]:
+    """Security-vs-log2 q curve with every β step resolved."""
+
+class HeStdCheck:
+    """Result of checking (n, log q) against the standard and the estimator"""
+
+    n: int
+    log_q: float
+    security_bits: int
+    secret: str
+    table_max_log_q: Optional[int]
+    table_ok: bool
+    estimate: SecurityEstimate
+    estimate_ok: bool
+    def __repr__(self) -> str: ...
+
+def he_standard_check(n: int, log_q: float, security_bits: int = 128, secret: str = "ternary", sieving: bool = False) -> HeStdCheck:
+    """Check (n, log q) against the HomomorphicEncryption.org standard."""
+
+def he_standard_max_log_q(n: int, security_bits: int = 128, secret: str = "ternary") -> Optional[int]:
+    """Largest log2 q the HE standard permits for ring dimension n."""
+
+class Candidate:
+    """A parameter set meeting the target, with its trade-offs"""
+
+    n: int
+    q: int
+    sigma: float
+    ciphertext_bytes: float
+    public_key_bytes: float
+    time_proxy: float
+    cost: float
+    estimate: SecurityEstimate
+    def __repr__(self) -> str: ...
+
+def optimize(q_values: List[int], sigma_values: List[SigmaLike], target_bits: float, ciphertext_weight: float = 1.0, public_key_weight: float = 0.0, time_weight: float = 0.0, top: int = 5, template: str = "lwe", max_ciphertext_bytes: Optional[float] = None, max_public_key_bytes: Optional[float] = None, margin: Optional[str] = None, sieving: bool = False, n_threads: Optional[int] = None) -> List[Candidate]:
+    """Find the cheapest parameter sets meeting a security target."""
+
+class Preset:
+    """A published parameter set"""
+
+    name: str
+    scheme: str
+    group: str
+    problem: str
+    n: int
+    k: int
+    q: int
+    eta: Optional[int]
+    sigma: float
+    nist_level: int
+    claimed_bits: float
+    sis: Optional[SisParams]
+    samples: Optional[int]
+    error_cdf: Optional[List[int]]
+    p: Optional[int]
+    def lwe_params(self) -> LweParams:
+        """Plain-LWE view of the preset"""
+    def estimate(self, sieving: bool = False) -> SecurityEstimate:
+        """Estimate the preset with this crate's model"""
+    def sis_estimate(self, sieving: bool = False) -> Optional[SisEstimate]:
+        """Estimate the SIS side, or None for encryption schemes"""
+    def __repr__(self) -> str: ...
+
+class SisParams:
+    """SIS instance, e.g. the MSIS side of a signature scheme"""
+
+    n: int
+    m: int
+    q: int
+    bound: float
+    def __repr__(self) -> str: ...
+
+class SisEstimate:
+    """SIS estimation result"""
+
+    classical_bits: float
+    beta: Optional[int]
+    d: int
+    params: SisParams
+    def __repr__(self) -> str: ...
+
+def estimate_sis(n: int, m: int, q: int, bound: float, sieving: bool = False) -> SisEstimate:
+    """Estimate SIS security."""
+
+class SnappedResult:
+    """Implementable version of a search result"""
+
+    n: int
+    q: int
+    sigma: float
+    estimate: SecurityEstimate
+    secure: bool
+    unconstrained: SearchResult
+    delta_n: int
+    delta_log_q: float
+    delta_bits: float
+    def __repr__(self) -> str: ...
+
+def snap(result: SearchResult, ring_dims: Optional[List[int]] = None, sieving: bool = False) -> SnappedResult:
+    """Snap a search result to an implementable ring dimension and NTT prime."""
+
+class SweepTable:
+    """Table of sweep results, one row per grid point"""
+
+    rows: List[SecurityEstimate]
+    def columns(self) -> Dict[str, Any]:
+        """Column-oriented view, e.g. for `pandas.DataFrame(table.columns())`"""
+    def to_pandas(self) -> Any:
+        """pandas DataFrame with one row per parameter set and attack, the `headline` column marking the attack behind each row's estimate"""
+    def to_arrow(self) -> Any:
+        """pyarrow Table with the columns of `columns()`"""
+    def to_parquet(self, path: Union[str, os.PathLike]) -> None:
+        """Write the table as Parquet to `path`"""
+    def to_csv(self, path: Optional[Union[str, os.PathLike]] = None) -> str:
+        """CSV text with stable column names; also written to `path` if given"""
+    def to_markdown(self) -> str:
+        """GitHub-flavored markdown table"""
+    def __len__(self) -> int: ...
+    def __getitem__(self, idx: int) -> SecurityEstimate: ...
+    def __repr__(self) -> str: ...
+    def _repr_html_(self) -> str:
+        """Jupyter rich display"""
+
+def sweep(n_range: List[int], q_range: List[int], sigma_range: List[SigmaLike], sieving: bool = False, checkpoint: Optional[Union[str, os.PathLike]] = None, resume: bool = False, progress: Optional[Any] = None, n_threads: Optional[int] = None) -> SweepTable:
+    """Estimate security across a parameter grid."""
+
+def estimate_lwe_batch(n: Any, q: Any, sigma: Any, sieving: bool = False, n_threads: Optional[int] = None) -> Dict[str, Any]:
+    """Estimate many parameter sets element-wise in one call."""
+
+def to_pandas(estimates: List[SecurityEstimate]) -> Any:
+    """Convert a list of estimates to a pandas DataFrame."""
@@ -0,0 +1,71 @@
+//! Awaitable wrappers for asyncio callers
+//!
+//! Each call is handed to the running event loop's default executor, so
+//! the estimate or search runs on a worker thread while the loop keeps
+//! serving other tasks. The Rust work itself runs with the GIL released.
+
+use crate::search::py_search_n;
+use crate::Sigma;
+use pyo3::prelude::*;
+use pyo3::types::{PyCFunction, PyDict, PyTuple};
+
+/// Future resolving to `job()` run on the running loop's default executor
+fn run_in_executor<'py, F, T>(py: Python<'py>, job: F) -> PyResult<&'py PyAny>
+where
+    F: Fn(Python) -> PyResult<T> + Send + 'static,
+    T: IntoPy<PyObject>,
+{
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let call = PyCFunction::new_closure(py, None, None, move |args: &PyTuple, _: Option<&PyDict>| {
+        job(args.py()).map(|r| r.into_py(args.py()))
+    })?;
+    event_loop.call_method1("run_in_executor", (py.None(), call))
+}
+
+/// Estimate plain LWE security without blocking the event loop.
+///
+/// Takes the same arguments as estimate_lwe and must be called from a
+/// running event loop.
+///
+/// Returns:
+///     Awaitable resolving to a SecurityEstimate
+///
+/// Example:
+///     >>> r = await estimate_async(512, 12289, 3.2)
+#[pyfunction]
+#[pyo3(name = "estimate_async", signature = (n, q, sigma, sieving = false, trace = false, samples = None))]
+pub fn py_estimate_async<'py>(
+    py: Python<'py>,
+    n: usize,
+    q: u64,
+    sigma: Sigma,
+    sieving: bool,
+    trace: bool,
+    samples: Option<usize>,
+) -> PyResult<&'py PyAny> {
+    run_in_executor(py, move |py| crate::estimate_lwe(py, n, q, sigma, sieving, trace, samples))
+}
+
+/// Find the smallest n reaching a target without blocking the event loop.
+///
+/// Takes the same arguments as search_n and must be called from a
+/// running event loop.
+///
+/// Returns:
+///     Awaitable resolving to a SearchResult
+///
+/// Example:
+///     >>> r = await search_async(12289, 3.2, 128)
+#[pyfunction]
+#[pyo3(name = "search_async", signature = (q, sigma, target_bits, margin = None, sieving = false, audit = false))]
+pub fn py_search_async<'py>(
+    py: Python<'py>,
+    q: u64,
+    sigma: Sigma,
+    target_bits: f64,
+    margin: Option<String>,
+    sieving: bool,
+    audit: bool,
+) -> PyResult<&'py PyAny> {
+    run_in_executor(py, move |py| py_search_n(py, q, sigma, target_bits, margin.as_deref(), sieving, audit, None, None, false))
+}
@@ -0,0 +1,833 @@
+//! Per-attack cost breakdown
+//!
+//! Every implemented attack priced separately, so the runner-up and the
+//! resources each attack needs are visible next to the headline estimate,
+//! which only reports the cheapest.
+//!
+//! Python callers can register further attacks with `register_attack`;
+//! those are priced next to the built-in ones by `attacks()` and compete
+//! for the headline in [`crate::estimator::Estimator`].
+
+use crate::cost_model::CostModel;
+use crate::descriptor::SchemeDescriptor;
+use crate::sis::{sis_core, SisParams};
+use crate::secret::SecretDist;
+use crate::distribution::{embedding_log_scale, DiscreteGaussian, Distribution};
+use crate::dual::{dual_hybrid, dual_stage};
+use crate::{delta_0, estimate_dist};
+#[cfg(feature = "python")]
+use crate::{LweParams, SecurityEstimate, Sigma};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+#[cfg(feature = "python")]
+use std::sync::Mutex;
+
+/// log2 of the sieve list size per unit of β, 2^{0.2075β} vectors
+pub const SIEVE_MEMORY_EXPONENT: f64 = 0.2075;
+
+/// Attacks [`lwe_attacks`] prices, in order
+pub const LWE_ATTACKS: &[&str] = &["primal_usvp", "dual", "dual_hybrid"];
+
+/// Cost of one attack
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttackCost {
+    pub attack: String,
+    pub bits: f64,
+    pub beta: usize,
+    /// Lattice dimension
+    pub d: usize,
+    /// LWE samples, or SIS columns, the attack uses
+    pub samples: usize,
+    /// log2 of the vectors a sieve in dimension β stores
+    pub memory_bits: f64,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AttackCost {
+    /// Cost of one attack, as returned by a registered attack's cost
+    /// function; `memory_bits` defaults to the sieve memory for `beta`
+    #[new]
+    #[pyo3(signature = (attack, bits, beta = 0, d = 0, samples = 0, memory_bits = None))]
+    fn py_new(attack: String, bits: f64, beta: usize, d: usize, samples: usize, memory_bits: Option<f64>) -> Self {
+        let memory_bits = memory_bits.unwrap_or_else(|| self::memory_bits(beta));
+        Self { attack, bits, beta, d, samples, memory_bits }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AttackCost({}: {:.1} bits, beta={}, d={}, samples={}, memory=2^{:.1})",
+            self.attack, self.bits, self.beta, self.d, self.samples, self.memory_bits
+        )
+    }
+}
+
+/// Result of pricing one attack
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttackOutcome {
+    /// The attack succeeds at this cost
+    Feasible(AttackCost),
+    /// No block size below [`crate::MAX_BETA`] succeeds with any sample
+    /// count
+    Infeasible { attack: String },
+}
+
+impl AttackOutcome {
+    pub fn attack(&self) -> &str {
+        match self {
+            Self::Feasible(c) => &c.attack,
+            Self::Infeasible { attack } => attack,
+        }
+    }
+
+    pub fn cost(&self) -> Option<&AttackCost> {
+        match self {
+            Self::Feasible(c) => Some(c),
+            Self::Infeasible { .. } => None,
+        }
+    }
+
+    pub fn into_cost(self) -> Option<AttackCost> {
+        match self {
+            Self::Feasible(c) => Some(c),
+            Self::Infeasible { .. } => None,
+        }
+    }
+
+    /// Cost in bits, infinite when the attack is infeasible
+    pub fn bits(&self) -> f64 {
+        self.cost().map_or(f64::INFINITY, |c| c.bits)
+    }
+
+    pub fn beta(&self) -> Option<usize> {
+        self.cost().map(|c| c.beta)
+    }
+
+    /// Reprice a feasible attack under `model`
+    pub fn reprice(&mut self, model: &CostModel) {
+        if let Self::Feasible(c) = self {
+            c.bits = model.cost(c.beta, c.d);
+        }
+    }
+}
+
+/// Sieving memory for block size β
+pub fn memory_bits(beta: usize) -> f64 {
+    if beta < 2 { 0.0 } else { SIEVE_MEMORY_EXPONENT * beta as f64 }
+}
+
+// ============================================================================
+// Detailed results
+// ============================================================================
+
+/// Full result of one attack. [`AttackCost`] is the flat summary every
+/// attack shares; implementors keep what is specific to theirs for
+/// detailed reports.
+pub trait AttackResult {
+    fn attack(&self) -> &str;
+
+    /// Cost in bits, infinite when the attack is infeasible
+    fn bits(&self) -> f64;
+
+    /// Flat summary; None when the attack is infeasible
+    fn cost(&self) -> Option<AttackCost>;
+
+    /// Attack-specific (label, value) rows, in report order
+    fn details(&self) -> Vec<(&'static str, String)>;
+
+    fn outcome(&self) -> AttackOutcome {
+        match self.cost() {
+            Some(c) => AttackOutcome::Feasible(c),
+            None => AttackOutcome::Infeasible { attack: self.attack().to_string() },
+        }
+    }
+}
+
+/// Primal uSVP with the details of its embedding
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimalResult {
+    pub n: usize,
+    /// LWE samples embedded
+    pub m: usize,
+    /// Lattice dimension m + n
+    pub d: usize,
+    pub beta: Option<usize>,
+    pub bits: f64,
+    /// ln ν of the Bai–Galbraith secret scaling; 0 for the plain Kannan
+    /// embedding
+    pub log_scale: f64,
+    /// Root Hermite factor BKZ-β reaches
+    pub delta: Option<f64>,
+    pub secret: String,
+    pub error: String,
+    pub max_samples: Option<usize>,
+}
+
+impl AttackResult for PrimalResult {
+    fn attack(&self) -> &str {
+        "primal_usvp"
+    }
+
+    fn bits(&self) -> f64 {
+        self.bits
+    }
+
+    fn cost(&self) -> Option<AttackCost> {
+        self.beta.map(|beta| AttackCost {
+            attack: self.attack().to_string(),
+            bits: self.bits,
+            beta,
+            d: self.d,
+            samples: self.m,
+            memory_bits: memory_bits(beta),
+        })
+    }
+
+    fn details(&self) -> Vec<(&'static str, String)> {
+        let embedding = if self.log_scale > 0.0 {
+            format!("Bai–Galbraith, ν = {:.4}", self.log_scale.exp())
+        } else {
+            "Kannan".to_string()
+        };
+        let samples = match self.max_samples {
+            Some(k) => format!("{} of ≤{}", self.m, k),
+            None => self.m.to_string(),
+        };
+        vec![
+            ("embedding", embedding),
+            ("secret", self.secret.clone()),
+            ("error", self.error.clone()),
+            ("samples m", samples),
+            ("dimension d", self.d.to_string()),
+            ("β", crate::report::beta_cell(self.beta)),
+            ("δ₀", self.delta.map_or("—".to_string(), |d| format!("{:.6}", d))),
+            ("memory", self.beta.map_or("—".to_string(), |b| format!("2^{:.1}", memory_bits(b)))),
+        ]
+    }
+}
+
+/// Primal-uSVP attack on (n, q = 2^log2_q) with the secret and error
+/// drawn from the given distributions and at most `max_samples` samples
+pub fn primal_usvp_result<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> PrimalResult
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let r = estimate_dist(n, log2_q, secret, error, max_samples, sieving);
+    PrimalResult {
+        n,
+        m: r.m,
+        d: r.d,
+        beta: r.beta,
+        bits: r.classical_bits,
+        log_scale: embedding_log_scale(n, secret, error),
+        delta: r.beta.map(delta_0),
+        secret: secret.name(),
+        error: error.name(),
+        max_samples,
+    }
+}
+
+/// Dual attack with the details of its distinguisher
+#[derive(Debug, Clone, PartialEq)]
+pub struct DualResult {
+    pub n: usize,
+    /// LWE samples embedded
+    pub m: usize,
+    /// Lattice dimension m + n
+    pub d: usize,
+    pub beta: Option<usize>,
+    /// log2 of the distinguishing advantage ε of one short vector
+    pub log2_advantage: f64,
+    pub bits: f64,
+    pub secret: String,
+    pub error: String,
+    pub max_samples: Option<usize>,
+}
+
+impl DualResult {
+    /// log2 of the 1/ε² short vectors the distinguisher needs
+    pub fn vectors_bits(&self) -> f64 {
+        -2.0 * self.log2_advantage
+    }
+}
+
+impl AttackResult for DualResult {
+    fn attack(&self) -> &str {
+        "dual"
+    }
+
+    fn bits(&self) -> f64 {
+        self.bits
+    }
+
+    fn cost(&self) -> Option<AttackCost> {
+        self.beta.map(|beta| AttackCost {
+            attack: self.attack().to_string(),
+            bits: self.bits,
+            beta,
+            d: self.d,
+            samples: self.m,
+            memory_bits: memory_bits(beta),
+        })
+    }
+
+    fn details(&self) -> Vec<(&'static str, String)> {
+        let samples = match self.max_samples {
+            Some(k) => format!("{} of ≤{}", self.m, k),
+            None => self.m.to_string(),
+        };
+        let feasible = |s: String| if self.beta.is_some() { s } else { "—".to_string() };
+        vec![
+            ("secret", self.secret.clone()),
+            ("error", self.error.clone()),
+            ("samples m", samples),
+            ("dimension d", self.d.to_string()),
+            ("β", crate::report::beta_cell(self.beta)),
+            ("advantage ε", feasible(format!("2^{:.1}", self.log2_advantage))),
+            ("vectors", feasible(format!("2^{:.1}", self.vectors_bits()))),
+            ("memory", self.beta.map_or("—".to_string(), |b| format!("2^{:.1}", memory_bits(b)))),
+        ]
+    }
+}
+
+/// Dual attack on (n, q = 2^log2_q) with the secret and error drawn from
+/// the given distributions and at most `max_samples` samples
+pub fn dual_result<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> DualResult
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let r = dual_stage(n, log2_q, error.variance(n), secret.variance(n), max_samples, sieving);
+    DualResult {
+        n,
+        m: r.m,
+        d: r.d,
+        beta: r.beta,
+        log2_advantage: r.log2_advantage,
+        bits: r.bits,
+        secret: secret.name(),
+        error: error.name(),
+        max_samples,
+    }
+}
+
+/// Dual-hybrid attack with the details of its guess and distinguisher
+#[derive(Debug, Clone, PartialEq)]
+pub struct HybridResult {
+    pub n: usize,
+    /// Secret coordinates guessed; 0 for the plain dual attack
+    pub guessed: usize,
+    /// log2 of the candidates for the guessed coordinates
+    pub guess_bits: f64,
+    /// log2 of the half-guesses the meet-in-the-middle stores
+    pub mitm_memory_bits: f64,
+    /// LWE samples embedded
+    pub m: usize,
+    /// Lattice dimension m + n − guessed
+    pub d: usize,
+    pub beta: Option<usize>,
+    /// log2 of the distinguishing advantage ε of one short vector
+    pub log2_advantage: f64,
+    pub bits: f64,
+    pub secret: String,
+    pub error: String,
+    pub max_samples: Option<usize>,
+}
+
+impl AttackResult for HybridResult {
+    fn attack(&self) -> &str {
+        "dual_hybrid"
+    }
+
+    fn bits(&self) -> f64 {
+        self.bits
+    }
+
+    /// Repricing under another model changes the lattice stage only; the
+    /// guessing cost stays in `bits` here
+    fn cost(&self) -> Option<AttackCost> {
+        self.beta.map(|beta| AttackCost {
+            attack: self.attack().to_string(),
+            bits: self.bits,
+            beta,
+            d: self.d,
+            samples: self.m,
+            memory_bits: memory_bits(beta).max(self.mitm_memory_bits),
+        })
+    }
+
+    fn details(&self) -> Vec<(&'static str, String)> {
+        let samples = match self.max_samples {
+            Some(k) => format!("{} of ≤{}", self.m, k),
+            None => self.m.to_string(),
+        };
+        let pow = |x: f64| if x.is_finite() { format!("2^{:.1}", x) } else { "—".to_string() };
+        vec![
+            ("secret", self.secret.clone()),
+            ("error", self.error.clone()),
+            ("guessed", format!("{} of {} coordinates", self.guessed, self.n)),
+            ("candidates", pow(self.guess_bits)),
+            ("MITM memory", pow(self.mitm_memory_bits)),
+            ("samples m", samples),
+            ("dimension d", self.d.to_string()),
+            ("β", crate::report::beta_cell(self.beta)),
+            ("advantage ε", self.beta.map_or("—".to_string(), |_| pow(self.log2_advantage))),
+            ("memory", self.cost().map_or("—".to_string(), |c| pow(c.memory_bits))),
+        ]
+    }
+}
+
+/// Dual-hybrid attack on (n, q = 2^log2_q) with the secret and error drawn
+/// from the given distributions and at most `max_samples` samples
+pub fn dual_hybrid_result<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> HybridResult
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let r = dual_hybrid(n, log2_q, secret, error, max_samples, sieving);
+    HybridResult {
+        n,
+        guessed: r.guessed,
+        guess_bits: r.guess_bits,
+        mitm_memory_bits: r.mitm_memory_bits(),
+        m: r.stage.m,
+        d: r.stage.d,
+        beta: r.stage.beta,
+        log2_advantage: r.stage.log2_advantage,
+        bits: r.bits,
+        secret: secret.name(),
+        error: error.name(),
+        max_samples,
+    }
+}
+
+/// [`primal_usvp_result`] as an [`AttackOutcome`]
+pub fn primal_usvp_outcome<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> AttackOutcome
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    primal_usvp_result(n, log2_q, secret, error, max_samples, sieving).outcome()
+}
+
+/// Every implemented LWE attack in full, in [`LWE_ATTACKS`] order
+pub fn lwe_attack_results<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> Vec<Box<dyn AttackResult>>
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    vec![
+        Box::new(primal_usvp_result(n, log2_q, secret, error, max_samples, sieving)),
+        Box::new(dual_result(n, log2_q, secret, error, max_samples, sieving)),
+        Box::new(dual_hybrid_result(n, log2_q, secret, error, max_samples, sieving)),
+    ]
+}
+
+/// Text block per attack listing its [`AttackResult::details`]
+pub fn attack_details(results: &[Box<dyn AttackResult>]) -> String {
+    let mut out = String::new();
+    for r in results {
+        out.push_str(r.attack());
+        out.push('\n');
+        let rows = r.details();
+        let width = rows.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+        for (label, value) in rows {
+            out.push_str(&format!("  {}{}  {}\n", label, " ".repeat(width - label.chars().count()), value));
+        }
+    }
+    out
+}
+
+/// Every implemented LWE attack on (n, q = 2^log2_q, σ): primal uSVP, dual
+/// and dual-hybrid
+pub fn lwe_attacks(n: usize, log2_q: f64, sigma: f64, sieving: bool) -> Vec<AttackOutcome> {
+    lwe_attacks_with(n, log2_q, sigma, SecretDist::Gaussian, None, sieving)
+}
+
+/// [`lwe_attacks`] with the secret drawn from `secret` and at most
+/// `max_samples` samples
+pub fn lwe_attacks_with(
+    n: usize,
+    log2_q: f64,
+    sigma: f64,
+    secret: SecretDist,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> Vec<AttackOutcome> {
+    lwe_attacks_dist(n, log2_q, &*secret.resolve(sigma), &DiscreteGaussian::new(sigma), max_samples, sieving)
+}
+
+/// Every implemented LWE attack with the secret and error drawn from the
+/// given distributions; new distributions need only implement
+/// [`Distribution`]
+pub fn lwe_attacks_dist<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> Vec<AttackOutcome>
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    lwe_attack_results(n, log2_q, secret, error, max_samples, sieving).iter().map(|r| r.outcome()).collect()
+}
+
+/// [`LWE_ATTACKS`] followed by the attacks registered from Python
+pub fn attack_names() -> Vec<String> {
+    let names = LWE_ATTACKS.iter().map(|a| a.to_string());
+    #[cfg(feature = "python")]
+    let names = names.chain(PLUGINS.lock().unwrap().iter().map(|p| p.name.clone()).collect::<Vec<_>>());
+    names.collect()
+}
+
+/// Check an attack selection: a name from [`attack_names`] or "all"
+pub fn check_attack(name: &str) -> Result<(), String> {
+    let names = attack_names();
+    if name == "all" || names.iter().any(|a| a == name) {
+        Ok(())
+    } else {
+        Err(format!("attack '{}' is not implemented (available: {}, all)", name, names.join(", ")))
+    }
+}
+
+/// LWE attacks plus, for signatures, the SIS attack on the descriptor
+pub fn descriptor_attacks(d: &SchemeDescriptor, sieving: bool) -> Vec<AttackOutcome> {
+    let mut costs = lwe_attacks(d.dimension(), d.log2_q(), d.error_sigma(), sieving);
+    if let Some(s) = &d.sis {
+        let r = sis_core(&SisParams { n: s.n, m: s.m, q: s.q, bound: s.bound }, sieving);
+        costs.push(match r.beta {
+            Some(beta) => AttackOutcome::Feasible(AttackCost {
+                attack: "sis".to_string(),
+                bits: r.classical_bits,
+                beta,
+                d: r.d,
+                samples: r.d,
+                memory_bits: memory_bits(beta),
+            }),
+            None => AttackOutcome::Infeasible { attack: "sis".to_string() },
+        });
+    }
+    costs
+}
+
+/// Index of the cheapest feasible attack
+pub fn cheapest(costs: &[AttackOutcome]) -> Option<usize> {
+    (0..costs.len()).filter(|&i| costs[i].cost().is_some()).min_by(|&a, &b| costs[a].bits().total_cmp(&costs[b].bits()))
+}
+
+/// Text table with one row per attack; the cheapest is marked with `*`
+/// and infeasible ones show ∞ bits and no parameters
+pub fn attack_table(costs: &[AttackOutcome]) -> String {
+    let finite = |x: f64, prec: usize| if x.is_finite() { format!("{:.*}", prec, x) } else { "∞".to_string() };
+    let best = cheapest(costs);
+    let mut rows = vec![["".to_string(), "attack".into(), "bits".into(), "β".into(), "d".into(), "samples".into(), "memory".into()]];
+    for (i, o) in costs.iter().enumerate() {
+        let mark = if Some(i) == best { "*".to_string() } else { String::new() };
+        rows.push(match o.cost() {
+            Some(c) => [
+                mark,
+                c.attack.clone(),
+                finite(c.bits, 1),
+                c.beta.to_string(),
+                c.d.to_string(),
+                c.samples.to_string(),
+                if c.memory_bits.is_finite() { format!("2^{:.1}", c.memory_bits) } else { "∞".to_string() },
+            ],
+            None => [mark, o.attack().to_string(), "∞".into(), "—".into(), "—".into(), "—".into(), "—".into()],
+        });
+    }
+    let widths: Vec<usize> = (0..7).map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0)).collect();
+    let mut out = String::new();
+    for r in &rows {
+        let cells: Vec<String> = r
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(c, (cell, &w))| {
+                let pad = " ".repeat(w - cell.chars().count());
+                if c < 2 { format!("{}{}", cell, pad) } else { format!("{}{}", pad, cell) }
+            })
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Attack registered from Python: a name and a cost function
+#[cfg(feature = "python")]
+struct Plugin {
+    name: String,
+    cost: PyObject,
+}
+
+#[cfg(feature = "python")]
+static PLUGINS: Mutex<Vec<Plugin>> = Mutex::new(Vec::new());
+
+/// Price every registered attack among `selected` on `params`. The cost
+/// functions are called as `cost(params, config)` and return an
+/// AttackCost or bare bits; the registered name replaces whatever attack
+/// name they report.
+#[cfg(feature = "python")]
+pub(crate) fn plugin_costs(
+    py: Python,
+    params: LweParams,
+    config: &PyDict,
+    selected: Option<&[String]>,
+) -> PyResult<Vec<AttackCost>> {
+    // Snapshot first: a cost function may itself register attacks
+    let plugins: Vec<(String, PyObject)> = PLUGINS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|p| selected.is_none_or(|s| s.contains(&p.name)))
+        .map(|p| (p.name.clone(), p.cost.clone_ref(py)))
+        .collect();
+    plugins
+        .into_iter()
+        .map(|(name, cost)| {
+            let out = cost.call1(py, (params.clone(), config))?;
+            let mut c = match out.extract::<AttackCost>(py) {
+                Ok(c) => c,
+                Err(_) => {
+                    let bits: f64 = out.extract(py).map_err(|_| {
+                        pyo3::exceptions::PyTypeError::new_err(format!(
+                            "attack '{}' must return an AttackCost or a number of bits",
+                            name
+                        ))
+                    })?;
+                    AttackCost { attack: String::new(), bits, beta: 0, d: 0, samples: 0, memory_bits: memory_bits(0) }
+                }
+            };
+            c.attack = name;
+            Ok(c)
+        })
+        .collect()
+}
+
+/// Let the registered attacks among `selected` compete with the primal
+/// estimate `r`: the cheapest one, if cheaper, or if primal_usvp is not
+/// selected, takes over the headline. Quantum bits are repriced from the
+/// winner's β, and dropped when it reports none.
+#[cfg(feature = "python")]
+pub(crate) fn apply_plugins(py: Python, r: &mut SecurityEstimate, config: &PyDict, selected: &[String]) -> PyResult<()> {
+    let params = LweParams { n: r.n, q: r.q, sigma: r.sigma };
+    let costs = plugin_costs(py, params, config, Some(selected))?;
+    let Some(best) = costs.iter().min_by(|a, b| a.bits.total_cmp(&b.bits)) else {
+        return Ok(());
+    };
+    let primal = selected.iter().any(|a| a == "primal_usvp");
+    if primal && best.bits >= r.classical_bits {
+        return Ok(());
+    }
+    r.attack = best.attack.clone();
+    r.classical_bits = best.bits;
+    (r.beta, r.d, r.m) = (Some(best.beta), best.d, best.samples);
+    if r.quantum_bits.is_some() {
+        r.quantum_bits = None;
+        if best.beta >= 2 {
+            r.add_quantum();
+        }
+    }
+    Ok(())
+}
+
+/// Register a Python function as an additional LWE attack.
+///
+/// The function is called as cost(params, config), params being an
+/// LweParams and config a dict with "cost_model", "secret" and "m_max",
+/// and returns an AttackCost or the attack's cost in bits. Registering a
+/// name again replaces the earlier function.
+///
+/// Args:
+///     name: Attack name, as accepted by attacks= selections
+///     cost: Cost function
+///
+/// Example:
+///     >>> register_attack("toy", lambda p, cfg: p.n / 4)
+///     >>> [c.attack for c in attacks(512, 12289, 3.2)]  # [..., "toy"]
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "register_attack")]
+pub fn py_register_attack(py: Python, name: &str, cost: PyObject) -> PyResult<()> {
+    if name.is_empty() || name == "all" || name == "sis" || LWE_ATTACKS.contains(&name) {
+        return Err(crate::errors::InvalidParameters::new_err(format!("attack name '{}' is reserved", name)));
+    }
+    if !cost.as_ref(py).is_callable() {
+        return Err(pyo3::exceptions::PyTypeError::new_err("cost must be callable"));
+    }
+    let mut plugins = PLUGINS.lock().unwrap();
+    match plugins.iter_mut().find(|p| p.name == name) {
+        Some(p) => p.cost = cost,
+        None => plugins.push(Plugin { name: name.to_string(), cost }),
+    }
+    Ok(())
+}
+
+/// Remove a registered attack; returns whether it was registered
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "unregister_attack")]
+pub fn py_unregister_attack(name: &str) -> bool {
+    let mut plugins = PLUGINS.lock().unwrap();
+    let before = plugins.len();
+    plugins.retain(|p| p.name != name);
+    plugins.len() < before
+}
+
+/// Cost of every implemented attack on an LWE parameter set.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     List of AttackCost, built-in attacks first, then registered ones;
+///     built-in attacks that are infeasible are left out
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "attacks", signature = (n, q, sigma, sieving = false))]
+pub fn py_attacks(py: Python, n: usize, q: u64, sigma: Sigma, sieving: bool) -> PyResult<Vec<AttackCost>> {
+    let sigma = sigma.0;
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, q >= 2 and sigma > 0"));
+    }
+    let mut costs: Vec<AttackCost> =
+        lwe_attacks(n, (q as f64).log2(), sigma, sieving).into_iter().filter_map(AttackOutcome::into_cost).collect();
+    let config = PyDict::new(py);
+    config.set_item("cost_model", crate::cost_model::for_sieving(sieving).name)?;
+    config.set_item("secret", "gaussian")?;
+    config.set_item("m_max", py.None())?;
+    costs.extend(plugin_costs(py, LweParams { n, q, sigma }, config, None)?);
+    Ok(costs)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets;
+
+    #[test]
+    fn test_attack_table_marks_cheapest() {
+        let costs = descriptor_attacks(&SchemeDescriptor::from_preset(&presets::dilithium2()), false);
+        assert_eq!(costs.iter().map(|c| c.attack()).collect::<Vec<_>>(), ["primal_usvp", "dual", "dual_hybrid", "sis"]);
+        let best = cheapest(&costs).unwrap();
+        let table = attack_table(&costs);
+        let marked: Vec<&str> = table.lines().filter(|l| l.starts_with('*')).collect();
+        assert_eq!(marked.len(), 1);
+        assert!(marked[0].contains(costs[best].attack()));
+        assert_eq!(memory_bits(200), SIEVE_MEMORY_EXPONENT * 200.0);
+    }
+
+    #[test]
+    fn test_check_attack() {
+        assert!(check_attack("all").is_ok());
+        assert!(LWE_ATTACKS.iter().all(|a| check_attack(a).is_ok()));
+        let costs = lwe_attacks(256, 7681f64.log2(), 8.0, false);
+        assert_eq!(costs.iter().map(|c| c.attack()).collect::<Vec<_>>(), LWE_ATTACKS);
+        assert!(check_attack("bkw").unwrap_err().contains("primal_usvp, dual, dual_hybrid"));
+    }
+
+    #[test]
+    fn test_dual_hybrid_against_primal() {
+        // Where the primal embedding is tight the two attacks land close
+        let costs = lwe_attacks(256, 7681f64.log2(), 8.0, false);
+        let (primal, dual) = (costs[0].cost().unwrap(), costs[2].cost().unwrap());
+        assert!(primal.bits < dual.bits && dual.bits < primal.bits + 10.0, "{} vs {}", primal.bits, dual.bits);
+        assert!(dual.d > primal.d);
+
+        // Guessing pays off on a ternary secret but costs the primal nothing
+        let error = DiscreteGaussian::new(3.2);
+        let ternary = dual_hybrid_result(1024, 12289f64.log2(), &crate::distribution::UniformInt::new(-1, 1), &error, None, false);
+        let gaussian = dual_hybrid_result(1024, 12289f64.log2(), &error, &error, None, false);
+        assert!(ternary.guessed > 0 && ternary.bits < gaussian.bits);
+        assert!(ternary.bits < lwe_attacks(1024, 12289f64.log2(), 3.2, false)[0].bits());
+        assert_eq!(ternary.cost().unwrap().memory_bits, memory_bits(ternary.beta.unwrap()).max(ternary.mitm_memory_bits));
+    }
+
+    #[test]
+    fn test_dual_result_keeps_distinguisher() {
+        let error = DiscreteGaussian::new(3.2);
+        let r = dual_result(512, 12289f64.log2(), &error, &error, Some(600), false);
+        assert!(r.m <= 600 && r.d == r.m + r.n);
+        // One sieve call yields the 1/ε² vectors the distinguisher needs
+        assert!(r.vectors_bits() <= memory_bits(r.beta.unwrap()));
+        let hybrid = dual_hybrid_result(512, 12289f64.log2(), &error, &error, Some(600), false);
+        assert!(hybrid.bits <= r.bits);
+
+        let results = lwe_attack_results(512, 12289f64.log2(), &error, &error, Some(600), false);
+        let details = attack_details(&results);
+        assert!(details.contains("\ndual\n") && details.contains(&format!("{} of ≤600", r.m)));
+        assert!(details.contains(&format!("2^{:.1}", r.log2_advantage)));
+        assert!(details.contains("MITM memory"));
+    }
+
+    #[test]
+    fn test_primal_result_keeps_embedding() {
+        let error = DiscreteGaussian::new(3.2);
+        let secret = crate::distribution::UniformInt::new(-1, 1);
+        let r = primal_usvp_result(512, 12289f64.log2(), &secret, &error, Some(600), false);
+        assert!(r.log_scale > 0.0 && r.d == r.m + r.n);
+        assert_eq!(r.outcome(), lwe_attacks_with(512, 12289f64.log2(), 3.2, SecretDist::Ternary, Some(600), false).remove(0));
+
+        let results = lwe_attack_results(512, 12289f64.log2(), &secret, &error, Some(600), false);
+        let details = attack_details(&results);
+        assert!(details.starts_with("primal_usvp\n") && details.contains("Bai–Galbraith"));
+        assert!(details.contains(&format!("{} of ≤600", r.m)));
+        let kannan = primal_usvp_result(512, 12289f64.log2(), &error, &error, None, false);
+        assert_eq!(kannan.details()[0], ("embedding", "Kannan".to_string()));
+    }
+}
@@ -0,0 +1,151 @@
+//! Diffing a fresh estimate against a saved one
+//!
+//! `cryptoparam estimate ... --format json > saved.json` records a result;
+//! a later `--baseline saved.json` finds the entry with the same (n, q, σ)
+//! and reports how far the numbers moved, so a model update that shifts
+//! them is noticed.
+
+use crate::error::CryptoParamError;
+use crate::report::beta_cell;
+use crate::SecurityEstimate;
+use serde::Deserialize;
+use std::path::Path;
+
+/// One saved result, as written by the JSON output format
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Baseline {
+    pub n: usize,
+    pub log2_q: f64,
+    pub sigma: f64,
+    pub attack: String,
+    /// `null` when no attack is feasible
+    pub beta: Option<usize>,
+    /// `null` for infinite security
+    pub classical_bits: Option<f64>,
+    #[serde(default)]
+    pub quantum_bits: Option<f64>,
+}
+
+impl Baseline {
+    /// Parse a JSON object, an array of them, or one object per line
+    pub fn from_json(s: &str) -> Result<Vec<Self>, CryptoParamError> {
+        let parse = |s: &str| -> Result<Vec<Self>, serde_json::Error> {
+            match serde_json::from_str::<serde_json::Value>(s)? {
+                serde_json::Value::Array(rows) => rows.into_iter().map(serde_json::from_value).collect(),
+                row => Ok(vec![serde_json::from_value(row)?]),
+            }
+        };
+        let rows = parse(s).or_else(|e| {
+            let lines: Vec<&str> = s.lines().filter(|l| !l.trim().is_empty()).collect();
+            match lines.len() {
+                0 | 1 => Err(e),
+                _ => lines.into_iter().map(|l| parse(l).map(|mut r| r.remove(0))).collect(),
+            }
+        })
+        .map_err(|e| CryptoParamError::InvalidInput(format!("invalid baseline JSON: {}", e)))?;
+        // Files saved by older releases mark "no attack" with β = MAX_BETA
+        Ok(rows.into_iter().map(|b| Self { beta: b.beta.filter(|&b| b < crate::MAX_BETA), ..b }).collect())
+    }
+
+    pub fn load(path: &Path) -> Result<Vec<Self>, CryptoParamError> {
+        let in_file = |e: &dyn std::fmt::Display| CryptoParamError::InvalidInput(format!("{}: {}", path.display(), e));
+        let text = std::fs::read_to_string(path).map_err(|e| in_file(&e))?;
+        Self::from_json(&text).map_err(|e| in_file(&e))
+    }
+
+    fn bits(&self) -> f64 {
+        self.classical_bits.unwrap_or(f64::INFINITY)
+    }
+
+    /// Whether this entry was saved for the parameters of `r`
+    pub fn matches(&self, r: &SecurityEstimate) -> bool {
+        self.n == r.n && (self.log2_q - r.log2_q).abs() < 1e-3 && self.sigma == r.sigma
+    }
+}
+
+/// Entry of `baselines` saved for the parameters of `r`
+pub fn find<'a>(baselines: &'a [Baseline], r: &SecurityEstimate) -> Result<&'a Baseline, CryptoParamError> {
+    baselines.iter().find(|b| b.matches(r)).ok_or_else(|| {
+        CryptoParamError::InvalidInput(format!("no baseline entry for n={}, log2 q={:.1}, σ={}", r.n, r.log2_q, r.sigma))
+    })
+}
+
+fn drift(then: f64, now: f64) -> f64 {
+    if then == now { 0.0 } else { (now - then).abs() }
+}
+
+/// Largest change in bits between `b` and `r`, classical or quantum when
+/// both carry it; infinite when only one side is infinite
+pub fn bits_drift(b: &Baseline, r: &SecurityEstimate) -> f64 {
+    let quantum = match (b.quantum_bits, r.quantum_bits) {
+        (Some(then), Some(now)) => drift(then, now),
+        _ => 0.0,
+    };
+    drift(b.bits(), r.classical_bits).max(quantum)
+}
+
+/// Text table of baseline, current and Δ for bits, quantum bits, β and
+/// the cheapest attack
+pub fn diff_table(b: &Baseline, r: &SecurityEstimate) -> String {
+    let bits = |x: f64| if x.is_finite() { format!("{:.1}", x) } else { "∞".to_string() };
+    let delta = |x: f64, y: f64| if x.is_finite() && y.is_finite() { format!("{:+.1}", y - x) } else { "—".to_string() };
+    let mut rows: Vec<[String; 4]> = vec![
+        ["".to_string(), "baseline".to_string(), "current".to_string(), "Δ".to_string()],
+        ["bits".to_string(), bits(b.bits()), bits(r.classical_bits), delta(b.bits(), r.classical_bits)],
+    ];
+    if let (Some(then), Some(now)) = (b.quantum_bits, r.quantum_bits) {
+        rows.push(["quantum".to_string(), bits(then), bits(now), delta(then, now)]);
+    }
+    let beta_delta = match (b.beta, r.beta) {
+        (Some(then), Some(now)) => format!("{:+}", now as i64 - then as i64),
+        _ => "—".to_string(),
+    };
+    rows.push(["β".to_string(), beta_cell(b.beta), beta_cell(r.beta), beta_delta]);
+    let changed = if b.attack == r.attack { "" } else { "changed" };
+    rows.push(["attack".to_string(), b.attack.clone(), r.attack.clone(), changed.to_string()]);
+    let widths: Vec<usize> = (0..4).map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0)).collect();
+    let mut out = String::new();
+    for row in &rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(c, (cell, &w))| {
+                let pad = w - cell.chars().count();
+                if c == 0 { format!("{}{}", cell, " ".repeat(pad)) } else { format!("{}{}", " ".repeat(pad), cell) }
+            })
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core;
+    use crate::sweep::to_json;
+
+    #[test]
+    fn test_diff_against_saved_json() {
+        let r = estimate_core(256, 7681, 8.0, false);
+        let saved = Baseline::from_json(&to_json(&[estimate_core(512, 7681, 8.0, false), r.clone()])).unwrap();
+        let b = find(&saved, &r).unwrap();
+        assert_eq!(bits_drift(b, &r), 0.0);
+        assert!(diff_table(b, &r).lines().nth(1).unwrap().ends_with("73.0  +0.0"));
+
+        let mut moved = r.clone();
+        moved.classical_bits -= 2.5;
+        assert_eq!(bits_drift(b, &moved), 2.5);
+        assert!(find(&saved, &estimate_core(128, 7681, 8.0, false)).unwrap_err().to_string().contains("n=128"));
+
+        let lines = format!("{}\n{}\n", crate::sweep::json_row(&r), crate::sweep::json_row(&moved));
+        assert_eq!(Baseline::from_json(&lines).unwrap().len(), 2);
+        assert!(Baseline::from_json("{\"n\": 1}").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Micro-benchmarks behind `cryptoparam bench`
+//!
+//! Times the estimator's hot paths on representative parameter sizes, so
+//! a slow machine or a regression shows up as a number rather than a
+//! feeling.
+
+use cryptoparam::estimate_core;
+use cryptoparam::search::{solve, SearchParam, SecurityMargin};
+use cryptoparam::sweep::sweep;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Most iterations of one case, however cheap
+const MAX_ITERATIONS: usize = 100_000;
+
+/// Timing of one benchmark case
+pub struct Timing {
+    pub name: String,
+    pub iterations: usize,
+    pub mean: Duration,
+    pub min: Duration,
+}
+
+/// Run `f` repeatedly until `budget` is spent, at least once
+pub fn time(name: &str, budget: Duration, mut f: impl FnMut()) -> Timing {
+    let start = Instant::now();
+    let (mut iterations, mut min) = (0, Duration::MAX);
+    while iterations == 0 || (start.elapsed() < budget && iterations < MAX_ITERATIONS) {
+        let t = Instant::now();
+        f();
+        min = min.min(t.elapsed());
+        iterations += 1;
+    }
+    Timing { name: name.to_string(), iterations, mean: start.elapsed() / iterations as u32, min }
+}
+
+/// Every case: single estimates by dimension, a search for n and a sweep
+pub fn run(budget: Duration) -> Vec<Timing> {
+    let mut out = Vec::new();
+    for n in [256, 512, 1024, 2048] {
+        out.push(time(&format!("estimate_core n={}", n), budget, || {
+            black_box(estimate_core(black_box(n), 12289, 3.2, false));
+        }));
+    }
+    out.push(time("solve n for 128 bits, q=12289", budget, || {
+        black_box(solve(SearchParam::N, 0, 12289, 3.2, 128.0, SecurityMargin::None, false).ok());
+    }));
+    let (ns, qs, sigmas) = ([256, 512, 768, 1024], [3329, 7681, 12289, 1 << 20], [2.0, 3.2, 8.0]);
+    out.push(time("sweep 4×4×3 grid", budget, || {
+        black_box(sweep(&ns, &qs, &sigmas, false));
+    }));
+    out
+}
+
+fn human(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 10_000 {
+        format!("{} ns", ns)
+    } else if ns < 10_000_000 {
+        format!("{:.1} µs", ns as f64 / 1e3)
+    } else {
+        format!("{:.1} ms", ns as f64 / 1e6)
+    }
+}
+
+/// Summary table, one row per case
+pub fn table(timings: &[Timing]) -> String {
+    let width = timings.iter().map(|t| t.name.chars().count()).max().unwrap_or(0).max(4);
+    let mut out = format!("{:<width$}  {:>10}  {:>10}  {:>10}\n", "case", "mean", "min", "iterations");
+    for t in timings {
+        out.push_str(&format!(
+            "{:<width$}  {:>10}  {:>10}  {:>10}\n",
+            t.name,
+            human(t.mean),
+            human(t.min),
+            t.iterations
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_runs_at_least_once() {
+        let mut calls = 0;
+        let t = time("noop", Duration::ZERO, || calls += 1);
+        assert_eq!((t.iterations, calls), (1, 1));
+        assert!(t.min <= t.mean);
+        let text = table(&[t]);
+        assert!(text.starts_with("case"));
+        assert!(text.lines().nth(1).unwrap().starts_with("noop"));
+    }
+}
@@ -0,0 +1,216 @@
+//! BFV/BGV plaintext-modulus advisor
+//!
+//! Picks a batching-friendly plaintext prime t ≡ 1 (mod 2n), sizes the
+//! ciphertext modulus for the requested depth with a standard noise-growth
+//! heuristic, and verifies the result with the estimator.
+
+use crate::ckks::MAX_RING_DIM;
+use crate::error::CryptoParamError;
+use crate::snap::ntt_primes_around;
+use crate::{estimate_core_log2q, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Smallest ring dimension the advisor will propose
+pub const MIN_RING_DIM: usize = 1024;
+
+/// Noise budget for `depth` multiplications with plaintext modulus t.
+///
+/// Fresh noise is taken as 6σ·2n, each multiplication costs
+/// log2 t + log2 n + 1 bits, and decryption needs noise below q / 2t.
+/// Returns (log2 q, bits per level).
+pub fn q_budget(n: usize, t: u64, depth: usize, sigma: f64) -> (f64, f64) {
+    let log_t = (t as f64).log2();
+    let log_n = (n as f64).log2();
+    let fresh = (6.0 * sigma * 2.0 * n as f64).log2();
+    let per_level = log_t + log_n + 1.0;
+    let log_q = (log_t + 1.0 + fresh + depth as f64 * per_level).ceil();
+    (log_q, per_level)
+}
+
+/// Recommended BFV/BGV parameters
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BgvParams {
+    pub n: usize,
+    /// Plaintext modulus, prime with t ≡ 1 (mod 2n)
+    pub t: u64,
+    pub depth: usize,
+    /// Ciphertext modulus size needed for `depth`
+    pub log_q: f64,
+    /// Noise growth per multiplication in bits
+    pub level_bits: f64,
+    pub target_bits: f64,
+    pub secure: bool,
+    pub estimate: SecurityEstimate,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl BgvParams {
+    fn __repr__(&self) -> String {
+        format!(
+            "BgvParams(n={}, t={}, depth={}, log q={:.0}, bits={:.1}, {})",
+            self.n,
+            self.t,
+            self.depth,
+            self.log_q,
+            self.estimate.classical_bits,
+            if self.secure { "secure" } else { "BELOW TARGET" }
+        )
+    }
+}
+
+/// Builder for [`BgvParams`]
+#[derive(Debug, Clone)]
+pub struct PlaintextAdvisor {
+    plaintext_bits: u32,
+    depth: usize,
+    slots: usize,
+    sigma: f64,
+    target_bits: f64,
+    sieving: bool,
+}
+
+impl PlaintextAdvisor {
+    /// Advisor for a `plaintext_bits`-bit t supporting `depth` multiplications
+    pub fn new(plaintext_bits: u32, depth: usize) -> Self {
+        Self {
+            plaintext_bits,
+            depth,
+            slots: 0,
+            sigma: 3.19,
+            target_bits: 128.0,
+            sieving: false,
+        }
+    }
+
+    /// Minimum number of batching slots (n >= slots)
+    pub fn slots(mut self, slots: usize) -> Self {
+        self.slots = slots;
+        self
+    }
+
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    pub fn target_bits(mut self, bits: f64) -> Self {
+        self.target_bits = bits;
+        self
+    }
+
+    pub fn sieving(mut self, sieving: bool) -> Self {
+        self.sieving = sieving;
+        self
+    }
+
+    /// Smallest power-of-two n whose q budget meets the target; t is the
+    /// smallest `plaintext_bits`-bit prime ≡ 1 (mod 2n)
+    pub fn advise(&self) -> Result<BgvParams, CryptoParamError> {
+        if !(2..=60).contains(&self.plaintext_bits) {
+            return Err(CryptoParamError::InvalidOption("plaintext_bits must be in 2..=60".to_string()));
+        }
+        if self.sigma <= 0.0 {
+            return Err(CryptoParamError::InvalidSigma("sigma must be positive".to_string()));
+        }
+
+        let mut n = self.slots.next_power_of_two().max(MIN_RING_DIM);
+        while n <= MAX_RING_DIM {
+            let floor = 1u64 << (self.plaintext_bits - 1);
+            let t = ntt_primes_around(floor, n).1.filter(|&t| t < floor << 1);
+            if let Some(t) = t {
+                let (log_q, level_bits) = q_budget(n, t, self.depth, self.sigma);
+                let estimate = estimate_core_log2q(n, log_q, self.sigma, self.sieving);
+                if estimate.classical_bits >= self.target_bits {
+                    return Ok(BgvParams {
+                        n,
+                        t,
+                        depth: self.depth,
+                        log_q,
+                        level_bits,
+                        target_bits: self.target_bits,
+                        secure: true,
+                        estimate,
+                    });
+                }
+            }
+            n *= 2;
+        }
+        Err(CryptoParamError::SearchFailed(format!(
+            "no ring dimension up to {} fits a {}-bit t at depth {} with {:.1} bits of security",
+            MAX_RING_DIM, self.plaintext_bits, self.depth, self.target_bits
+        )))
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Recommend a BFV/BGV plaintext modulus and q budget for a depth.
+///
+/// Args:
+///     plaintext_bits: Size of the plaintext modulus t in bits
+///     depth: Multiplicative depth
+///     slots: Minimum number of batching slots (default: 0)
+///     sigma: Error standard deviation (default: 3.19)
+///     target_bits: Required security in bits (default: 128)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     BgvParams with n, t ≡ 1 (mod 2n), log q and the verified security
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "bgv_params",
+    signature = (plaintext_bits, depth, slots = 0, sigma = Sigma(3.19), target_bits = 128.0, sieving = false)
+)]
+pub fn py_bgv_params(
+    plaintext_bits: u32,
+    depth: usize,
+    slots: usize,
+    sigma: Sigma,
+    target_bits: f64,
+    sieving: bool,
+) -> PyResult<BgvParams> {
+    let sigma = sigma.0;
+    PlaintextAdvisor::new(plaintext_bits, depth)
+        .slots(slots)
+        .sigma(sigma)
+        .target_bits(target_bits)
+        .sieving(sieving)
+        .advise()
+        .map_err(PyErr::from)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snap::is_prime;
+
+    #[test]
+    fn test_plaintext_modulus_batching() {
+        let p = PlaintextAdvisor::new(17, 3).slots(4096).advise().unwrap();
+        assert!(p.n >= 4096);
+        assert!(is_prime(p.t));
+        assert_eq!(p.t % (2 * p.n as u64), 1);
+        assert_eq!(64 - p.t.leading_zeros(), 17);
+        assert!(p.secure);
+    }
+
+    #[test]
+    fn test_q_budget_grows_with_depth() {
+        let (q1, per) = q_budget(8192, 65537, 1, 3.19);
+        let (q4, _) = q_budget(8192, 65537, 4, 3.19);
+        assert!((q4 - q1 - 3.0 * per).abs() <= 1.0);
+    }
+}
@@ -0,0 +1,91 @@
+//! Ciphertext and public-key size budgets
+//!
+//! Sizes are expressed per scheme template as n·log q style formulas, so a
+//! search can reject candidates that are secure but too large to ship.
+
+/// Layout used to turn (n, q) into object sizes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SizeTemplate {
+    /// Plain (Regev) LWE: ct (n + 1)·log q, pk n·(n + 1)·log q bits
+    #[default]
+    Lwe,
+    /// Ring-LWE over a degree-n ring: ct and pk 2n·log q bits
+    RingLwe,
+    /// Module-LWE of rank k, n = k·d: ct (k + 1)·d·log q, pk n·log q bits
+    ModuleLwe(usize),
+}
+
+impl SizeTemplate {
+    /// Parse "lwe", "rlwe" or "mlwe:<rank>"
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "lwe" => Ok(Self::Lwe),
+            "rlwe" | "ring" => Ok(Self::RingLwe),
+            _ => {
+                let rank = s
+                    .strip_prefix("mlwe:")
+                    .and_then(|k| k.parse::<usize>().ok())
+                    .filter(|&k| k > 0)
+                    .ok_or_else(|| format!("unknown size template {:?} (expected lwe, rlwe or mlwe:<rank>)", s))?;
+                Ok(Self::ModuleLwe(rank))
+            }
+        }
+    }
+
+    /// (ciphertext, public key) size in bytes
+    pub fn sizes(&self, n: usize, q: u64) -> (f64, f64) {
+        let log_q = (q as f64).log2().ceil();
+        let n_f = n as f64;
+        let (ct_bits, pk_bits) = match *self {
+            Self::Lwe => ((n_f + 1.0) * log_q, n_f * (n_f + 1.0) * log_q),
+            Self::RingLwe => (2.0 * n_f * log_q, 2.0 * n_f * log_q),
+            Self::ModuleLwe(k) => {
+                let d = n_f / k as f64;
+                ((k as f64 + 1.0) * d * log_q, n_f * log_q)
+            }
+        };
+        (ct_bits / 8.0, pk_bits / 8.0)
+    }
+}
+
+/// Upper bounds on object sizes; `None` leaves a size unconstrained
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ByteBudget {
+    pub template: SizeTemplate,
+    pub max_ciphertext_bytes: Option<f64>,
+    pub max_public_key_bytes: Option<f64>,
+}
+
+impl ByteBudget {
+    /// Whether (n, q) fits every configured bound
+    pub fn admits(&self, n: usize, q: u64) -> bool {
+        let (ct, pk) = self.template.sizes(n, q);
+        self.max_ciphertext_bytes.is_none_or(|max| ct <= max)
+            && self.max_public_key_bytes.is_none_or(|max| pk <= max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_sizes() {
+        // Kyber-768 shape, uncompressed: k = 3, d = 256, 12-bit q
+        let (ct, pk) = SizeTemplate::ModuleLwe(3).sizes(768, 3329);
+        assert_eq!(ct, 1536.0);
+        assert_eq!(pk, 1152.0);
+        assert_eq!(SizeTemplate::parse("mlwe:3"), Ok(SizeTemplate::ModuleLwe(3)));
+        assert!(SizeTemplate::parse("mlwe:0").is_err());
+    }
+
+    #[test]
+    fn test_budget_admits() {
+        let b = ByteBudget { max_ciphertext_bytes: Some(1000.0), ..Default::default() };
+        assert!(b.admits(500, 1 << 14));
+        assert!(!b.admits(600, 1 << 14));
+    }
+}
@@ -0,0 +1,249 @@
+//! RNS modulus chains for leveled schemes
+//!
+//! Proposes prime sizes for a given multiplicative depth and checks that the
+//! total modulus keeps the ring dimension above a security target.
+
+use crate::error::CryptoParamError;
+use crate::{estimate_core_log2q, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Largest prime size that still fits a 64-bit word with room for lazy reduction
+pub const MAX_PRIME_BITS: u32 = 61;
+
+// ============================================================================
+// Chain
+// ============================================================================
+
+/// Proposed RNS modulus chain
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModulusChain {
+    pub n: usize,
+    pub depth: usize,
+    /// Bit sizes of the ciphertext primes, base prime first
+    pub level_bits: Vec<u32>,
+    /// Bit sizes of the key-switching special primes
+    pub special_bits: Vec<u32>,
+    /// log2 of the full modulus P·Q seen by the key-switching keys
+    pub total_log_q: f64,
+    pub target_bits: f64,
+    pub secure: bool,
+    pub estimate: SecurityEstimate,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl ModulusChain {
+    fn __repr__(&self) -> String {
+        format!(
+            "ModulusChain(n={}, depth={}, levels={:?}, special={:?}, log q={:.0}, bits={:.1}, {})",
+            self.n,
+            self.depth,
+            self.level_bits,
+            self.special_bits,
+            self.total_log_q,
+            self.estimate.classical_bits,
+            if self.secure { "secure" } else { "BELOW TARGET" }
+        )
+    }
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+/// Builder for [`ModulusChain`]
+///
+/// ```
+/// use cryptoparam::chain::ModulusChainBuilder;
+/// let chain = ModulusChainBuilder::new(8192, 4).scale_bits(40).build().unwrap();
+/// assert_eq!(chain.level_bits, vec![60, 40, 40, 40, 40]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModulusChainBuilder {
+    n: usize,
+    depth: usize,
+    scale_bits: u32,
+    base_bits: u32,
+    special_primes: usize,
+    special_bits: u32,
+    sigma: f64,
+    target_bits: f64,
+    sieving: bool,
+}
+
+impl ModulusChainBuilder {
+    /// Chain for ring dimension `n` supporting `depth` multiplications
+    pub fn new(n: usize, depth: usize) -> Self {
+        Self {
+            n,
+            depth,
+            scale_bits: 40,
+            base_bits: 60,
+            special_primes: 1,
+            special_bits: 60,
+            sigma: 3.19,
+            target_bits: 128.0,
+            sieving: false,
+        }
+    }
+
+    /// Bits consumed per level (one prime per level)
+    pub fn scale_bits(mut self, bits: u32) -> Self {
+        self.scale_bits = bits;
+        self
+    }
+
+    /// Size of the base prime left after the last level
+    pub fn base_bits(mut self, bits: u32) -> Self {
+        self.base_bits = bits;
+        self
+    }
+
+    /// Number and size of special primes used for key switching
+    pub fn special_primes(mut self, count: usize, bits: u32) -> Self {
+        self.special_primes = count;
+        self.special_bits = bits;
+        self
+    }
+
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    pub fn target_bits(mut self, bits: f64) -> Self {
+        self.target_bits = bits;
+        self
+    }
+
+    pub fn sieving(mut self, sieving: bool) -> Self {
+        self.sieving = sieving;
+        self
+    }
+
+    /// Lay out the chain and estimate the security of its full modulus
+    pub fn build(&self) -> Result<ModulusChain, CryptoParamError> {
+        if self.n == 0 {
+            return Err(CryptoParamError::InvalidDimension("n must be positive".to_string()));
+        }
+        if self.sigma <= 0.0 {
+            return Err(CryptoParamError::InvalidSigma("sigma must be positive".to_string()));
+        }
+        for (name, bits) in [
+            ("scale_bits", self.scale_bits),
+            ("base_bits", self.base_bits),
+            ("special_bits", self.special_bits),
+        ] {
+            if !(2..=MAX_PRIME_BITS).contains(&bits) {
+                return Err(CryptoParamError::InvalidOption(format!("{} must be in 2..={}", name, MAX_PRIME_BITS)));
+            }
+        }
+
+        let mut level_bits = vec![self.base_bits];
+        level_bits.extend(std::iter::repeat_n(self.scale_bits, self.depth));
+        let special_bits = vec![self.special_bits; self.special_primes];
+
+        let total_log_q: f64 = level_bits
+            .iter()
+            .chain(special_bits.iter())
+            .map(|&b| b as f64)
+            .sum();
+        let estimate = estimate_core_log2q(self.n, total_log_q, self.sigma, self.sieving);
+
+        Ok(ModulusChain {
+            n: self.n,
+            depth: self.depth,
+            level_bits,
+            special_bits,
+            total_log_q,
+            target_bits: self.target_bits,
+            secure: estimate.classical_bits >= self.target_bits,
+            estimate,
+        })
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Propose an RNS modulus chain and check its security.
+///
+/// Args:
+///     n: Ring dimension
+///     depth: Multiplicative depth
+///     scale_bits: Bits consumed per level (default: 40)
+///     base_bits: Size of the base prime (default: 60)
+///     special_primes: Number of key-switching primes (default: 1)
+///     special_bits: Size of each special prime (default: 60)
+///     sigma: Error standard deviation (default: 3.19)
+///     target_bits: Required security in bits (default: 128)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     ModulusChain with per-level prime sizes and a `secure` verdict
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "modulus_chain",
+    signature = (
+        n, depth, scale_bits = 40, base_bits = 60, special_primes = 1,
+        special_bits = 60, sigma = Sigma(3.19), target_bits = 128.0, sieving = false
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_modulus_chain(
+    n: usize,
+    depth: usize,
+    scale_bits: u32,
+    base_bits: u32,
+    special_primes: usize,
+    special_bits: u32,
+    sigma: Sigma,
+    target_bits: f64,
+    sieving: bool,
+) -> PyResult<ModulusChain> {
+    let sigma = sigma.0;
+    ModulusChainBuilder::new(n, depth)
+        .scale_bits(scale_bits)
+        .base_bits(base_bits)
+        .special_primes(special_primes, special_bits)
+        .sigma(sigma)
+        .target_bits(target_bits)
+        .sieving(sieving)
+        .build()
+        .map_err(PyErr::from)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_layout() {
+        let c = ModulusChainBuilder::new(4096, 3)
+            .scale_bits(30)
+            .base_bits(50)
+            .special_primes(2, 50)
+            .build()
+            .unwrap();
+        assert_eq!(c.level_bits, vec![50, 30, 30, 30]);
+        assert_eq!(c.special_bits, vec![50, 50]);
+        assert_eq!(c.total_log_q, 240.0);
+        assert_eq!(c.estimate.log2_q, 240.0);
+    }
+
+    #[test]
+    fn test_chain_rejects_oversized_primes() {
+        assert!(ModulusChainBuilder::new(4096, 3).scale_bits(64).build().is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! Checkpoint files for long-running sweeps and searches
+//!
+//! Completed points are appended to a plain-text file as they finish, so
+//! an interrupted run can be resumed without recomputing them. A sweep
+//! records its grid points; a search records every candidate it
+//! evaluates through an [`EstimateCache`].
+//!
+//! Format: one header line naming the cost model, then one CSV row per
+//! finished point:
+//!
+//! ```text
+//! # cryptoparam checkpoint v1 sieving=false model=core-svp-0.292
+//! n,q,sigma,log2_q,beta,m,d,bits
+//! ```
+
+use crate::cost_model::CostModel;
+use crate::estimate_core;
+use crate::provenance::{cost_model_id, Provenance};
+use crate::warnings::{lwe_warnings, precision_warning};
+use crate::SecurityEstimate;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const MAGIC: &str = "# cryptoparam checkpoint v1";
+
+/// Key identifying a grid point, σ compared bit-for-bit
+pub type PointKey = (usize, u64, u64);
+
+pub fn point_key(n: usize, q: u64, sigma: f64) -> PointKey {
+    (n, q, sigma.to_bits())
+}
+
+/// Append-only checkpoint for one sweep
+pub struct Checkpoint {
+    path: PathBuf,
+    file: File,
+}
+
+impl Checkpoint {
+    /// Rows are priced with `sieving`; `model` is what the caller reprices
+    /// them with afterwards, if anything
+    fn header(sieving: bool, model: Option<&CostModel>) -> String {
+        format!("{} sieving={} model={}", MAGIC, sieving, model.map_or(cost_model_id(sieving), |m| m.id))
+    }
+
+    /// Start a fresh checkpoint, truncating any existing file
+    pub fn create(path: &Path, sieving: bool, model: Option<&CostModel>) -> io::Result<Self> {
+        let mut file = File::create(path)?;
+        writeln!(file, "{}", Self::header(sieving, model))?;
+        file.flush()?;
+        Ok(Self { path: path.to_path_buf(), file })
+    }
+
+    /// Reopen an existing checkpoint, returning the points it already holds.
+    ///
+    /// Fails if the file was written with a different cost model.
+    pub fn resume(
+        path: &Path,
+        sieving: bool,
+        model: Option<&CostModel>,
+    ) -> io::Result<(Self, HashMap<PointKey, SecurityEstimate>)> {
+        let mut done = HashMap::new();
+        let reader = BufReader::new(File::open(path)?);
+        let mut lines = reader.lines();
+        let header = lines.next().transpose()?.unwrap_or_default();
+        // Files from before the model was recorded only name the flag
+        let legacy = model.is_none() && header == format!("{} sieving={}", MAGIC, sieving);
+        if header != Self::header(sieving, model) && !legacy {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: not a checkpoint for this run (header {:?})", path.display(), header),
+            ));
+        }
+        for line in lines {
+            let line = line?;
+            // A crash mid-write can leave a truncated last row; skip it
+            if let Some(r) = parse_row(&line, sieving) {
+                done.insert(point_key(r.n, r.q, r.sigma), r);
+            }
+        }
+        let file = OpenOptions::new().append(true).open(path)?;
+        Ok((Self { path: path.to_path_buf(), file }, done))
+    }
+
+    /// [`Checkpoint::resume`] when `resume` is set and `path` exists,
+    /// otherwise [`Checkpoint::create`] with nothing done yet
+    pub fn open(
+        path: &Path,
+        sieving: bool,
+        model: Option<&CostModel>,
+        resume: bool,
+    ) -> io::Result<(Self, HashMap<PointKey, SecurityEstimate>)> {
+        if resume && path.exists() {
+            Self::resume(path, sieving, model)
+        } else {
+            Ok((Self::create(path, sieving, model)?, HashMap::new()))
+        }
+    }
+
+    /// Record finished points and flush them to disk
+    pub fn append(&mut self, rows: &[SecurityEstimate]) -> io::Result<()> {
+        for r in rows {
+            writeln!(
+                self.file,
+                "{},{},{},{},{},{},{},{}",
+                r.n,
+                r.q,
+                r.sigma,
+                r.log2_q,
+                r.beta.map_or(String::new(), |b| b.to_string()),
+                r.m,
+                r.d,
+                r.classical_bits
+            )?;
+        }
+        self.file.flush()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Estimates memoized in a checkpoint, for searches.
+///
+/// Every estimate computed is appended to the file. The searches are
+/// deterministic, so a resumed one walks the same candidates again and
+/// takes those on file instead of recomputing them, picking up where the
+/// interrupted run stopped.
+pub struct EstimateCache {
+    sieving: bool,
+    state: Mutex<CacheState>,
+}
+
+struct CacheState {
+    ckpt: Checkpoint,
+    done: HashMap<PointKey, SecurityEstimate>,
+    /// First write that failed; later estimates are still computed
+    error: Option<io::Error>,
+}
+
+impl EstimateCache {
+    /// See [`Checkpoint::open`]
+    pub fn open(path: &Path, sieving: bool, resume: bool) -> io::Result<Self> {
+        let (ckpt, done) = Checkpoint::open(path, sieving, None, resume)?;
+        Ok(Self { sieving, state: Mutex::new(CacheState { ckpt, done, error: None }) })
+    }
+
+    /// [`estimate_core`] of (n, q, σ), read from the file when it is there
+    pub fn estimate(&self, n: usize, q: u64, sigma: f64) -> SecurityEstimate {
+        let key = point_key(n, q, sigma);
+        if let Some(r) = self.state.lock().unwrap().done.get(&key) {
+            return r.clone();
+        }
+        // Computed unlocked so parallel searches do not serialize on it
+        let r = estimate_core(n, q, sigma, self.sieving);
+        let mut state = self.state.lock().unwrap();
+        if state.error.is_none() {
+            if let Err(e) = state.ckpt.append(std::slice::from_ref(&r)) {
+                state.error = Some(e);
+            }
+        }
+        state.done.insert(key, r.clone());
+        r
+    }
+
+    /// Err with the first write that failed
+    pub fn finish(self) -> io::Result<()> {
+        self.state.into_inner().unwrap().error.map_or(Ok(()), Err)
+    }
+}
+
+/// Rows read back are stamped with the time they were loaded
+fn parse_row(line: &str, sieving: bool) -> Option<SecurityEstimate> {
+    let f: Vec<&str> = line.split(',').collect();
+    if f.len() != 8 {
+        return None;
+    }
+    let (n, q, sigma) = (f[0].parse().ok()?, f[1].parse().ok()?, f[2].parse().ok()?);
+    // Infeasible rows leave β empty; older files wrote MAX_BETA instead
+    let beta = match f[4] {
+        "" => None,
+        b => Some(b.parse::<usize>().ok()?).filter(|&b| b < crate::MAX_BETA),
+    };
+    let m = f[5].parse().ok()?;
+    let mut warnings = lwe_warnings(n, sigma, beta, m);
+    warnings.extend(precision_warning(q));
+    Some(SecurityEstimate {
+        n,
+        q,
+        sigma,
+        noise: None,
+        secret: None,
+        max_samples: None,
+        quantum_bits: None,
+        log2_q: f[3].parse().ok()?,
+        beta,
+        m,
+        d: f[6].parse().ok()?,
+        classical_bits: f[7].parse().ok()?,
+        attack: "primal_usvp".to_string(),
+        provenance: Provenance::new(sieving, &["primal_usvp"]),
+        warnings,
+        trace: None,
+    })
+}
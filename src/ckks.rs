@@ -0,0 +1,229 @@
+//! CKKS precision-vs-security advisor
+//!
+//! Turns a desired fractional precision, slot count and depth into a ring
+//! dimension, scaling factor Δ and modulus chain, then checks the chain
+//! against the estimator, growing n until the target is met.
+
+use crate::chain::{ModulusChain, ModulusChainBuilder, MAX_PRIME_BITS};
+use crate::error::CryptoParamError;
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Largest ring dimension the advisor will propose
+pub const MAX_RING_DIM: usize = 1 << 17;
+
+/// log2 of the fresh-noise bound 6σ·√n on a slot, the precision lost to
+/// encryption and rescaling noise
+pub fn noise_bits(n: usize, sigma: f64) -> f64 {
+    (6.0 * sigma * (n as f64).sqrt()).log2()
+}
+
+/// Recommended CKKS parameters
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CkksParams {
+    pub n: usize,
+    /// Available slots, n / 2
+    pub slots: usize,
+    pub depth: usize,
+    /// Δ = 2^scale_bits
+    pub scale_bits: u32,
+    /// Fractional bits left after noise, >= the requested precision
+    pub precision_bits: f64,
+    pub noise_bits: f64,
+    pub chain: ModulusChain,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CkksParams {
+    fn __repr__(&self) -> String {
+        format!(
+            "CkksParams(n={}, slots={}, depth={}, Δ=2^{}, precision={:.1} bits, levels={:?}, bits={:.1}, {})",
+            self.n,
+            self.slots,
+            self.depth,
+            self.scale_bits,
+            self.precision_bits,
+            self.chain.level_bits,
+            self.chain.estimate.classical_bits,
+            if self.chain.secure { "secure" } else { "BELOW TARGET" }
+        )
+    }
+}
+
+/// Builder for [`CkksParams`]
+#[derive(Debug, Clone)]
+pub struct CkksAdvisor {
+    precision_bits: f64,
+    slots: usize,
+    depth: usize,
+    integer_bits: u32,
+    sigma: f64,
+    target_bits: f64,
+    sieving: bool,
+}
+
+impl CkksAdvisor {
+    /// Advisor for `precision_bits` fractional bits over `slots` values
+    /// through `depth` multiplications
+    pub fn new(precision_bits: f64, slots: usize, depth: usize) -> Self {
+        Self {
+            precision_bits,
+            slots,
+            depth,
+            integer_bits: 20,
+            sigma: 3.19,
+            target_bits: 128.0,
+            sieving: false,
+        }
+    }
+
+    /// Extra bits of the base prime holding the integer part of the result
+    pub fn integer_bits(mut self, bits: u32) -> Self {
+        self.integer_bits = bits;
+        self
+    }
+
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    pub fn target_bits(mut self, bits: f64) -> Self {
+        self.target_bits = bits;
+        self
+    }
+
+    pub fn sieving(mut self, sieving: bool) -> Self {
+        self.sieving = sieving;
+        self
+    }
+
+    /// Smallest power-of-two n with enough slots whose chain meets the target
+    pub fn advise(&self) -> Result<CkksParams, CryptoParamError> {
+        if self.slots == 0 {
+            return Err(CryptoParamError::InvalidOption("slots must be positive".to_string()));
+        }
+        if self.precision_bits <= 0.0 {
+            return Err(CryptoParamError::InvalidOption("precision_bits must be positive".to_string()));
+        }
+        if self.sigma <= 0.0 {
+            return Err(CryptoParamError::InvalidSigma("sigma must be positive".to_string()));
+        }
+
+        let mut n = (2 * self.slots).next_power_of_two();
+        while n <= MAX_RING_DIM {
+            let noise = noise_bits(n, self.sigma);
+            let scale_bits = (self.precision_bits + noise).ceil() as u32;
+            if scale_bits > MAX_PRIME_BITS {
+                return Err(CryptoParamError::SearchFailed(format!(
+                    "{} bits of precision need Δ = 2^{}, above the {}-bit prime limit",
+                    self.precision_bits, scale_bits, MAX_PRIME_BITS
+                )));
+            }
+            let base_bits = (scale_bits + self.integer_bits).min(MAX_PRIME_BITS);
+            let chain = ModulusChainBuilder::new(n, self.depth)
+                .scale_bits(scale_bits)
+                .base_bits(base_bits)
+                .special_primes(1, base_bits)
+                .sigma(self.sigma)
+                .target_bits(self.target_bits)
+                .sieving(self.sieving)
+                .build()?;
+            if chain.secure {
+                return Ok(CkksParams {
+                    n,
+                    slots: n / 2,
+                    depth: self.depth,
+                    scale_bits,
+                    precision_bits: scale_bits as f64 - noise,
+                    noise_bits: noise,
+                    chain,
+                });
+            }
+            n *= 2;
+        }
+        Err(CryptoParamError::SearchFailed(format!(
+            "no ring dimension up to {} reaches {:.1} bits at depth {}",
+            MAX_RING_DIM, self.target_bits, self.depth
+        )))
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Recommend CKKS parameters for a precision, slot count and depth.
+///
+/// Args:
+///     precision_bits: Desired fractional precision in bits
+///     slots: Number of packed values needed
+///     depth: Multiplicative depth
+///     integer_bits: Headroom for the integer part in the base prime (default: 20)
+///     sigma: Error standard deviation (default: 3.19)
+///     target_bits: Required security in bits (default: 128)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     CkksParams with n, Δ, the modulus chain and its security
+///
+/// Example:
+///     >>> from cryptoparam import ckks_params
+///     >>> p = ckks_params(20, 4096, 5)
+///     >>> print(p.n, p.scale_bits)  # 8192 31
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "ckks_params",
+    signature = (
+        precision_bits, slots, depth, integer_bits = 20, sigma = Sigma(3.19),
+        target_bits = 128.0, sieving = false
+    )
+)]
+pub fn py_ckks_params(
+    precision_bits: f64,
+    slots: usize,
+    depth: usize,
+    integer_bits: u32,
+    sigma: Sigma,
+    target_bits: f64,
+    sieving: bool,
+) -> PyResult<CkksParams> {
+    let sigma = sigma.0;
+    CkksAdvisor::new(precision_bits, slots, depth)
+        .integer_bits(integer_bits)
+        .sigma(sigma)
+        .target_bits(target_bits)
+        .sieving(sieving)
+        .advise()
+        .map_err(PyErr::from)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ckks_advice() {
+        let p = CkksAdvisor::new(20.0, 4096, 5).advise().unwrap();
+        assert!(p.n >= 8192 && p.n.is_power_of_two());
+        assert!(p.precision_bits >= 20.0);
+        assert_eq!(p.chain.level_bits.len(), 6);
+        assert_eq!(p.chain.level_bits[1], p.scale_bits);
+        assert!(p.chain.secure);
+    }
+
+    #[test]
+    fn test_ckks_rejects_excess_precision() {
+        assert!(matches!(CkksAdvisor::new(60.0, 1024, 2).advise(), Err(CryptoParamError::SearchFailed(_))));
+    }
+}
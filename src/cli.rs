@@ -0,0 +1,1040 @@
+//! Subcommand and option declarations of the CLI
+//!
+//! Each subcommand declares its arguments and options once, as a clap
+//! derive struct; parsing, error messages, `--help` and the man page are
+//! derived from the declaration.
+
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use cryptoparam::cost_model::{self, CostModel};
+use cryptoparam::hestd::HeSecret;
+use cryptoparam::report::OutputFormat;
+use cryptoparam::search::{SearchParam, SecurityMargin};
+use clap_mangen::Man;
+use cryptoparam::secret::SecretDist;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cryptoparam",
+    version,
+    about = "CryptoParam - Plain LWE Security Estimator",
+    long_about = "CryptoParam - Plain LWE Security Estimator\n\n\
+                  Estimates the cost of lattice attacks on LWE parameter sets. \
+                  A bare 'cryptoparam <n> <q> <sigma>' is the same as 'cryptoparam estimate'.",
+    override_usage = "cryptoparam <COMMAND> [ARGS] [OPTIONS]\n       \
+                      cryptoparam <n> <q> <sigma> [OPTIONS]   (same as 'estimate')\n       \
+                      cryptoparam --config <params.toml|yaml> [OPTIONS]\n       \
+                      cryptoparam --generate-man [DIR]",
+    after_help = "Run 'cryptoparam <COMMAND> --help' for its arguments and options.\n\
+                  Option defaults are read from ~/.config/cryptoparam/config.toml (see --profile).",
+    arg_required_else_help = true,
+    args_override_self = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+    /// Estimate the parameter sets listed in a TOML/YAML run configuration
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+    /// Print the man page as roff, or write one page per command to DIR
+    #[arg(long, value_name = "DIR", num_args = 0..=1)]
+    pub generate_man: Option<Option<PathBuf>>,
+    #[command(flatten)]
+    pub run: ConfigArgs,
+    #[command(flatten)]
+    pub global: GlobalArgs,
+}
+
+/// Options every subcommand accepts
+#[derive(Args, Debug)]
+#[command(next_help_heading = "Global options")]
+pub struct GlobalArgs {
+    /// Worker threads for parallel work (default: all cores)
+    #[arg(long, global = true, value_name = "N")]
+    pub threads: Option<usize>,
+    /// Take option defaults from [profile.NAME] of the user config file
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+}
+
+/// Options of `--config` mode; they need `--config`, so they cannot be
+/// mistaken for a subcommand's own
+#[derive(Args, Debug)]
+#[command(next_help_heading = "Run configuration (--config)")]
+pub struct ConfigArgs {
+    /// Use aggressive sieving cost model
+    #[arg(long, requires = "config")]
+    pub sieving: bool,
+    /// Also show quantum security (quantum sieving model) in every format
+    #[arg(long, requires = "config")]
+    pub quantum: bool,
+    /// Output as text, csv, json, markdown or latex (default: the file's format)
+    #[arg(long, value_name = "F", requires = "config", value_parser = OutputFormat::parse)]
+    pub format: Option<OutputFormat>,
+    /// LaTeX columns: name,n,logq,sigma,attack,beta,d,m,bits,quantum
+    #[arg(long, value_name = "C,..", requires = "config")]
+    pub columns: Option<String>,
+    /// Exit with status 2 if classical security is below BITS
+    #[arg(long, value_name = "BITS", requires = "config", value_parser = parse_bits)]
+    pub require_bits: Option<f64>,
+    /// Exit with status 2 if quantum security is below BITS
+    #[arg(long, value_name = "BITS", requires = "config", value_parser = parse_bits)]
+    pub require_quantum_bits: Option<f64>,
+    /// Do not colour text output (also NO_COLOR)
+    #[arg(long, requires = "config")]
+    pub no_color: bool,
+    /// Colour bits green/yellow/red at these levels (default: 128,100)
+    #[arg(long, value_name = "GOOD,WEAK", requires = "config", value_parser = parse_thresholds)]
+    pub thresholds: Option<(f64, f64)>,
+    /// `--format` of the user profile, below the file's own
+    #[arg(skip)]
+    pub profile_format: Option<OutputFormat>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Estimate the security of one LWE parameter set
+    #[command(override_usage = "cryptoparam estimate <n> <q> <sigma> [OPTIONS]\n       \
+                                cryptoparam estimate <n> <q> (--noise-bits B | --eta η) [OPTIONS]\n       \
+                                cryptoparam estimate --batch FILE [OPTIONS]")]
+    Estimate(EstimateArgs),
+    /// Find the parameter value that just reaches a security target
+    Search(SearchArgs),
+    /// Print the cost of every implemented attack, marking the cheapest
+    Attacks(AttacksArgs),
+    /// Compare two presets, params files or n,q,sigma triples side by side
+    Compare(CompareArgs),
+    /// Print the (n, bits) curve as CSV/JSON for plotting
+    CurveN(CurveNArgs),
+    /// Print the (log2 q, bits) curve as CSV/JSON for plotting
+    CurveLogq(CurveLogqArgs),
+    /// Estimate every combination of values or ranges, in parallel
+    Sweep(SweepArgs),
+    /// Browse the built-in parameter sets and re-estimate them
+    Presets(PresetsArgs),
+    /// Diff parameters against the closest built-in presets
+    Nearest(NearestArgs),
+    /// Check scheme descriptors' claimed bits and NIST category; exit 1 if any fails
+    Verify(VerifyArgs),
+    /// Write a self-contained HTML report with plots
+    Report(ReportArgs),
+    /// Write a Sage script cross-checking with the lattice-estimator
+    Sage(SageArgs),
+    /// Explore parameters interactively, re-estimating after every change
+    Repl(ReplArgs),
+    /// Print the JSON Schema of the descriptor format
+    Schema,
+    /// Check (n, log q) against the HomomorphicEncryption.org tables
+    HeStd(HeStdArgs),
+    /// Time single estimates, a search and a sweep on representative sizes
+    Bench(BenchArgs),
+}
+
+// ============================================================================
+// Shared Options
+// ============================================================================
+
+/// `--sieving` and the `--model` that overrides it
+#[derive(Args, Debug)]
+pub struct ModelArgs {
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Cost model: core-svp (classical), sieving, quantum (q-core-svp), matzov22, gates or
+    /// enum (enumeration); overrides --sieving
+    #[arg(long, value_name = "M", value_parser = cost_model::by_name)]
+    pub model: Option<&'static CostModel>,
+}
+
+impl ModelArgs {
+    /// The model to price with: `--model`, else the one `--sieving` selects
+    pub fn cost_model(&self) -> &'static CostModel {
+        self.model.unwrap_or_else(|| cost_model::for_sieving(self.sieving))
+    }
+}
+
+/// How result rows are printed
+#[derive(Args, Debug)]
+pub struct OutputArgs {
+    /// Output as text, csv, json, markdown or latex
+    #[arg(long, value_name = "F", value_parser = OutputFormat::parse)]
+    pub format: Option<OutputFormat>,
+    /// LaTeX columns: name,n,logq,sigma,attack,beta,d,m,bits,quantum
+    #[arg(long, value_name = "C,..")]
+    pub columns: Option<String>,
+    /// Do not colour text output (also NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+    /// Colour bits green/yellow/red at these levels (default: 128,100)
+    #[arg(long, value_name = "GOOD,WEAK", value_parser = parse_thresholds)]
+    pub thresholds: Option<(f64, f64)>,
+}
+
+/// Security levels the results must reach
+#[derive(Args, Debug)]
+pub struct RequireArgs {
+    /// Exit with status 2 if classical security is below BITS
+    #[arg(long, value_name = "BITS", value_parser = parse_bits)]
+    pub require_bits: Option<f64>,
+    /// Exit with status 2 if quantum security is below BITS
+    #[arg(long, value_name = "BITS", value_parser = parse_bits)]
+    pub require_quantum_bits: Option<f64>,
+}
+
+/// Progress bar on stderr
+#[derive(Args, Debug)]
+pub struct ProgressArgs {
+    /// Show a progress bar on stderr even when it is not a terminal
+    #[arg(long)]
+    pub progress: bool,
+    /// Never show a progress bar (default: shown on a terminal)
+    #[arg(long, conflicts_with = "progress")]
+    pub no_progress: bool,
+}
+
+/// How a curve is printed
+#[derive(Args, Debug)]
+pub struct CurveArgs {
+    /// Print JSON instead of CSV
+    #[arg(long)]
+    pub json: bool,
+    /// Draw the curve in the terminal instead of printing data
+    #[arg(long)]
+    pub plot: bool,
+    /// With --plot, use ASCII characters only
+    #[arg(long, requires = "plot")]
+    pub ascii: bool,
+}
+
+// ============================================================================
+// Subcommands
+// ============================================================================
+
+#[derive(Args, Debug)]
+pub struct EstimateArgs {
+    /// n, q and sigma; n and q alone with --noise-bits or --eta
+    #[arg(value_name = "N Q SIGMA", num_args = 0..=3)]
+    pub params: Vec<String>,
+    /// Give the error as σ = 2^-B·q instead of sigma (TFHE convention)
+    #[arg(long, value_name = "B", value_parser = parse_real, conflicts_with = "eta")]
+    pub noise_bits: Option<f64>,
+    /// Give the error as centered binomial CBD(η), σ = √(η/2) (Kyber convention)
+    #[arg(long, value_name = "η", value_parser = parse_number)]
+    pub eta: Option<u64>,
+    /// Secret: gaussian, binary, ternary or sparse:h
+    #[arg(long, value_name = "D", default_value = "gaussian", value_parser = SecretDist::parse)]
+    pub secret_dist: SecretDist,
+    /// LWE samples available to the attacker (default: unbounded)
+    #[arg(long, value_name = "M", value_parser = parse_count)]
+    pub samples: Option<usize>,
+    /// Show detailed output
+    #[arg(short, long)]
+    pub verbose: bool,
+    /// Show the intermediate quantities of the estimate step by step
+    #[arg(long)]
+    pub explain: bool,
+    /// Dump every (m, β, cost) the optimizer considered to stderr as CSV
+    #[arg(long)]
+    pub trace: bool,
+    /// Write the --trace dump to FILE instead
+    #[arg(long, value_name = "FILE")]
+    pub trace_file: Option<PathBuf>,
+    /// Print one stable key=value line per estimate, no warnings
+    #[arg(long)]
+    pub porcelain: bool,
+    /// Print only the security in bits
+    #[arg(long)]
+    pub bits_only: bool,
+    /// Estimate every n,q,sigma row of a CSV file ('-' for stdin)
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["params", "noise_bits", "eta", "baseline"])]
+    pub batch: Option<String>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+    /// Also show quantum security (quantum sieving model) in every format
+    #[arg(long)]
+    pub quantum: bool,
+    /// Evaluate one attack, or 'all' for a per-attack table
+    ///
+    /// The estimate itself uses primal_usvp, so naming it prints the
+    /// default output; other attacks, dual or dual_hybrid, print their own
+    /// row, with details under --verbose.
+    #[arg(long, value_name = "NAME")]
+    pub attack: Option<String>,
+    #[command(flatten)]
+    pub output: OutputArgs,
+    #[command(flatten)]
+    pub require: RequireArgs,
+    /// Diff against a result saved with --format json
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+    /// Exit with status 3 if --baseline bits moved more (default: 0)
+    #[arg(long, value_name = "BITS", requires = "baseline", value_parser = parse_tolerance)]
+    pub tolerance: Option<f64>,
+}
+
+#[derive(Args, Debug)]
+pub struct SearchArgs {
+    /// Required security in bits
+    #[arg(long, value_name = "BITS", value_parser = parse_bits)]
+    pub target_bits: f64,
+    /// Parameter to solve for
+    #[arg(long, value_name = "n|q|sigma|h", value_parser = SearchParam::parse)]
+    pub vary: SearchParam,
+    /// Hold a parameter fixed, e.g. q=2**32; repeat for each
+    #[arg(long, value_name = "NAME=VALUE")]
+    pub fix: Vec<String>,
+    /// Safety margin, e.g. +16 or 15% (default: none)
+    #[arg(long, value_name = "M", value_parser = SecurityMargin::parse)]
+    pub margin: Option<SecurityMargin>,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Record every candidate evaluated in FILE
+    #[arg(long, value_name = "FILE")]
+    pub checkpoint: Option<PathBuf>,
+    /// Continue from the candidates already in FILE
+    #[arg(long, requires = "checkpoint")]
+    pub resume: bool,
+    #[command(flatten)]
+    pub progress: ProgressArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct AttacksArgs {
+    /// n, q and sigma, or a preset name or descriptor file
+    #[arg(value_name = "N Q SIGMA | PRESET", required = true, num_args = 1..=3)]
+    pub operand: Vec<String>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// Two presets, params files or n,q,sigma; or n q sigma twice
+    #[arg(value_name = "A B | N Q SIGMA N Q SIGMA", required = true, num_args = 2..=6)]
+    pub operands: Vec<String>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+    /// Also show quantum security (quantum sieving model) in every format
+    #[arg(long)]
+    pub quantum: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CurveNArgs {
+    /// Modulus, e.g. 12289 or 2**32
+    #[arg(value_parser = parse_number)]
+    pub q: u64,
+    /// Error standard deviation
+    #[arg(value_parser = parse_real)]
+    pub sigma: f64,
+    /// Smallest dimension
+    #[arg(value_parser = parse_number)]
+    pub n_min: u64,
+    /// Largest dimension
+    #[arg(value_parser = parse_number)]
+    pub n_max: u64,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    #[command(flatten)]
+    pub curve: CurveArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct CurveLogqArgs {
+    /// LWE dimension
+    #[arg(value_parser = parse_number)]
+    pub n: u64,
+    /// Error standard deviation
+    #[arg(value_parser = parse_real)]
+    pub sigma: f64,
+    /// Smallest log2 q
+    #[arg(value_parser = parse_real)]
+    pub logq_min: f64,
+    /// Largest log2 q
+    #[arg(value_parser = parse_real)]
+    pub logq_max: f64,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    #[command(flatten)]
+    pub curve: CurveArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct SweepArgs {
+    /// n, q and sigma values, in place of --n, --q and --sigma
+    #[arg(value_name = "N,.. Q,.. SIGMA,..", num_args = 0..=3)]
+    pub params: Vec<String>,
+    /// Dimensions: a list 256,512 or a range 256..2048:256
+    #[arg(long, value_name = "VALUES")]
+    pub n: Option<String>,
+    /// Moduli: a list, a range, or 2**20..2**30:*2 for a geometric one
+    #[arg(long, value_name = "VALUES")]
+    pub q: Option<String>,
+    /// Error widths: a list or a range 3.0..4.0:0.25
+    #[arg(long, value_name = "VALUES")]
+    pub sigma: Option<String>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+    /// Also show quantum security (quantum sieving model) in every format
+    #[arg(long)]
+    pub quantum: bool,
+    /// Record finished points in FILE
+    #[arg(long, value_name = "FILE")]
+    pub checkpoint: Option<PathBuf>,
+    /// Continue from the points already in FILE
+    #[arg(long, requires = "checkpoint")]
+    pub resume: bool,
+    #[command(flatten)]
+    pub progress: ProgressArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+    #[command(flatten)]
+    pub require: RequireArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct PresetsArgs {
+    /// list, show <name>, estimate <name> or a preset name
+    #[arg(value_name = "ACTION", num_args = 0..=2)]
+    pub action: Vec<String>,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Only presets of scheme S
+    #[arg(long, value_name = "S")]
+    pub scheme: Option<String>,
+    /// Only presets of group G
+    #[arg(long, value_name = "G")]
+    pub group: Option<String>,
+    /// Only presets of NIST level L
+    #[arg(long, value_name = "L")]
+    pub level: Option<u8>,
+    /// Print the preset as a lattice-estimator snippet
+    #[arg(long)]
+    pub lattice_estimator: bool,
+    /// Export every preset with its estimate
+    #[arg(long, value_name = "json|csv")]
+    pub export: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct NearestArgs {
+    /// LWE dimension
+    #[arg(value_parser = parse_number)]
+    pub n: u64,
+    /// Modulus, e.g. 12289 or 2**32
+    #[arg(value_parser = parse_number)]
+    pub q: u64,
+    /// Error standard deviation
+    #[arg(value_parser = parse_real)]
+    pub sigma: f64,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Number of presets to show
+    #[arg(long, value_name = "K", default_value_t = 3)]
+    pub count: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Descriptor files, JSON or TOML
+    #[arg(value_name = "DESCRIPTOR", required = true)]
+    pub paths: Vec<PathBuf>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+    /// Read a SEAL or OpenFHE parameter file
+    #[arg(long, value_name = "seal|openfhe")]
+    pub from: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    /// LWE dimension
+    #[arg(value_parser = parse_number)]
+    pub n: u64,
+    /// Modulus, e.g. 12289 or 2**32
+    #[arg(value_parser = parse_number)]
+    pub q: u64,
+    /// Error standard deviation
+    #[arg(value_parser = parse_real)]
+    pub sigma: f64,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Write to FILE instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct SageArgs {
+    /// n, q and sigma, or a preset name or descriptor file
+    #[arg(value_name = "N Q SIGMA | PRESET", required = true, num_args = 1..=3)]
+    pub operand: Vec<String>,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Write to FILE instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReplArgs {
+    /// Starting n, q and sigma
+    #[arg(value_name = "N Q SIGMA", num_args = 0..=3)]
+    pub params: Vec<String>,
+    #[command(flatten)]
+    pub model: ModelArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct HeStdArgs {
+    /// LWE dimension
+    #[arg(value_parser = parse_number)]
+    pub n: u64,
+    /// log2 of the ciphertext modulus
+    #[arg(value_parser = parse_real)]
+    pub log_q: f64,
+    /// Use aggressive sieving cost model
+    #[arg(long)]
+    pub sieving: bool,
+    /// Security level
+    #[arg(long, value_name = "128|192|256", default_value_t = 128)]
+    pub security: u32,
+    /// Secret distribution
+    #[arg(long, value_name = "ternary|gaussian", default_value = "ternary", value_parser = HeSecret::parse)]
+    pub secret: HeSecret,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Time spent on each case in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 200)]
+    pub budget: u64,
+}
+
+// ============================================================================
+// Profile Defaults and Argument Rewriting
+// ============================================================================
+
+/// Value of `long` among the `(option, value)` defaults of a profile
+fn lookup<'a>(defaults: &'a [(&str, String)], long: &str) -> Option<&'a str> {
+    defaults.iter().find(|(l, _)| *l == long).map(|(_, v)| v.as_str())
+}
+
+/// Parse the profile default of `long` unless the command line set one
+fn fill<T, E: std::fmt::Display>(slot: &mut Option<T>, defaults: &[(&str, String)], long: &str, parse: fn(&str) -> Result<T, E>) -> Result<(), String> {
+    match lookup(defaults, long) {
+        Some(v) if slot.is_none() => parse(v).map(|v| *slot = Some(v)).map_err(|e| format!("profile {}: {}", long, e)),
+        _ => Ok(()),
+    }
+}
+
+impl ModelArgs {
+    fn apply_defaults(&mut self, defaults: &[(&str, String)]) -> Result<(), String> {
+        // An explicit --sieving beats a profile's model
+        if self.sieving {
+            return Ok(());
+        }
+        fill(&mut self.model, defaults, "--model", cost_model::by_name)
+    }
+}
+
+impl OutputArgs {
+    fn apply_defaults(&mut self, defaults: &[(&str, String)]) -> Result<(), String> {
+        fill(&mut self.format, defaults, "--format", OutputFormat::parse)?;
+        fill(&mut self.thresholds, defaults, "--thresholds", parse_thresholds)
+    }
+}
+
+impl Cli {
+    /// Record profile defaults for the options the command has and the
+    /// command line left unset; others are ignored, so one profile serves
+    /// every command
+    pub fn apply_defaults(&mut self, defaults: &[(&str, String)]) -> Result<(), String> {
+        match &mut self.command {
+            None => {
+                fill(&mut self.run.profile_format, defaults, "--format", OutputFormat::parse)?;
+                fill(&mut self.run.thresholds, defaults, "--thresholds", parse_thresholds)
+            }
+            Some(Commands::Estimate(a)) => {
+                fill(&mut a.attack, defaults, "--attack", |s| Ok::<_, String>(s.to_string()))?;
+                a.model.apply_defaults(defaults)?;
+                a.output.apply_defaults(defaults)
+            }
+            Some(Commands::Sweep(a)) => {
+                a.model.apply_defaults(defaults)?;
+                a.output.apply_defaults(defaults)
+            }
+            Some(Commands::Attacks(AttacksArgs { model, .. }))
+            | Some(Commands::Compare(CompareArgs { model, .. }))
+            | Some(Commands::Verify(VerifyArgs { model, .. }))
+            | Some(Commands::Repl(ReplArgs { model, .. })) => model.apply_defaults(defaults),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+/// Options of the top level that take no subcommand
+const TOP_LEVEL: &[&str] = &["-h", "--help", "-V", "--version", "--generate-man"];
+
+/// Insert `estimate` into a bare `cryptoparam <n> <q> <sigma> ...` so it
+/// parses as the subcommand; `args` includes the program name
+pub fn with_default_command(mut args: Vec<String>) -> Vec<String> {
+    let mut i = 1;
+    // Global options may come first, e.g. `cryptoparam --threads 4 512 ...`
+    while let Some(arg) = args.get(i) {
+        match arg.as_str() {
+            "--threads" | "--profile" => i += 2,
+            a if a.starts_with("--threads=") || a.starts_with("--profile=") => i += 1,
+            _ => break,
+        }
+    }
+    let Some(first) = args.get(i) else { return args };
+    let config = args.iter().any(|a| a == "--config" || a.starts_with("--config="));
+    let subcommand = Cli::command().get_subcommands().any(|c| c.get_name() == first) || first == "help";
+    if !config && !subcommand && !TOP_LEVEL.contains(&first.as_str()) && (first.starts_with('-') || parse_number(first).is_ok()) {
+        args.insert(i, "estimate".to_string());
+    }
+    args
+}
+
+/// Positive number of bits, e.g. for `--require-bits`
+fn parse_bits(s: &str) -> Result<f64, String> {
+    match s.trim().parse::<f64>() {
+        Ok(bits) if bits.is_finite() && bits > 0.0 => Ok(bits),
+        _ => Err("expected a positive number of bits".to_string()),
+    }
+}
+
+/// Non-negative `--tolerance` in bits
+fn parse_tolerance(s: &str) -> Result<f64, String> {
+    match s.trim().parse::<f64>() {
+        Ok(t) if t.is_finite() && t >= 0.0 => Ok(t),
+        _ => Err("expected a non-negative number of bits".to_string()),
+    }
+}
+
+/// Positive integer such as `64` or `2**10`
+fn parse_count(s: &str) -> Result<usize, String> {
+    match parse_number(s)? {
+        0 => Err("must be positive".to_string()),
+        k => usize::try_from(k).map_err(|_| format!("'{}' is too large", s)),
+    }
+}
+
+/// `GOOD,WEAK` colour thresholds with GOOD >= WEAK
+pub fn parse_thresholds(s: &str) -> Result<(f64, f64), String> {
+    let Some((g, w)) = s.split_once(',') else {
+        return Err("expected GOOD,WEAK, e.g. 128,100".to_string());
+    };
+    match (g.trim().parse::<f64>(), w.trim().parse::<f64>()) {
+        (Ok(g), Ok(w)) if g >= w => Ok((g, w)),
+        _ => Err("expected GOOD,WEAK with GOOD >= WEAK".to_string()),
+    }
+}
+
+// ============================================================================
+// Man Pages
+// ============================================================================
+
+/// The CLI as man pages see it, without clap's `help` subcommand
+fn man_command() -> clap::Command {
+    let mut cmd = Cli::command().about("plain LWE security estimator").disable_help_subcommand(true);
+    cmd.build();
+    cmd
+}
+
+/// Escape `s` for roff: backslashes, hyphens and leading control characters
+fn roff(s: &str) -> String {
+    let s = s.replace('\\', "\\e").replace('-', "\\-");
+    if s.starts_with('.') || s.starts_with('\'') { format!("\\&{}", s) } else { s }
+}
+
+/// man(7) page of the top-level command, rendered by clap_mangen, with
+/// one line per exit status
+pub fn man_page(exit_status: &[(i32, &str)]) -> io::Result<Vec<u8>> {
+    let man = Man::new(man_command());
+    let mut out = Vec::new();
+    man.render_title(&mut out)?;
+    man.render_name_section(&mut out)?;
+    man.render_synopsis_section(&mut out)?;
+    man.render_description_section(&mut out)?;
+    man.render_options_section(&mut out)?;
+    man.render_subcommands_section(&mut out)?;
+    out.extend_from_slice(b".SH \"EXIT STATUS\"\n");
+    for (code, meaning) in exit_status {
+        out.extend_from_slice(format!(".TP\n.B {}\n{}\n", code, roff(meaning)).as_bytes());
+    }
+    out.extend_from_slice(b".SH FILES\n.TP\n.I ~/.config/cryptoparam/config.toml\n");
+    let files = roff("Defaults for --model, --attack, --thresholds and --format, with [profile.NAME] tables for --profile.");
+    out.extend_from_slice(format!("{}\n", files).as_bytes());
+    man.render_version_section(&mut out)?;
+    Ok(out)
+}
+
+/// Write cryptoparam.1 and one cryptoparam-<command>.1 per subcommand to `dir`
+pub fn write_man_pages(dir: &Path, exit_status: &[(i32, &str)]) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join("cryptoparam.1"), man_page(exit_status)?)?;
+    for sub in man_command().get_subcommands() {
+        Man::new(sub.clone()).generate_to(dir)?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Value Parsing
+// ============================================================================
+
+/// Largest number of values one list or range may expand to
+pub const MAX_VALUES: usize = 1 << 20;
+
+/// Number literal: decimal or `0x` hex integer, or a real in decimal or
+/// scientific notation; `_` separators are ignored
+enum Literal {
+    Int(u128),
+    Real(f64),
+}
+
+fn literal(s: &str) -> Result<Literal, String> {
+    let s = s.trim().replace('_', "");
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return u128::from_str_radix(hex, 16).map(Literal::Int).map_err(|_| format!("invalid hex number '{}'", s));
+    }
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        return s.parse().map(Literal::Int).map_err(|_| format!("'{}' is too large", s));
+    }
+    match s.parse::<f64>() {
+        Ok(x) if x.is_finite() => Ok(Literal::Real(x)),
+        _ if s.is_empty() => Err("missing number".to_string()),
+        _ => Err(format!("invalid number '{}'", s)),
+    }
+}
+
+/// Factors `base ** exp` of a product such as `3*2**30`; `^` is accepted
+/// for `**` and a factor without exponent has exponent 1
+fn factors(s: &str) -> Result<Vec<(Literal, String)>, String> {
+    // Mark the power operators so the remaining `*` separate factors
+    let marked = s.replace("**", "^");
+    let mut out = Vec::new();
+    for factor in marked.split('*') {
+        let (base, exp) = factor.split_once('^').unwrap_or((factor, "1"));
+        if exp.contains('^') {
+            return Err(format!("chained powers in '{}' are ambiguous", factor.replace('^', "**")));
+        }
+        out.push((literal(base)?, exp.trim().to_string()));
+    }
+    Ok(out)
+}
+
+/// Non-negative integer such as `12289`, `0x3001`, `2**32`, `2^32`,
+/// `1_000_003` or a product of those like `3*2**30`
+pub fn parse_number(s: &str) -> Result<u64, String> {
+    let err = |why: String| format!("Cannot parse '{}': {}", s, why);
+    let mut value: u128 = 1;
+    for (base, exp) in factors(s).map_err(err)? {
+        let Literal::Int(base) = base else {
+            return Err(err("expected an integer".to_string()));
+        };
+        let exp: u32 = exp.parse().map_err(|_| err(format!("exponent '{}' must be a non-negative integer", exp)))?;
+        value = base
+            .checked_pow(exp)
+            .and_then(|p| value.checked_mul(p))
+            .ok_or_else(|| err("overflows 128-bit arithmetic".to_string()))?;
+    }
+    u64::try_from(value).map_err(|_| err("exceeds 2^64 - 1".to_string()))
+}
+
+/// log2 of a positive expression, computed in log space so exponents of
+/// any size work, e.g. `2**300` or `3*2**120`
+pub fn parse_log2(s: &str) -> Result<f64, String> {
+    let err = |why: String| format!("Cannot parse '{}': {}", s, why);
+    let mut log2 = 0.0;
+    for (base, exp) in factors(s).map_err(err)? {
+        let base = match base {
+            Literal::Int(i) => i as f64,
+            Literal::Real(x) => x,
+        };
+        if base <= 0.0 {
+            return Err(err("must be positive".to_string()));
+        }
+        let exp: f64 = exp.parse().map_err(|_| err(format!("invalid exponent '{}'", exp)))?;
+        log2 += exp * base.log2();
+    }
+    if log2.is_finite() { Ok(log2) } else { Err(err("is not finite".to_string())) }
+}
+
+/// Modulus argument: exact when it fits in 64 bits, otherwise only its log2
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Modulus {
+    Exact(u64),
+    Log2(f64),
+}
+
+impl Modulus {
+    pub fn log2(self) -> f64 {
+        match self {
+            Self::Exact(q) => (q as f64).log2(),
+            Self::Log2(l) => l,
+        }
+    }
+}
+
+/// A modulus; values of 2^64 and beyond switch to their log2 instead of
+/// overflowing
+pub fn parse_modulus(s: &str) -> Result<Modulus, String> {
+    match parse_number(s) {
+        Ok(q) => Ok(Modulus::Exact(q)),
+        Err(e) => match parse_log2(s) {
+            Ok(l) if l >= 64.0 => Ok(Modulus::Log2(l)),
+            _ => Err(e),
+        },
+    }
+}
+
+/// Real such as `3.19`, `1e-5`, `2**-15*2**32` or
+/// `0x10`; exponents may be fractional
+pub fn parse_real(s: &str) -> Result<f64, String> {
+    let err = |why: String| format!("Cannot parse '{}': {}", s, why);
+    let mut value = 1.0;
+    for (base, exp) in factors(s).map_err(err)? {
+        let base = match base {
+            Literal::Int(i) => i as f64,
+            Literal::Real(x) => x,
+        };
+        let exp: f64 = exp.parse().map_err(|_| err(format!("invalid exponent '{}'", exp)))?;
+        value *= base.powf(exp);
+    }
+    if value.is_finite() { Ok(value) } else { Err(err("is not finite".to_string())) }
+}
+
+/// Step of a range: `:k` adds k, `:*k` multiplies by k
+enum Step<T> {
+    Add(T),
+    Mul(T),
+}
+
+/// Split "lo..hi[:step]" into its parts; None when `s` is not a range
+fn split_range(s: &str) -> Option<(&str, &str, Option<&str>)> {
+    let (lo, rest) = s.split_once("..")?;
+    Some(match rest.split_once(':') {
+        Some((hi, step)) => (lo, hi, Some(step)),
+        None => (lo, rest, None),
+    })
+}
+
+fn parse_step<T, F: Fn(&str) -> Result<T, String>>(s: Option<&str>, default: T, parse: F) -> Result<Step<T>, String> {
+    match s.map(str::trim) {
+        None => Ok(Step::Add(default)),
+        Some(s) => match s.strip_prefix('*') {
+            Some(k) => parse(k).map(Step::Mul),
+            None => parse(s).map(Step::Add),
+        },
+    }
+}
+
+fn too_many(s: &str) -> String {
+    format!("'{}' expands to more than {} values", s, MAX_VALUES)
+}
+
+/// Comma-separated integers and inclusive ranges, e.g. "256,512",
+/// "256..2048:256" or "2**20..2**30:*2" (each item accepts 2**k)
+pub fn parse_int_values(s: &str) -> Result<Vec<u64>, String> {
+    let mut out = Vec::new();
+    for item in s.split(',').map(str::trim) {
+        let Some((lo, hi, step)) = split_range(item) else {
+            out.push(parse_number(item)?);
+            continue;
+        };
+        let (lo, hi) = (parse_number(lo.trim())?, parse_number(hi.trim())?);
+        let mut x = lo;
+        match parse_step(step, 1, parse_number)? {
+            Step::Add(0) | Step::Mul(0..=1) => return Err(format!("range '{}' does not advance", item)),
+            Step::Add(k) => {
+                while x <= hi {
+                    out.push(x);
+                    x = match x.checked_add(k) { Some(v) => v, None => break };
+                    if out.len() > MAX_VALUES { return Err(too_many(item)); }
+                }
+            }
+            Step::Mul(k) => {
+                while x <= hi {
+                    out.push(x);
+                    x = match x.checked_mul(k) { Some(v) => v, None => break };
+                    if out.len() > MAX_VALUES { return Err(too_many(item)); }
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Comma-separated reals and inclusive ranges, e.g. "3.19" or
+/// "3.0..4.0:0.25"; the default step is 1
+pub fn parse_float_values(s: &str) -> Result<Vec<f64>, String> {
+    let parse = |x: &str| parse_real(x.trim());
+    let mut out = Vec::new();
+    for item in s.split(',').map(str::trim) {
+        let Some((lo, hi, step)) = split_range(item) else {
+            out.push(parse(item)?);
+            continue;
+        };
+        let (lo, hi) = (parse(lo)?, parse(hi)?);
+        // Tolerance so 3.0..4.0:0.1 still ends on 4.0
+        let end = hi + 1e-9 * hi.abs().max(1.0);
+        let step = parse_step(step, 1.0, parse)?;
+        let count = match step {
+            Step::Add(k) if k > 0.0 => ((end - lo) / k).floor() + 1.0,
+            Step::Mul(k) if k > 1.0 && lo > 0.0 => ((end / lo).ln() / k.ln()).floor() + 1.0,
+            _ => return Err(format!("range '{}' does not advance", item)),
+        };
+        if count > MAX_VALUES as f64 {
+            return Err(too_many(item));
+        }
+        out.extend((0..count.max(0.0) as i32).map(|i| match step {
+            Step::Add(k) => lo + i as f64 * k,
+            Step::Mul(k) => lo * k.powi(i),
+        }));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Cli, clap::Error> {
+        let args = std::iter::once("cryptoparam").chain(s.split_whitespace()).map(String::from).collect();
+        Cli::try_parse_from(with_default_command(args))
+    }
+
+    #[test]
+    fn test_cli_declaration() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let cli = parse("search --vary n --fix q=2**14 --fix sigma=3.2 --target-bits 128 --threads=4 --sieving").unwrap();
+        assert_eq!(cli.global.threads, Some(4));
+        let Some(Commands::Search(s)) = cli.command else { panic!("expected search") };
+        assert_eq!(s.vary, SearchParam::N);
+        assert_eq!(s.fix, ["q=2**14", "sigma=3.2"]);
+        assert!(s.sieving);
+        // A repeated option keeps its last value
+        let Some(Commands::Report(r)) = parse("report 512 12289 3.2 -o a --output b").unwrap().command else {
+            panic!("expected report")
+        };
+        assert_eq!(r.output, Some(PathBuf::from("b")));
+    }
+
+    #[test]
+    fn test_bare_estimate() {
+        let Some(Commands::Estimate(e)) = parse("--threads 2 512 12289 3.2 --sieving").unwrap().command else {
+            panic!("expected estimate")
+        };
+        assert_eq!(e.params, ["512", "12289", "3.2"]);
+        assert!(e.model.sieving);
+        assert!(matches!(parse("--batch rows.csv").unwrap().command, Some(Commands::Estimate(_))));
+        assert!(matches!(parse("--sieving").unwrap().command, Some(Commands::Estimate(_))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use clap::error::ErrorKind;
+        assert_eq!(parse("search --bogus").unwrap_err().kind(), ErrorKind::UnknownArgument);
+        assert_eq!(parse("report 512 12289").unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+        assert_eq!(parse("estimate 1 2 3 4").unwrap_err().kind(), ErrorKind::TooManyValues);
+        assert_eq!(parse("estimate 1 2 --eta 2 --noise-bits 5").unwrap_err().kind(), ErrorKind::ArgumentConflict);
+        assert_eq!(parse("sweep 256 12289 3.2 --config x.toml").unwrap_err().kind(), ErrorKind::UnknownArgument);
+        assert!(parse("search --target-bits nan --vary n").unwrap_err().to_string().contains("positive number of bits"));
+        let cli = parse("--config x.toml --format json").unwrap();
+        assert!(cli.command.is_none() && cli.run.format == Some(OutputFormat::Json));
+    }
+
+    #[test]
+    fn test_profile_defaults() {
+        let defaults = [("--model", "matzov22".to_string()), ("--format", "csv".to_string())];
+        let mut cli = parse("sweep 256 12289 3.2").unwrap();
+        cli.apply_defaults(&defaults).unwrap();
+        let Some(Commands::Sweep(s)) = &cli.command else { panic!("expected sweep") };
+        assert_eq!(s.model.model.map(|m| m.id), Some("matzov22"));
+        assert_eq!(s.output.format, Some(OutputFormat::Csv));
+        // An explicit --sieving beats the profile's model
+        let mut cli = parse("attacks 512 12289 3.2 --sieving").unwrap();
+        cli.apply_defaults(&defaults).unwrap();
+        let Some(Commands::Attacks(a)) = &cli.command else { panic!("expected attacks") };
+        assert!(a.model.model.is_none());
+    }
+
+    #[test]
+    fn test_man_page() {
+        let man = String::from_utf8(man_page(&[(0, "success")]).unwrap()).unwrap();
+        assert!(man.contains(".TH cryptoparam 1"));
+        assert!(man.contains(".SH NAME\ncryptoparam \\- plain LWE security estimator\n"));
+        assert!(man.contains("cryptoparam\\-search(1)"));
+        assert!(man.contains(".SH \"EXIT STATUS\"\n.TP\n.B 0\nsuccess\n"));
+        assert!(man.contains("\\-\\-threads"));
+
+        let dir = std::env::temp_dir().join(format!("cryptoparam-man-{}", std::process::id()));
+        write_man_pages(&dir, &[(0, "success")]).unwrap();
+        let search = std::fs::read_to_string(dir.join("cryptoparam-search.1")).unwrap();
+        assert!(search.contains("\\-\\-target\\-bits"));
+        assert!(!dir.join("cryptoparam-help.1").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_number_syntax() {
+        assert_eq!(parse_number("12289"), Ok(12289));
+        assert_eq!(parse_number("0x3001"), Ok(12289));
+        assert_eq!(parse_number("1_000_003"), Ok(1_000_003));
+        assert_eq!(parse_number("3*2**30"), Ok(3 << 30));
+        assert_eq!(parse_number("2^32"), Ok(1 << 32));
+        assert_eq!(parse_real("2**-15*2**32"), Ok(131072.0));
+        assert_eq!(parse_real("3.2e0"), Ok(3.2));
+        assert_eq!(parse_real("0x10"), Ok(16.0));
+        assert!(parse_number("3.5").unwrap_err().contains("expected an integer"));
+        assert!(parse_number("2**-1").unwrap_err().contains("non-negative integer"));
+        assert!(parse_number("2**3**2").unwrap_err().contains("ambiguous"));
+        assert!(parse_real("abc").unwrap_err().contains("invalid number 'abc'"));
+    }
+
+    #[test]
+    fn test_large_moduli() {
+        assert!(parse_number("2**64").unwrap_err().contains("exceeds 2^64 - 1"));
+        assert!(parse_number("2**200").unwrap_err().contains("overflows"));
+        assert_eq!(parse_modulus("2**64"), Ok(Modulus::Log2(64.0)));
+        assert_eq!(parse_modulus("3*2**120").map(Modulus::log2), Ok(120.0 + 3f64.log2()));
+        assert_eq!(parse_modulus("12289"), Ok(Modulus::Exact(12289)));
+        assert!(parse_modulus("2**x").unwrap_err().contains("non-negative integer"));
+        assert!(parse_modulus("0.5*2**70").is_ok());
+
+        // Moduli past 2^64 are estimated from their log2 like any other
+        let log2_q = parse_modulus("2**80").unwrap().log2();
+        let config = cryptoparam::estimator::EstimateConfig::default();
+        let r = config.estimate_log2q(1024, log2_q, 2f64.powi(40)).unwrap();
+        assert!(r.classical_bits.is_finite() && r.log2_q == 80.0);
+        let below = config.estimate(1024, 1 << 63, 2f64.powi(40)).unwrap();
+        assert!(below.classical_bits < r.classical_bits);
+        let dual = &cryptoparam::attacks::lwe_attacks(2048, log2_q, 3.2, false)[1];
+        assert!(dual.bits().is_finite());
+    }
+
+    #[test]
+    fn test_value_ranges() {
+        assert_eq!(parse_int_values("256..1024:256").unwrap(), vec![256, 512, 768, 1024]);
+        assert_eq!(parse_int_values("2**10..2**13:*2,7681").unwrap(), vec![1024, 2048, 4096, 8192, 7681]);
+        assert_eq!(parse_float_values("3.0..4.0:0.5").unwrap(), vec![3.0, 3.5, 4.0]);
+        assert_eq!(parse_float_values("3.19").unwrap(), vec![3.19]);
+        assert!(parse_int_values("1..10:0").is_err());
+        assert!(parse_int_values("1..4294967296").is_err());
+    }
+}
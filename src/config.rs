@@ -0,0 +1,172 @@
+//! Run-configuration files
+//!
+//! A TOML or YAML file listing one or many parameter sets plus model
+//! options, so a review can live in version control instead of a long
+//! command line:
+//!
+//! ```toml
+//! sieving = false
+//! format = "csv"
+//!
+//! [[params]]
+//! name = "toy"
+//! n = 512
+//! q = "2**14"
+//! sigma = 3.19
+//!
+//! [[params]]
+//! n = 8192
+//! log_q = 218
+//! sigma = 3.2
+//! ```
+
+use crate::error::CryptoParamError;
+use crate::{estimate_core_log2q, SecurityEstimate};
+use rayon::prelude::*;
+use serde::Deserialize;
+use std::path::Path;
+
+/// Modulus as an integer or an expression such as "2**32" / "2^32"
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Modulus {
+    Int(u64),
+    Expr(String),
+}
+
+impl Modulus {
+    pub fn log2(&self) -> Result<f64, CryptoParamError> {
+        match self {
+            Self::Int(q) => Ok((*q as f64).log2()),
+            Self::Expr(s) => {
+                let s = s.trim();
+                let invalid = || CryptoParamError::InvalidModulus(format!("invalid modulus {:?}", s));
+                let exp = s.strip_prefix("2**").or_else(|| s.strip_prefix("2^"));
+                match exp {
+                    Some(e) => e.trim().parse().map_err(|_| invalid()),
+                    None => s.parse::<u64>().map(|q| (q as f64).log2()).map_err(|_| invalid()),
+                }
+            }
+        }
+    }
+}
+
+/// One parameter set of a run configuration
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParamSet {
+    #[serde(default)]
+    pub name: String,
+    pub n: usize,
+    pub q: Option<Modulus>,
+    /// Alternative to `q` for moduli wider than 64 bits
+    pub log_q: Option<f64>,
+    pub sigma: f64,
+}
+
+impl ParamSet {
+    pub fn log2_q(&self) -> Result<f64, CryptoParamError> {
+        let invalid = |msg: &str| Err(CryptoParamError::InvalidInput(format!("{}: {}", self.label(), msg)));
+        match (&self.q, self.log_q) {
+            (Some(q), None) => q.log2(),
+            (None, Some(l)) => Ok(l),
+            (Some(_), Some(_)) => invalid("give q or log_q, not both"),
+            (None, None) => invalid("missing q"),
+        }
+    }
+
+    fn label(&self) -> String {
+        if self.name.is_empty() { format!("n={}", self.n) } else { self.name.clone() }
+    }
+}
+
+/// Parameter sets plus model options
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RunConfig {
+    #[serde(default)]
+    pub sieving: bool,
+    /// Worker threads; all cores when absent
+    pub threads: Option<usize>,
+    /// Output format, e.g. "text" or "csv"
+    pub format: Option<String>,
+    #[serde(default)]
+    pub params: Vec<ParamSet>,
+}
+
+impl RunConfig {
+    pub fn from_toml(s: &str) -> Result<Self, CryptoParamError> {
+        let c: Self = toml::from_str(s).map_err(|e| CryptoParamError::InvalidInput(format!("invalid config TOML: {}", e)))?;
+        c.validate()
+    }
+
+    pub fn from_yaml(s: &str) -> Result<Self, CryptoParamError> {
+        let c: Self = serde_yaml::from_str(s).map_err(|e| CryptoParamError::InvalidInput(format!("invalid config YAML: {}", e)))?;
+        c.validate()
+    }
+
+    /// Load a `.toml`, `.yaml` or `.yml` file; other extensions try TOML, then YAML
+    pub fn load(path: &Path) -> Result<Self, CryptoParamError> {
+        let text = std::fs::read_to_string(path).map_err(|e| CryptoParamError::InvalidInput(format!("{}: {}", path.display(), e)))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::from_toml(&text),
+            Some("yaml" | "yml") => Self::from_yaml(&text),
+            _ => Self::from_toml(&text).or_else(|_| Self::from_yaml(&text)),
+        }
+    }
+
+    fn validate(self) -> Result<Self, CryptoParamError> {
+        if self.params.is_empty() {
+            return Err(CryptoParamError::InvalidInput("config lists no parameter sets".to_string()));
+        }
+        for p in &self.params {
+            let log_q = p.log2_q()?;
+            if p.n == 0 || log_q < 1.0 || p.sigma <= 0.0 {
+                return Err(CryptoParamError::InvalidInput(format!("{}: need n > 0, q >= 2 and sigma > 0", p.label())));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Estimate every parameter set, in file order
+    pub fn estimates(&self) -> Vec<SecurityEstimate> {
+        self.params
+            .par_iter()
+            .map(|p| estimate_core_log2q(p.n, p.log2_q().unwrap_or(1.0), p.sigma, self.sieving))
+            .collect()
+    }
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_and_yaml_agree() {
+        let t = RunConfig::from_toml(
+            "sieving = true\n[[params]]\nname = \"a\"\nn = 512\nq = \"2**14\"\nsigma = 3.19\n\
+             [[params]]\nn = 1024\nq = 12289\nsigma = 3.19\n",
+        )
+        .unwrap();
+        let y = RunConfig::from_yaml(
+            "sieving: true\nparams:\n  - {name: a, n: 512, q: \"2**14\", sigma: 3.19}\n  - {n: 1024, q: 12289, sigma: 3.19}\n",
+        )
+        .unwrap();
+        assert_eq!(t, y);
+        let rows = t.estimates();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].q, 1 << 14);
+        assert_eq!(rows[1].beta, crate::estimate_core(1024, 12289, 3.19, true).beta);
+    }
+
+    #[test]
+    fn test_config_errors() {
+        assert!(RunConfig::from_toml("sieving = true\n").is_err());
+        assert!(RunConfig::from_toml("[[params]]\nn = 512\nsigma = 3.0\n").is_err());
+        assert!(RunConfig::from_toml("[[params]]\nn = 512\nq = 12289\nsigma = 3.0\nfoo = 1\n").is_err());
+    }
+}
@@ -0,0 +1,163 @@
+//! BKZ cost-model registry
+//!
+//! The block size β an attack needs does not depend on how BKZ is priced,
+//! so every model here reprices the same β. The `sieving` flag used across
+//! the crate selects between the first two entries; quantum security
+//! levels are priced with the third.
+
+use crate::error::CryptoParamError;
+use crate::provenance::{CORE_SVP_MODEL, QUANTUM_MODEL, SIEVING_MODEL};
+use crate::SecurityEstimate;
+
+/// One way of pricing BKZ-β in a lattice of dimension d
+#[derive(Debug)]
+pub struct CostModel {
+    /// Name accepted by `--model`
+    pub name: &'static str,
+    /// Other accepted names
+    pub aliases: &'static [&'static str],
+    /// Identifier recorded in the provenance
+    pub id: &'static str,
+    pub about: &'static str,
+    /// Whether the model assumes a quantum adversary
+    pub quantum: bool,
+    log2_cost: fn(f64, f64) -> f64,
+}
+
+impl CostModel {
+    /// log2 cost of BKZ-β in dimension d
+    pub fn cost(&self, beta: usize, d: usize) -> f64 {
+        if beta < 2 {
+            return 0.0;
+        }
+        (self.log2_cost)(beta as f64, d as f64).max(0.0)
+    }
+
+    /// [`CostModel::cost`] of an attack's block size; infinite when the
+    /// attack is infeasible
+    pub fn price(&self, beta: Option<usize>, d: usize) -> f64 {
+        beta.map_or(f64::INFINITY, |b| self.cost(b, d))
+    }
+
+    /// Reprice `r` under this model
+    pub fn apply(&self, r: &mut SecurityEstimate) {
+        r.classical_bits = self.price(r.beta, r.d);
+        r.provenance.cost_model = self.id.to_string();
+    }
+}
+
+/// Every registered model; core-SVP, sieving and quantum first
+pub const MODELS: &[CostModel] = &[
+    CostModel {
+        name: "core-svp",
+        aliases: &["classical"],
+        id: CORE_SVP_MODEL,
+        about: "0.292β, one classical sieve call (ADPS16)",
+        quantum: false,
+        log2_cost: |b, _| 0.292 * b,
+    },
+    CostModel {
+        name: "sieving",
+        aliases: &[],
+        id: SIEVING_MODEL,
+        about: "0.265β, one sieve call at the aggressive exponent --sieving selects",
+        quantum: false,
+        log2_cost: |b, _| 0.265 * b,
+    },
+    CostModel {
+        name: "quantum",
+        aliases: &["q-core-svp"],
+        id: QUANTUM_MODEL,
+        about: "0.265β, one quantum sieve call (Laarhoven), used for quantum security levels",
+        quantum: true,
+        log2_cost: |b, _| 0.265 * b,
+    },
+    CostModel {
+        name: "matzov22",
+        aliases: &[],
+        id: "matzov22",
+        about: "0.296β + 20.4 + log2(8d), classical gate count after MATZOV 2022",
+        quantum: false,
+        log2_cost: |b, d| 0.296 * b + 20.4 + (8.0 * d).log2(),
+    },
+    CostModel {
+        name: "gates",
+        aliases: &[],
+        id: "gates-0.292",
+        about: "0.292β + 16.4 + log2(8d), core-SVP with sieve gate count and 8d calls",
+        quantum: false,
+        log2_cost: |b, d| 0.292 * b + 16.4 + (8.0 * d).log2(),
+    },
+    CostModel {
+        name: "enum",
+        aliases: &["enumeration"],
+        id: "enum-chengu12",
+        about: "0.187β log2 β − 1.019β + 16.1, extreme-pruning enumeration (Chen–Nguyen fit)",
+        quantum: false,
+        log2_cost: |b, _| 0.270_188_776 * b * b.ln() - 1.019_205_045 * b + 16.102_531_352,
+    },
+];
+
+/// Model registered under `name` or one of its aliases
+pub fn by_name(name: &str) -> Result<&'static CostModel, CryptoParamError> {
+    MODELS.iter().find(|m| m.name == name || m.aliases.contains(&name)).ok_or_else(|| {
+        let names: Vec<String> = MODELS
+            .iter()
+            .map(|m| match m.aliases {
+                [] => m.name.to_string(),
+                aliases => format!("{} ({})", m.name, aliases.join(", ")),
+            })
+            .collect();
+        CryptoParamError::InvalidOption(format!("unknown cost model '{}' (available: {})", name, names.join(", ")))
+    })
+}
+
+/// Model recorded under the provenance identifier `id`
+pub fn by_id(id: &str) -> Option<&'static CostModel> {
+    MODELS.iter().find(|m| m.id == id)
+}
+
+/// Model selected by the `sieving` flag
+pub fn for_sieving(sieving: bool) -> &'static CostModel {
+    &MODELS[sieving as usize]
+}
+
+/// Model that prices quantum security levels
+pub fn quantum() -> &'static CostModel {
+    &MODELS[2]
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bkz_cost, estimate_core};
+
+    #[test]
+    fn test_registry_matches_sieving_flag() {
+        for sieving in [false, true] {
+            let m = for_sieving(sieving);
+            assert_eq!(m.cost(400, 1000), bkz_cost(400, sieving));
+            assert_eq!(by_id(m.id).unwrap().name, m.name);
+        }
+        assert_eq!(by_name("q-core-svp").unwrap().name, "quantum");
+        assert_eq!(quantum().id, QUANTUM_MODEL);
+        assert!(quantum().quantum && !for_sieving(true).quantum);
+        assert_eq!(quantum().cost(400, 1000), for_sieving(true).cost(400, 1000));
+        let CryptoParamError::InvalidOption(err) = by_name("dual").unwrap_err() else { panic!("not an option error") };
+        assert!(err.contains("matzov22") && err.contains("quantum (q-core-svp)") && err.contains("enum (enumeration)"));
+    }
+
+    #[test]
+    fn test_apply_keeps_beta() {
+        let mut r = estimate_core(512, 12289, 3.2, false);
+        let beta = r.beta;
+        by_name("matzov22").unwrap().apply(&mut r);
+        assert_eq!(r.beta, beta);
+        assert_eq!(r.provenance.cost_model, "matzov22");
+        assert!(r.classical_bits > bkz_cost(beta.unwrap(), false));
+    }
+}
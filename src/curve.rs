@@ -0,0 +1,358 @@
+//! Security curves for plotting
+//!
+//! Samples security along one parameter with adaptive refinement: intervals
+//! whose endpoints land on different BKZ block sizes are bisected until each
+//! step of the β staircase is located, so plots show the true stairs instead
+//! of a smoothed line.
+
+use crate::{estimate_core, estimate_core_log2q, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use crate::parallel::with_threads;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+/// Default cap on the number of points in a curve
+pub const DEFAULT_MAX_POINTS: usize = 512;
+
+/// Initial uniform samples before refinement
+const INITIAL_POINTS: usize = 16;
+
+/// One sample of a security curve
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurvePoint {
+    /// Varied parameter (n, log2 q, ...)
+    pub x: f64,
+    pub bits: f64,
+    /// None where no attack is feasible
+    pub beta: Option<usize>,
+}
+
+// ============================================================================
+// Adaptive Sampler
+// ============================================================================
+
+/// Sample `eval` over [lo, hi], refining around β changes.
+///
+/// Refinement stops once neighbouring samples are at most `min_gap` apart,
+/// share a β, or the curve would exceed `max_points`. With `integer` set,
+/// sample positions are rounded to whole numbers.
+pub fn adaptive_curve<F>(
+    lo: f64,
+    hi: f64,
+    min_gap: f64,
+    integer: bool,
+    max_points: usize,
+    eval: F,
+) -> Vec<CurvePoint>
+where
+    F: Fn(f64) -> SecurityEstimate + Sync,
+{
+    let snap = |x: f64| if integer { x.round() } else { x };
+    let sample = |x: f64| {
+        let r = eval(x);
+        CurvePoint { x, bits: r.classical_bits, beta: r.beta }
+    };
+
+    let steps = INITIAL_POINTS.min(max_points.max(2)) - 1;
+    let mut xs: Vec<f64> = (0..=steps)
+        .map(|i| snap(lo + (hi - lo) * i as f64 / steps as f64))
+        .collect();
+    xs.dedup();
+    let mut points: Vec<CurvePoint> = xs.par_iter().map(|&x| sample(x)).collect();
+
+    loop {
+        let mids: Vec<f64> = points
+            .windows(2)
+            .filter(|w| w[0].beta != w[1].beta && w[1].x - w[0].x > min_gap)
+            .map(|w| snap(0.5 * (w[0].x + w[1].x)))
+            .filter(|&m| points.iter().all(|p| p.x != m))
+            .collect();
+        if mids.is_empty() || points.len() + mids.len() > max_points {
+            break;
+        }
+        points.extend(mids.par_iter().map(|&x| sample(x)).collect::<Vec<_>>());
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+    }
+    points
+}
+
+/// Security as a function of n for fixed q and σ
+pub fn security_vs_n(
+    q: u64,
+    sigma: f64,
+    n_min: usize,
+    n_max: usize,
+    sieving: bool,
+    max_points: usize,
+) -> Vec<CurvePoint> {
+    adaptive_curve(n_min as f64, n_max as f64, 1.0, true, max_points, |x| {
+        estimate_core(x as usize, q, sigma, sieving)
+    })
+}
+
+/// Security as a function of log2 q for fixed n and σ, steps located to
+/// within 0.01 bits of modulus
+pub fn security_vs_log_q(
+    n: usize,
+    sigma: f64,
+    log_q_min: f64,
+    log_q_max: f64,
+    sieving: bool,
+    max_points: usize,
+) -> Vec<CurvePoint> {
+    adaptive_curve(log_q_min, log_q_max, 0.01, false, max_points, |x| {
+        estimate_core_log2q(n, x, sigma, sieving)
+    })
+}
+
+// ============================================================================
+// Output
+// ============================================================================
+
+/// CSV with a header row, e.g. `n,bits,beta`; β is empty where no
+/// attack is feasible
+pub fn to_csv(points: &[CurvePoint], x_name: &str) -> String {
+    let mut out = format!("{},bits,beta\n", x_name);
+    for p in points {
+        out.push_str(&format!("{},{:.3},{}\n", p.x, p.bits, p.beta.map_or(String::new(), |b| b.to_string())));
+    }
+    out
+}
+
+/// JSON array of `{"<x_name>": .., "bits": .., "beta": ..}` objects.
+/// Infinite security ("no attack found") is written as `null`, bits and β.
+pub fn to_json(points: &[CurvePoint], x_name: &str) -> String {
+    let rows: Vec<String> = points
+        .iter()
+        .map(|p| {
+            let bits = if p.bits.is_finite() { format!("{:.3}", p.bits) } else { "null".to_string() };
+            let beta = p.beta.map_or("null".to_string(), |b| b.to_string());
+            format!("{{\"{}\": {}, \"bits\": {}, \"beta\": {}}}", x_name, p.x, bits, beta)
+        })
+        .collect();
+    format!("[\n  {}\n]\n", rows.join(",\n  "))
+}
+
+/// Plot area of [`to_ascii`], in terminal cells
+pub const PLOT_WIDTH: usize = 64;
+pub const PLOT_HEIGHT: usize = 16;
+
+/// Lower eighth blocks, indexed by how many eighths of a cell are filled
+const EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Filled terminal plot of the curve with labelled axes.
+///
+/// Each column shows the staircase value at its x, with eighth blocks for
+/// sub-row resolution; `ascii` restricts the output to `#` and `.`.
+/// Columns without an attack are marked `^` in the top row.
+pub fn to_ascii(points: &[CurvePoint], x_name: &str, ascii: bool) -> String {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return "(no points)\n".to_string();
+    };
+    let column_bits: Vec<f64> = (0..PLOT_WIDTH)
+        .map(|c| {
+            let x = first.x + (last.x - first.x) * c as f64 / (PLOT_WIDTH - 1) as f64;
+            points[points.partition_point(|p| p.x <= x).max(1) - 1].bits
+        })
+        .collect();
+    let finite = column_bits.iter().copied().filter(|b| b.is_finite());
+    let lo = (finite.clone().fold(f64::INFINITY, f64::min) / 10.0).floor() * 10.0;
+    let mut hi = (finite.fold(f64::NEG_INFINITY, f64::max) / 10.0).ceil() * 10.0;
+    let lo = if lo.is_finite() { lo } else { 0.0 };
+    if hi <= lo {
+        hi = lo + 10.0;
+    }
+    let levels: Vec<usize> = column_bits
+        .iter()
+        .map(|&b| if b.is_finite() { (((b - lo) / (hi - lo)) * (8 * PLOT_HEIGHT) as f64).round().max(1.0) as usize } else { 0 })
+        .collect();
+
+    let (v_axis, h_axis, corner) = if ascii { ('|', '-', '+') } else { ('┤', '─', '└') };
+    let mut out = "   bits\n".to_string();
+    for row in 0..PLOT_HEIGHT {
+        let label = match row {
+            0 => format!("{:.0}", hi),
+            r if r == PLOT_HEIGHT - 1 => format!("{:.0}", lo),
+            _ => String::new(),
+        };
+        let bottom = 8 * (PLOT_HEIGHT - 1 - row);
+        let cells: String = column_bits
+            .iter()
+            .zip(&levels)
+            .map(|(b, &level)| {
+                if !b.is_finite() {
+                    return if row == 0 { '^' } else { ' ' };
+                }
+                let fill = level.saturating_sub(bottom).min(8);
+                match (ascii, fill) {
+                    (true, 0) => ' ',
+                    (true, 8) => '#',
+                    (true, _) => '.',
+                    (false, f) => EIGHTHS[f],
+                }
+            })
+            .collect();
+        out.push_str(&format!("{:>7} {}{}\n", label, v_axis, cells.trim_end()));
+    }
+    let x_label = |x: f64| if x.fract() == 0.0 { format!("{}", x) } else { format!("{:.2}", x) };
+    let (left, right) = (x_label(first.x), x_label(last.x));
+    out.push_str(&format!("{:>7} {}{}\n", "", corner, h_axis.to_string().repeat(PLOT_WIDTH)));
+    let gap = (PLOT_WIDTH + 1).saturating_sub(left.len() + right.len());
+    out.push_str(&format!("{:>8}{}{}{}\n", "", left, " ".repeat(gap), right));
+    out.push_str(format!("{:>8}{:^width$}", "", x_name, width = PLOT_WIDTH + 1).trim_end());
+    out.push('\n');
+    out
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Security-vs-n curve with every β step resolved.
+///
+/// Args:
+///     q: Modulus
+///     sigma: Error standard deviation
+///     n_min, n_max: Range of dimensions
+///     sieving: Use aggressive sieving cost model (default: False)
+///     max_points: Cap on the number of samples (default: 512)
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     List of (n, bits, beta) tuples sorted by n; beta is None where no
+///     attack is feasible
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "curve_n",
+    signature = (q, sigma, n_min, n_max, sieving = false, max_points = DEFAULT_MAX_POINTS, n_threads = None)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_curve_n(
+    py: Python,
+    q: u64,
+    sigma: Sigma,
+    n_min: usize,
+    n_max: usize,
+    sieving: bool,
+    max_points: usize,
+    n_threads: Option<usize>,
+) -> PyResult<Vec<(usize, f64, Option<usize>)>> {
+    let sigma = sigma.0;
+    if n_min == 0 || n_max < n_min {
+        return Err(crate::errors::InvalidParameters::new_err("need 0 < n_min <= n_max"));
+    }
+    crate::error::check_modulus(q)?;
+    crate::error::check_sigma(sigma)?;
+    let points = py.allow_threads(|| {
+        with_threads(n_threads, || security_vs_n(q, sigma, n_min, n_max, sieving, max_points))
+    });
+    Ok(points.iter().map(|p| (p.x as usize, p.bits, p.beta)).collect())
+}
+
+/// Security-vs-log2 q curve with every β step resolved.
+///
+/// Args:
+///     n: LWE dimension
+///     sigma: Error standard deviation
+///     log_q_min, log_q_max: Range of log2 q (may exceed 64)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     max_points: Cap on the number of samples (default: 512)
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     List of (log2_q, bits, beta) tuples sorted by log2_q; beta is None
+///     where no attack is feasible
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "curve_log_q",
+    signature = (
+        n, sigma, log_q_min, log_q_max, sieving = false,
+        max_points = DEFAULT_MAX_POINTS, n_threads = None
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_curve_log_q(
+    py: Python,
+    n: usize,
+    sigma: Sigma,
+    log_q_min: f64,
+    log_q_max: f64,
+    sieving: bool,
+    max_points: usize,
+    n_threads: Option<usize>,
+) -> PyResult<Vec<(f64, f64, Option<usize>)>> {
+    let sigma = sigma.0;
+    crate::error::check_dimension(n)?;
+    if !(1.0..=log_q_max).contains(&log_q_min) {
+        return Err(crate::errors::InvalidParameters::new_err("need 1 <= log_q_min <= log_q_max"));
+    }
+    crate::error::check_sigma(sigma)?;
+    let points = py.allow_threads(|| {
+        with_threads(n_threads, || {
+            security_vs_log_q(n, sigma, log_q_min, log_q_max, sieving, max_points)
+        })
+    });
+    Ok(points.iter().map(|p| (p.x, p.bits, p.beta)).collect())
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_resolves_steps() {
+        let pts = security_vs_n(7681, 8.0, 200, 260, false, DEFAULT_MAX_POINTS);
+        assert_eq!(pts.first().unwrap().x, 200.0);
+        assert_eq!(pts.last().unwrap().x, 260.0);
+        assert!(pts.windows(2).all(|w| w[0].x < w[1].x));
+        // Every β change happens between adjacent integers
+        for w in pts.windows(2) {
+            if w[0].beta != w[1].beta {
+                assert_eq!(w[1].x - w[0].x, 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_q_curve_and_json() {
+        let pts = security_vs_log_q(512, 3.19, 8.0, 16.0, false, 64);
+        assert_eq!(pts.first().unwrap().x, 8.0);
+        assert_eq!(pts.last().unwrap().x, 16.0);
+        assert!(pts.len() <= 64);
+        let json = to_json(&pts[..1], "log2_q");
+        assert!(json.starts_with("[\n  {\"log2_q\": 8, \"bits\": "));
+
+        let inf = CurvePoint { x: 1.0, bits: f64::INFINITY, beta: None };
+        assert!(to_json(&[inf], "n").contains("\"bits\": null"));
+    }
+
+    #[test]
+    fn test_ascii_plot() {
+        let pts = security_vs_n(7681, 8.0, 200, 260, false, DEFAULT_MAX_POINTS);
+        let plot = to_ascii(&pts, "n", true);
+        let lines: Vec<&str> = plot.lines().collect();
+        // Title, plot rows, axis, tick labels, axis name
+        assert_eq!(lines.len(), PLOT_HEIGHT + 4);
+        assert!(plot.is_ascii());
+        // Every column has finite security, so the bottom row is filled
+        assert_eq!(lines[PLOT_HEIGHT].len(), 9 + PLOT_WIDTH);
+        assert!(lines[PLOT_HEIGHT + 2].trim_start().starts_with("200"));
+        assert!(!to_ascii(&pts, "n", false).is_ascii());
+    }
+
+    #[test]
+    fn test_curve_respects_max_points() {
+        let pts = security_vs_n(7681, 8.0, 100, 400, false, 40);
+        assert!(pts.len() <= 40);
+    }
+}
@@ -0,0 +1,149 @@
+//! Process-wide defaults for the Python estimate functions
+//!
+//! `with cryptoparam.config(model="matzov22", quantum=True): ...` reprices
+//! every estimate made inside the block, so notebooks comparing models
+//! need not thread the options through each call. The defaults are global
+//! to the process, not per thread; an explicit `sieving=True` still wins
+//! over a configured model, and [`crate::estimator::Estimator`] keeps its
+//! own options.
+
+use crate::cost_model::{self, CostModel};
+use crate::SecurityEstimate;
+use pyo3::prelude::*;
+use std::sync::RwLock;
+
+/// Options applied to estimates made from Python
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Defaults {
+    /// Cost model to reprice with; None keeps the one the call selects
+    pub model: Option<&'static CostModel>,
+    /// Also fill `quantum_bits`
+    pub quantum: bool,
+}
+
+static CURRENT: RwLock<Defaults> = RwLock::new(Defaults { model: None, quantum: false });
+
+/// Defaults now in effect
+pub fn current() -> Defaults {
+    *CURRENT.read().unwrap()
+}
+
+/// Install `d`, returning the defaults it replaces
+pub fn replace(d: Defaults) -> Defaults {
+    std::mem::replace(&mut *CURRENT.write().unwrap(), d)
+}
+
+/// Apply the current defaults to `r`, computed with the given `sieving`
+/// flag
+pub(crate) fn apply(r: &mut SecurityEstimate, sieving: bool) {
+    let d = current();
+    if let Some(model) = d.model.filter(|_| !sieving) {
+        model.apply(r);
+    }
+    if d.quantum && r.quantum_bits.is_none() {
+        r.add_quantum();
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Defaults installed by a `with cryptoparam.config(...)` block
+#[pyclass(module = "cryptoparam", name = "Config")]
+#[derive(Debug, Clone)]
+pub struct Config {
+    defaults: Defaults,
+    /// Defaults to restore on exit, while the block is active
+    saved: Vec<Defaults>,
+}
+
+#[pymethods]
+impl Config {
+    #[getter]
+    fn cost_model(&self) -> Option<&'static str> {
+        self.defaults.model.map(|m| m.name)
+    }
+
+    #[getter]
+    fn quantum(&self) -> bool {
+        self.defaults.quantum
+    }
+
+    fn __enter__(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        let previous = replace(slf.defaults);
+        slf.saved.push(previous);
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) -> bool {
+        if let Some(previous) = self.saved.pop() {
+            replace(previous);
+        }
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "config(model={}, quantum={})",
+            self.defaults.model.map_or("None".to_string(), |m| format!("{:?}", m.name)),
+            if self.defaults.quantum { "True" } else { "False" }
+        )
+    }
+}
+
+/// Set estimate defaults for the duration of a `with` block.
+///
+/// Applies to estimate_lwe, estimate, estimate_many, estimate_small_secret,
+/// sweep and estimate_lwe_batch. Options left as None keep their current
+/// value, so blocks nest. The defaults are process-wide, not per thread.
+///
+/// Args:
+///     model: Cost model to reprice with, e.g. "matzov22" (default: unchanged)
+///     quantum: Also fill `quantum_bits` (default: unchanged)
+///
+/// Returns:
+///     Config context manager
+///
+/// Example:
+///     >>> with cryptoparam.config(model="matzov22", quantum=True):
+///     ...     r = estimate_lwe(512, 12289, 3.2)
+#[pyfunction]
+#[pyo3(name = "config", signature = (model = None, quantum = None))]
+pub fn py_config(model: Option<&str>, quantum: Option<bool>) -> PyResult<Config> {
+    let mut defaults = current();
+    if let Some(name) = model {
+        defaults.model = Some(cost_model::by_name(name)?);
+    }
+    if let Some(quantum) = quantum {
+        defaults.quantum = quantum;
+    }
+    Ok(Config { defaults, saved: Vec::new() })
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core;
+
+    #[test]
+    fn test_defaults_reprice_and_restore() {
+        let previous = replace(Defaults { model: cost_model::by_name("matzov22").ok(), quantum: true });
+        let mut r = estimate_core(512, 12289, 3.2, false);
+        apply(&mut r, false);
+        assert_eq!(r.provenance.cost_model, "matzov22");
+        assert!(r.quantum_bits.is_some());
+
+        let mut s = estimate_core(512, 12289, 3.2, true);
+        let sieving_bits = s.classical_bits;
+        apply(&mut s, true);
+        assert_eq!(s.classical_bits, sieving_bits);
+
+        assert_eq!(replace(previous).model.map(|m| m.name), Some("matzov22"));
+        assert_eq!((current().model.is_none(), current().quantum), (previous.model.is_none(), previous.quantum));
+    }
+}
@@ -0,0 +1,578 @@
+//! Scheme descriptors and claim verification
+//!
+//! A `SchemeDescriptor` is a JSON or TOML file describing a parameter set
+//! and the security level its authors claim. `verify` runs every attack
+//! that applies and reports pass/fail with the margin, so downstream
+//! libraries can pin their parameters in CI.
+//!
+//! ```toml
+//! name = "kyber768"
+//! problem = "MLWE"
+//! n = 256
+//! k = 3
+//! q = 3329
+//! eta = 2
+//! claimed_bits = 128
+//! ```
+
+use crate::presets::{cbd_sigma, Preset};
+use crate::attacks::descriptor_attacks;
+use crate::cost_model::{self, CostModel};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use jsonschema::JSONSchema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// SIS side of a signature-scheme descriptor
+#[derive(Debug, Clone, PartialEq, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SisDescriptor {
+    /// Rows of A
+    #[schemars(range(min = 1))]
+    pub n: usize,
+    /// Columns of A
+    #[schemars(range(min = 2))]
+    pub m: usize,
+    #[schemars(range(min = 2))]
+    pub q: u64,
+    /// ℓ∞ bound on the solution
+    #[schemars(range(min = 1.0))]
+    pub bound: f64,
+}
+
+/// Parameter set with a claimed security level
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Debug, Clone, PartialEq, Deserialize, JsonSchema)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SchemeDescriptor {
+    #[serde(default)]
+    pub name: String,
+    /// "LWE", "MLWE", "RLWE", ...; informational
+    #[serde(default = "default_problem")]
+    pub problem: String,
+    /// Ring degree, or the LWE dimension when k = 1
+    #[schemars(range(min = 1))]
+    pub n: usize,
+    /// Module rank of the secret
+    #[serde(default = "default_rank")]
+    #[schemars(range(min = 1))]
+    pub k: usize,
+    /// Modulus; may be omitted when `log_q` is given
+    #[serde(default)]
+    pub q: u64,
+    /// log2 q for moduli wider than 64 bits (HE chains); overrides `q`
+    #[serde(default)]
+    #[schemars(range(min = 1.0))]
+    pub log_q: Option<f64>,
+    /// Error standard deviation; derived from `eta` (CBD) when absent
+    pub sigma: Option<f64>,
+    /// Centered-binomial parameter of the error
+    #[schemars(range(min = 1))]
+    pub eta: Option<u32>,
+    /// Security level the authors claim, in bits
+    #[schemars(range(min = 0.0))]
+    pub claimed_bits: f64,
+    /// NIST security category the authors claim, 1 to 5
+    #[serde(default)]
+    #[schemars(range(min = 1, max = 5))]
+    pub category: Option<u8>,
+    pub sis: Option<SisDescriptor>,
+}
+
+fn default_problem() -> String {
+    "LWE".to_string()
+}
+
+fn default_rank() -> usize {
+    1
+}
+
+impl SchemeDescriptor {
+    /// Parse JSON, checked against [`json_schema`]
+    pub fn from_json(s: &str) -> Result<Self, String> {
+        let v: serde_json::Value = serde_json::from_str(s).map_err(|e| format!("invalid descriptor JSON: {}", e))?;
+        Self::from_value(v)
+    }
+
+    /// Parse TOML, checked against [`json_schema`]
+    pub fn from_toml(s: &str) -> Result<Self, String> {
+        let v: serde_json::Value = toml::from_str(s).map_err(|e| format!("invalid descriptor TOML: {}", e))?;
+        Self::from_value(v)
+    }
+
+    fn from_value(v: serde_json::Value) -> Result<Self, String> {
+        check_schema(&v)?;
+        let d: Self = serde_json::from_value(v).map_err(|e| format!("invalid descriptor: {}", e))?;
+        d.validate()
+    }
+
+    /// Load a `.json` or `.toml` file; other extensions try JSON, then TOML
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json(&text),
+            Some("toml") => Self::from_toml(&text),
+            _ => Self::from_json(&text).or_else(|_| Self::from_toml(&text)),
+        }
+    }
+
+    fn validate(self) -> Result<Self, String> {
+        if self.n == 0 || self.k == 0 {
+            return Err("n and k must be positive".to_string());
+        }
+        match self.log_q {
+            Some(l) if l < 1.0 => return Err("log_q must be >= 1".to_string()),
+            None if self.q < 2 => return Err("q must be >= 2".to_string()),
+            _ => {}
+        }
+        match (self.sigma, self.eta) {
+            (Some(s), _) if s <= 0.0 => Err("sigma must be positive".to_string()),
+            (None, None) => Err("descriptor needs sigma or eta".to_string()),
+            (None, Some(0)) => Err("eta must be positive".to_string()),
+            _ => Ok(self),
+        }
+    }
+
+    /// Error standard deviation, from `sigma` or CBD(`eta`)
+    pub fn error_sigma(&self) -> f64 {
+        self.sigma.unwrap_or_else(|| cbd_sigma(self.eta.unwrap_or(1)))
+    }
+
+    /// log2 of the modulus, from `log_q` or `q`
+    pub fn log2_q(&self) -> f64 {
+        self.log_q.unwrap_or((self.q as f64).log2())
+    }
+
+    /// LWE dimension seen by lattice attacks, n·k
+    pub fn dimension(&self) -> usize {
+        self.n * self.k
+    }
+
+    /// Plain-LWE descriptor with no claim, for ad-hoc (n, q, σ)
+    pub fn from_lwe(n: usize, q: u64, sigma: f64) -> Self {
+        Self {
+            name: String::new(),
+            problem: "LWE".to_string(),
+            n,
+            k: 1,
+            q,
+            log_q: None,
+            sigma: Some(sigma),
+            eta: None,
+            claimed_bits: 0.0,
+            category: None,
+            sis: None,
+        }
+    }
+
+    /// Descriptor of a built-in preset, claiming its published level
+    pub fn from_preset(p: &Preset) -> Self {
+        Self {
+            name: p.name.clone(),
+            problem: p.problem.clone(),
+            n: p.n,
+            k: p.k,
+            q: p.q,
+            log_q: None,
+            sigma: Some(p.sigma),
+            eta: p.eta,
+            claimed_bits: p.claimed_bits,
+            category: Some(p.nist_level),
+            sis: p.sis.as_ref().map(|s| SisDescriptor { n: s.n, m: s.m, q: s.q, bound: s.bound }),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SchemeDescriptor {
+    // Spelled out rather than `get_all`, which `sis` has no Python type for
+    #[getter]
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[getter]
+    fn problem(&self) -> String {
+        self.problem.clone()
+    }
+
+    #[getter]
+    fn n(&self) -> usize {
+        self.n
+    }
+
+    #[getter]
+    fn k(&self) -> usize {
+        self.k
+    }
+
+    #[getter]
+    fn q(&self) -> u64 {
+        self.q
+    }
+
+    #[getter]
+    fn log_q(&self) -> Option<f64> {
+        self.log_q
+    }
+
+    #[getter]
+    fn sigma(&self) -> Option<f64> {
+        self.sigma
+    }
+
+    #[getter]
+    fn eta(&self) -> Option<u32> {
+        self.eta
+    }
+
+    #[getter]
+    fn claimed_bits(&self) -> f64 {
+        self.claimed_bits
+    }
+
+    #[getter]
+    fn category(&self) -> Option<u8> {
+        self.category
+    }
+
+    /// Descriptor from a dict with the keys of a descriptor file, checked
+    /// against the schema; unknown keys are rejected
+    #[staticmethod]
+    fn from_dict(d: &PyDict) -> PyResult<Self> {
+        let py = d.py();
+        let known = json_schema()["properties"].as_object().map(|p| p.keys().cloned().collect::<Vec<_>>()).unwrap_or_default();
+        for key in d.keys() {
+            let name = key.str()?.to_string();
+            if !known.contains(&name) {
+                return Err(crate::errors::InvalidParameters::new_err(format!(
+                    "unknown key '{}' in descriptor (expected one of {})",
+                    name,
+                    known.join(", ")
+                )));
+            }
+        }
+        let json: String = py.import("json")?.call_method1("dumps", (d,))?.extract()?;
+        Self::from_json(&json).map_err(crate::errors::InvalidParameters::new_err)
+    }
+
+    /// Run the attack suite against the claimed level
+    #[pyo3(name = "verify", signature = (sieving = false, model = None))]
+    fn py_verify(&self, sieving: bool, model: Option<&str>) -> PyResult<VerifyReport> {
+        let model = match model {
+            Some(m) => cost_model::by_name(m)?,
+            None => cost_model::for_sieving(sieving),
+        };
+        Ok(verify_with(self, model))
+    }
+
+    /// lattice-estimator `LWE.Parameters(...)` snippet
+    fn to_lattice_estimator(&self) -> String {
+        crate::lattice_estimator::to_snippet(self)
+    }
+
+    /// Sage script comparing this crate with the lattice-estimator
+    #[pyo3(signature = (sieving = false))]
+    fn to_sage_script(&self, sieving: bool) -> String {
+        crate::lattice_estimator::sage_script(self, sieving)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Same as `__copy__`; the SIS side is owned, not shared
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SchemeDescriptor({}: {} n={}, k={}, log q={:.1}, σ={:.3}, claimed {:.0} bits)",
+            self.name,
+            self.problem,
+            self.n,
+            self.k,
+            self.log2_q(),
+            self.error_sigma(),
+            self.claimed_bits
+        )
+    }
+}
+
+// ============================================================================
+// JSON Schema
+// ============================================================================
+
+/// JSON Schema (draft 7) of the descriptor format
+pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(SchemeDescriptor)).unwrap_or_default()
+}
+
+/// Validate a parsed file against [`json_schema`], one line per violation
+/// as "`<path>`: <problem>"
+pub fn check_schema(v: &serde_json::Value) -> Result<(), String> {
+    static COMPILED: OnceLock<JSONSchema> = OnceLock::new();
+    let schema = COMPILED.get_or_init(|| JSONSchema::compile(&json_schema()).expect("derived schema compiles"));
+    let result = schema.validate(v);
+    let Err(errors) = result else {
+        return Ok(());
+    };
+    let lines: Vec<String> = errors
+        .map(|e| {
+            let path = e.instance_path.to_string();
+            format!("`{}`: {}", if path.is_empty() { "/" } else { &path }, e)
+        })
+        .collect();
+    Err(format!("descriptor does not match the schema:\n  {}", lines.join("\n  ")))
+}
+
+// ============================================================================
+// Verification
+// ============================================================================
+
+/// Bits of one attack in the suite
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttackBits {
+    pub attack: String,
+    pub bits: f64,
+    /// None when the attack is infeasible
+    pub beta: Option<usize>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AttackBits {
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AttackBits({}: {:.1} bits, β={})", self.attack, self.bits, crate::report::beta_cell(self.beta))
+    }
+}
+
+/// Nominal bits of NIST categories 1–5. Categories 2 and 4 are defined by
+/// hash collisions and take the level of the key-search category below.
+pub const CATEGORY_BITS: [f64; 5] = [128.0, 128.0, 192.0, 192.0, 256.0];
+
+/// Highest key-search category (1, 3 or 5) whose nominal level `bits`
+/// reaches; 0 below category 1
+pub fn category_for(bits: f64) -> u8 {
+    [5u8, 3, 1].into_iter().find(|&c| bits >= CATEGORY_BITS[c as usize - 1]).unwrap_or(0)
+}
+
+/// Outcome of checking a descriptor against its claim
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifyReport {
+    pub name: String,
+    pub claimed_bits: f64,
+    /// Cheapest attack in the suite
+    pub estimated_bits: f64,
+    /// estimated_bits − claimed_bits; negative when the claim fails
+    pub margin_bits: f64,
+    /// Category claimed by the descriptor, if any
+    pub claimed_category: Option<u8>,
+    /// Category the estimate reaches, see [`category_for`]
+    pub derived_category: u8,
+    /// Cost model the attacks were priced with
+    pub model: String,
+    /// Both the bit claim and any category claim hold
+    pub passed: bool,
+    pub attacks: Vec<AttackBits>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl VerifyReport {
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Same as `__copy__`; `attacks` is rebuilt, not shared
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "VerifyReport({}: {} — estimated {:.1} bits vs claimed {:.0}, margin {:+.1})",
+            self.name,
+            if self.passed { "PASS" } else { "FAIL" },
+            self.estimated_bits,
+            self.claimed_bits,
+            self.margin_bits
+        )
+    }
+}
+
+/// Run every applicable attack and compare the cheapest to the claim
+pub fn verify(d: &SchemeDescriptor, sieving: bool) -> VerifyReport {
+    verify_with(d, cost_model::for_sieving(sieving))
+}
+
+/// [`verify`] with the attacks priced under `model`
+pub fn verify_with(d: &SchemeDescriptor, model: &CostModel) -> VerifyReport {
+    let attacks: Vec<AttackBits> = descriptor_attacks(d, false)
+        .into_iter()
+        .map(|o| AttackBits {
+            bits: o.cost().map_or(f64::INFINITY, |c| model.cost(c.beta, c.d)),
+            attack: o.attack().to_string(),
+            beta: o.beta(),
+        })
+        .collect();
+
+    let estimated_bits = attacks.iter().map(|a| a.bits).fold(f64::INFINITY, f64::min);
+    let margin_bits = estimated_bits - d.claimed_bits;
+    let derived_category = category_for(estimated_bits);
+    let category_ok = d.category.is_none_or(|c| estimated_bits >= CATEGORY_BITS[c as usize - 1]);
+    VerifyReport {
+        name: d.name.clone(),
+        claimed_bits: d.claimed_bits,
+        estimated_bits,
+        margin_bits,
+        claimed_category: d.category,
+        derived_category,
+        model: model.name.to_string(),
+        passed: margin_bits >= 0.0 && category_ok,
+        attacks,
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Load a scheme descriptor from a JSON or TOML file.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "load_descriptor")]
+pub fn py_load_descriptor(path: &str) -> PyResult<SchemeDescriptor> {
+    SchemeDescriptor::load(Path::new(path)).map_err(crate::errors::InvalidParameters::new_err)
+}
+
+/// JSON Schema of the scheme-descriptor format, as a JSON string.
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "descriptor_schema")]
+pub fn py_descriptor_schema() -> String {
+    serde_json::to_string_pretty(&json_schema()).unwrap_or_default()
+}
+
+/// Verify a descriptor file's claimed security level.
+///
+/// Args:
+///     path: JSON or TOML scheme descriptor file, a SchemeDescriptor, or a
+///         dict of descriptor fields
+///     sieving: Use aggressive sieving cost model (default: False)
+///     model: Cost model name, e.g. "matzov22"; overrides `sieving`
+///
+/// Returns:
+///     VerifyReport with pass/fail, the margin and per-attack bits
+///
+/// Example:
+///     >>> r = verify("kyber768.toml")
+///     >>> assert r.passed, r
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "verify", signature = (path, sieving = false, model = None))]
+pub fn py_verify(path: &PyAny, sieving: bool, model: Option<&str>) -> PyResult<VerifyReport> {
+    extract_descriptor(path)?.py_verify(sieving, model)
+}
+
+/// SchemeDescriptor, a dict of its fields, or the path of a file
+#[cfg(feature = "python")]
+fn extract_descriptor(obj: &PyAny) -> PyResult<SchemeDescriptor> {
+    if let Ok(d) = obj.extract::<SchemeDescriptor>() {
+        return Ok(d);
+    }
+    if let Ok(d) = obj.downcast::<PyDict>() {
+        return SchemeDescriptor::from_dict(d);
+    }
+    let path: std::path::PathBuf = obj.extract().map_err(|_| {
+        pyo3::exceptions::PyTypeError::new_err("expected a SchemeDescriptor, a dict or a descriptor path")
+    })?;
+    SchemeDescriptor::load(&path).map_err(crate::errors::InvalidParameters::new_err)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets;
+
+    #[test]
+    fn test_parse_json_and_toml() {
+        let j = SchemeDescriptor::from_json(
+            r#"{"name": "x", "problem": "MLWE", "n": 256, "k": 3, "q": 3329, "eta": 2, "claimed_bits": 128}"#,
+        )
+        .unwrap();
+        let t = SchemeDescriptor::from_toml(
+            "name = \"x\"\nproblem = \"MLWE\"\nn = 256\nk = 3\nq = 3329\neta = 2\nclaimed_bits = 128\n",
+        )
+        .unwrap();
+        assert_eq!(j, t);
+        assert_eq!(j.dimension(), 768);
+        assert_eq!(j.error_sigma(), 1.0);
+        assert!(SchemeDescriptor::from_json(r#"{"n": 256, "q": 3329, "claimed_bits": 128}"#).is_err());
+    }
+
+    #[test]
+    fn test_schema_errors_name_the_field() {
+        let e = SchemeDescriptor::from_json(r#"{"n": 0, "q": 3329, "eta": 2, "claimed_bits": "high"}"#).unwrap_err();
+        assert!(e.contains("`/n`"), "{}", e);
+        assert!(e.contains("`/claimed_bits`"), "{}", e);
+        let e = SchemeDescriptor::from_toml("n = 256\nq = 3329\neta = 2\n").unwrap_err();
+        assert!(e.contains("claimed_bits"), "{}", e);
+    }
+
+    #[test]
+    fn test_published_schema_is_current() {
+        let published: serde_json::Value =
+            serde_json::from_str(include_str!("../schema/scheme-descriptor.schema.json")).unwrap();
+        assert_eq!(published, json_schema());
+    }
+
+    #[test]
+    fn test_verify_margin() {
+        let mut d = SchemeDescriptor::from_preset(&presets::dilithium2());
+        let r = verify(&d, false);
+        assert_eq!(r.attacks.len(), 4);
+        assert_eq!(r.margin_bits, r.estimated_bits - r.claimed_bits);
+
+        d.claimed_bits = r.estimated_bits + 1.0;
+        assert!(!verify(&d, false).passed);
+    }
+
+    #[test]
+    fn test_verify_category() {
+        assert_eq!([category_for(127.9), category_for(150.0), category_for(192.0), category_for(300.0)], [0, 1, 3, 5]);
+        let mut d = SchemeDescriptor::from_preset(&presets::kyber768());
+        d.claimed_bits = 0.0;
+        let r = verify_with(&d, cost_model::by_name("matzov22").unwrap());
+        assert_eq!((r.claimed_category, r.model.as_str()), (Some(3), "matzov22"));
+        assert_eq!(r.derived_category, category_for(r.estimated_bits));
+        assert!(r.passed);
+        // A wide error still meets 0 claimed bits but not category 3
+        d.sigma = Some(100.0);
+        let r = verify(&d, false);
+        assert!(r.margin_bits >= 0.0 && r.derived_category < 3 && !r.passed);
+    }
+}
@@ -0,0 +1,226 @@
+//! Coefficient distributions of LWE secrets and errors
+//!
+//! The attacks only see a distribution through [`Distribution`], so a new
+//! one is added by implementing the trait rather than by touching each
+//! attack. The primal attack reads the standard deviations; support,
+//! entropy and sparsity are there for attacks that exploit them.
+
+/// Distribution of the coefficients of an n-dimensional secret or error
+pub trait Distribution {
+    /// Name as recorded on an estimate, e.g. "ternary"
+    fn name(&self) -> String;
+
+    /// Variance of one coefficient in dimension n
+    fn variance(&self, n: usize) -> f64;
+
+    /// Largest |x| that can be drawn; None when unbounded
+    fn support_bound(&self) -> Option<u64>;
+
+    /// Shannon entropy in bits of a whole n-coefficient vector
+    fn entropy(&self, n: usize) -> f64;
+
+    /// Whether most coefficients are zero by construction
+    fn is_sparse(&self) -> bool {
+        false
+    }
+
+    /// Standard deviation of one coefficient in dimension n
+    fn std_dev(&self, n: usize) -> f64 {
+        self.variance(n).sqrt()
+    }
+}
+
+/// Discrete Gaussian of width σ, as the error of plain LWE
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscreteGaussian {
+    pub sigma: f64,
+}
+
+impl DiscreteGaussian {
+    pub fn new(sigma: f64) -> Self {
+        Self { sigma }
+    }
+}
+
+impl Distribution for DiscreteGaussian {
+    fn name(&self) -> String {
+        "gaussian".to_string()
+    }
+
+    fn variance(&self, _n: usize) -> f64 {
+        self.sigma * self.sigma
+    }
+
+    fn support_bound(&self) -> Option<u64> {
+        None
+    }
+
+    fn std_dev(&self, _n: usize) -> f64 {
+        self.sigma
+    }
+
+    /// Continuous approximation ½·log2(2πeσ²) per coefficient, accurate
+    /// once σ is above about 1
+    fn entropy(&self, n: usize) -> f64 {
+        n as f64 * 0.5 * (2.0 * std::f64::consts::PI * std::f64::consts::E * self.variance(n)).log2()
+    }
+}
+
+/// Centered binomial of parameter η, the difference of two sums of η bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CenteredBinomial {
+    pub eta: u32,
+}
+
+impl CenteredBinomial {
+    pub fn new(eta: u32) -> Self {
+        Self { eta }
+    }
+}
+
+impl Distribution for CenteredBinomial {
+    fn name(&self) -> String {
+        format!("cbd:{}", self.eta)
+    }
+
+    fn variance(&self, _n: usize) -> f64 {
+        self.eta as f64 / 2.0
+    }
+
+    fn support_bound(&self) -> Option<u64> {
+        Some(self.eta as u64)
+    }
+
+    fn entropy(&self, n: usize) -> f64 {
+        // x + η is binomial(2η, 1/2)
+        let trials = 2 * self.eta as usize;
+        let log2_total = trials as f64;
+        let per_coefficient: f64 = (0..=trials)
+            .map(|k| {
+                let log2_p = log2_binomial(trials, k) - log2_total;
+                -log2_p.exp2() * log2_p
+            })
+            .sum();
+        n as f64 * per_coefficient
+    }
+}
+
+/// Uniform on the integers lo..=hi, e.g. 0..=1 for a binary secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniformInt {
+    pub lo: i64,
+    pub hi: i64,
+}
+
+impl UniformInt {
+    pub fn new(lo: i64, hi: i64) -> Self {
+        Self { lo, hi }
+    }
+
+    fn width(&self) -> f64 {
+        (self.hi - self.lo + 1) as f64
+    }
+}
+
+impl Distribution for UniformInt {
+    fn name(&self) -> String {
+        match (self.lo, self.hi) {
+            (0, 1) => "binary".to_string(),
+            (-1, 1) => "ternary".to_string(),
+            (lo, hi) => format!("uniform:{}..{}", lo, hi),
+        }
+    }
+
+    fn variance(&self, _n: usize) -> f64 {
+        (self.width() * self.width() - 1.0) / 12.0
+    }
+
+    fn support_bound(&self) -> Option<u64> {
+        Some(self.lo.unsigned_abs().max(self.hi.unsigned_abs()))
+    }
+
+    fn entropy(&self, n: usize) -> f64 {
+        n as f64 * self.width().log2()
+    }
+}
+
+/// Ternary with exactly h non-zero coefficients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SparseTernary {
+    pub h: usize,
+}
+
+impl SparseTernary {
+    pub fn new(h: usize) -> Self {
+        Self { h }
+    }
+}
+
+impl Distribution for SparseTernary {
+    fn name(&self) -> String {
+        format!("sparse:{}", self.h)
+    }
+
+    fn variance(&self, n: usize) -> f64 {
+        self.h as f64 / n as f64
+    }
+
+    fn support_bound(&self) -> Option<u64> {
+        Some(1)
+    }
+
+    /// log2 C(n, h) positions plus one sign bit per non-zero coefficient
+    fn entropy(&self, n: usize) -> f64 {
+        log2_binomial(n, self.h.min(n)) + self.h.min(n) as f64
+    }
+
+    fn is_sparse(&self) -> bool {
+        true
+    }
+}
+
+/// log2 of the binomial coefficient C(n, k)
+fn log2_binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k).map(|i| ((n - i) as f64 / (i + 1) as f64).log2()).sum()
+}
+
+/// ln ν of the Bai–Galbraith embedding of a `secret` against an `error`:
+/// the secret coordinates are scaled up to the error width, never down, so
+/// ln ν ≥ 0 and the scaled estimate never exceeds the plain one
+pub fn embedding_log_scale<S, E>(n: usize, secret: &S, error: &E) -> f64
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    (error.std_dev(n) / secret.std_dev(n)).ln().max(0.0)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_moments() {
+        assert_eq!(UniformInt::new(0, 1).variance(512), 0.25);
+        assert!((UniformInt::new(-1, 1).variance(512) - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(CenteredBinomial::new(2).variance(256), 1.0);
+        assert_eq!(SparseTernary::new(64).variance(256), 0.25);
+        assert_eq!(DiscreteGaussian::new(3.2).support_bound(), None);
+        assert_eq!(UniformInt::new(-3, 2).support_bound(), Some(3));
+
+        // CBD(1) is {-1, 0, 1} with probabilities ¼, ½, ¼: 1.5 bits
+        assert!((CenteredBinomial::new(1).entropy(1) - 1.5).abs() < 1e-12);
+        assert_eq!(UniformInt::new(-1, 1).entropy(2), 2.0 * 3f64.log2());
+        assert!((SparseTernary::new(1).entropy(8) - 4.0).abs() < 1e-12);
+        assert!(SparseTernary::new(1).is_sparse() && !UniformInt::new(0, 1).is_sparse());
+
+        let error = DiscreteGaussian::new(3.2);
+        assert_eq!(embedding_log_scale(512, &error, &error), 0.0);
+        assert!((embedding_log_scale(512, &UniformInt::new(0, 1), &error) - 6.4f64.ln()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,177 @@
+//! Dual-hybrid attack on LWE
+//!
+//! The dual attack looks for a short (x, y) with xᵀA ≡ yᵀ (mod q), of
+//! dimension d = m + n and volume q^n, and tells ⟨x, b⟩ = ⟨x, e⟩ + ⟨y, s⟩
+//! (mod q) apart from uniform. BKZ-β finds such vectors of length
+//! ℓ = δ^d·q^{n/d}; the inner product then has width τ = ℓ·σ̄, σ̄² being
+//! the coefficient variance averaged over the m error and n secret
+//! coordinates, and is ε = exp(−2π²τ²/q²) away from uniform. Telling the
+//! two apart takes 1/ε² vectors. As in ADPS16, one sieve call in dimension
+//! β returns 2^{0.2075β} of them, so the attack succeeds at the smallest β
+//! whose sieve output covers 1/ε².
+//!
+//! The hybrid first guesses k secret coordinates and runs the dual on the
+//! remaining n − k. The guess is split in two halves that meet in the
+//! middle against the short vectors: √(candidates) half-guesses are stored
+//! and each is checked against the 1/ε² inner products.
+
+use crate::attacks::SIEVE_MEMORY_EXPONENT;
+use crate::distribution::Distribution;
+use crate::search::bisect;
+use crate::{bkz_cost, delta_0, sample_range, MAX_BETA};
+use std::f64::consts::{LOG2_E, PI};
+
+/// Lattice stage of a dual attack
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualStage {
+    /// None when no β < [`MAX_BETA`] gives enough short vectors
+    pub beta: Option<usize>,
+    /// LWE samples embedded
+    pub m: usize,
+    /// Lattice dimension m + n
+    pub d: usize,
+    /// log2 of the advantage ε one short vector gives
+    pub log2_advantage: f64,
+    /// BKZ-β cost in log2, infinite when infeasible
+    pub bits: f64,
+}
+
+/// Dual-hybrid attack at its cheapest guess
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualHybrid {
+    /// Secret coordinates guessed
+    pub guessed: usize,
+    /// log2 of the candidates for the guessed coordinates
+    pub guess_bits: f64,
+    /// Dual attack on the n − k coordinates left
+    pub stage: DualStage,
+    /// log2 of the lattice stage plus the meet-in-the-middle guessing
+    pub bits: f64,
+}
+
+impl DualHybrid {
+    /// log2 of the half-guesses the meet-in-the-middle stores
+    pub fn mitm_memory_bits(&self) -> f64 {
+        self.guess_bits / 2.0
+    }
+}
+
+/// (m, d, log2 ε) of the dual with BKZ-β on n secret coordinates, at the
+/// sample count that makes the short vectors shortest
+fn dual_at(n: usize, log_q: f64, variances: (f64, f64), samples: &std::ops::Range<usize>, beta: usize) -> (usize, usize, f64) {
+    let (n_f, log_delta) = (n as f64, delta_0(beta).ln());
+    // δ^d·q^{n/d} is least at d = √(n·ln q / ln δ)
+    let d_opt = (n_f * log_q / log_delta).sqrt();
+    let m = ((d_opt - n_f).round().max(0.0) as usize).clamp(samples.start, samples.end - 1);
+    let d_f = (m + n) as f64;
+    let log_len = d_f * log_delta + (n_f / d_f) * log_q;
+    let width = (m as f64 * variances.0 + n_f * variances.1) / d_f;
+    let log_tau = log_len + 0.5 * width.ln();
+    (m, m + n, -2.0 * PI * PI * (2.0 * (log_tau - log_q)).exp() * LOG2_E)
+}
+
+/// Dual attack on n secret coordinates of variance `secret_variance`
+/// against an error of variance `error_variance`
+pub fn dual_stage(
+    n: usize,
+    log2_q: f64,
+    error_variance: f64,
+    secret_variance: f64,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> DualStage {
+    let samples = sample_range(n, max_samples);
+    let log_q = log2_q * std::f64::consts::LN_2;
+    let variances = (error_variance, secret_variance);
+    let infeasible = DualStage { beta: None, m: samples.start, d: samples.start + n, log2_advantage: f64::NEG_INFINITY, bits: f64::INFINITY };
+    if samples.is_empty() {
+        return infeasible;
+    }
+    // A sieve in dimension β covers 1/ε² = 2^{−2·log2 ε} vectors
+    let enough = |beta: u64| {
+        let (_, _, log2_advantage) = dual_at(n, log_q, variances, &samples, beta as usize);
+        -2.0 * log2_advantage <= SIEVE_MEMORY_EXPONENT * beta as f64
+    };
+    let Some(beta) = bisect(2, MAX_BETA as u64 - 1, enough) else {
+        return infeasible;
+    };
+    let beta = beta as usize;
+    let (m, d, log2_advantage) = dual_at(n, log_q, variances, &samples, beta);
+    DualStage { beta: Some(beta), m, d, log2_advantage, bits: bkz_cost(beta, sieving) }
+}
+
+/// Guessing k of the n coordinates on top of the dual on the rest
+fn hybrid_at<S>(n: usize, log2_q: f64, secret: &S, error_variance: f64, max_samples: Option<usize>, sieving: bool, k: usize) -> DualHybrid
+where
+    S: Distribution + ?Sized,
+{
+    let stage = dual_stage(n - k, log2_q, error_variance, secret.variance(n), max_samples, sieving);
+    let guess_bits = secret.entropy(n) * k as f64 / n as f64;
+    let bits = if k == 0 || stage.beta.is_none() {
+        stage.bits
+    } else {
+        // Each stored half-guess is checked against the 1/ε² inner products
+        let guessing = guess_bits / 2.0 - 2.0 * stage.log2_advantage;
+        let (hi, lo) = if guessing > stage.bits { (guessing, stage.bits) } else { (stage.bits, guessing) };
+        hi + (lo - hi).exp2().ln_1p() * LOG2_E
+    };
+    DualHybrid { guessed: k, guess_bits, stage, bits }
+}
+
+/// Cheapest dual-hybrid attack over the guess k ≤ n/2; k = 0 is the plain
+/// dual attack
+pub fn dual_hybrid<S, E>(n: usize, log2_q: f64, secret: &S, error: &E, max_samples: Option<usize>, sieving: bool) -> DualHybrid
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let error_variance = error.variance(n);
+    let at = |k: usize| hybrid_at(n, log2_q, secret, error_variance, max_samples, sieving, k);
+    let cheaper = |a: DualHybrid, b: DualHybrid| if b.bits < a.bits { b } else { a };
+    // The cost is unimodal in k in practice: scan a coarse grid, then the
+    // steps either side of its minimum
+    let k_max = n / 2;
+    let step = (k_max / 32).max(1);
+    let coarse = (0..=k_max).step_by(step).map(at).fold(at(0), cheaper);
+    let lo = coarse.guessed.saturating_sub(step - 1);
+    let hi = (coarse.guessed + step - 1).min(k_max);
+    (lo..=hi).map(at).fold(coarse, cheaper)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{DiscreteGaussian, UniformInt};
+
+    #[test]
+    fn test_dual_stage() {
+        let log2_q = 12289f64.log2();
+        let r = dual_stage(512, log2_q, 3.2 * 3.2, 3.2 * 3.2, None, false);
+        let beta = r.beta.unwrap();
+        assert_eq!(r.bits, bkz_cost(beta, false));
+        assert_eq!(r.d, r.m + 512);
+        // β is the smallest block size whose sieve output is large enough
+        assert!(-2.0 * r.log2_advantage <= SIEVE_MEMORY_EXPONENT * beta as f64);
+        let below = dual_at(512, log2_q * std::f64::consts::LN_2, (3.2 * 3.2, 3.2 * 3.2), &sample_range(512, None), beta - 1);
+        assert!(-2.0 * below.2 > SIEVE_MEMORY_EXPONENT * (beta - 1) as f64);
+        // Fewer samples can only make the attack harder
+        let bounded = dual_stage(512, log2_q, 3.2 * 3.2, 3.2 * 3.2, Some(r.m / 2), false);
+        assert!(bounded.m <= r.m / 2 && bounded.beta >= r.beta);
+    }
+
+    #[test]
+    fn test_hybrid_guesses_small_secrets() {
+        let log2_q = 12289f64.log2();
+        let error = DiscreteGaussian::new(3.2);
+        let gaussian = dual_hybrid(1024, log2_q, &error, &error, None, false);
+        let binary = dual_hybrid(1024, log2_q, &UniformInt::new(0, 1), &error, None, false);
+        assert!(binary.bits <= gaussian.bits);
+        assert!(binary.bits <= hybrid_at(1024, log2_q, &UniformInt::new(0, 1), 3.2 * 3.2, None, false, 0).bits);
+        assert_eq!(binary.mitm_memory_bits(), binary.guess_bits / 2.0);
+        assert_eq!(binary.stage.d, binary.stage.m + 1024 - binary.guessed);
+    }
+}
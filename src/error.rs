@@ -0,0 +1,106 @@
+//! Typed errors from the estimator core
+//!
+//! Rust callers match on [`CryptoParamError`]; the Python bindings turn it
+//! into the matching exception class of [`crate::errors`] at the boundary,
+//! so messages read the same from either side.
+
+/// Why a core call could not produce a result
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CryptoParamError {
+    /// The LWE dimension n is out of range, e.g. n = 0
+    #[error("{0}")]
+    InvalidDimension(String),
+    /// The modulus q is out of range, e.g. q < 2
+    #[error("{0}")]
+    InvalidModulus(String),
+    /// The error standard deviation σ is out of range, e.g. σ ≤ 0
+    #[error("{0}")]
+    InvalidSigma(String),
+    /// No value in the search range reaches the target
+    #[error("{0}")]
+    SearchFailed(String),
+    /// q is too large for log2 q to be computed exactly in f64
+    #[error("{0}")]
+    PrecisionLoss(String),
+    /// An estimator option is unknown or out of range, e.g. an attack
+    /// that is not implemented or m_max = 0
+    #[error("{0}")]
+    InvalidOption(String),
+    /// A config, profile, baseline or imported parameter file cannot be
+    /// read or does not parse
+    #[error("{0}")]
+    InvalidInput(String),
+}
+
+#[cfg(feature = "python")]
+impl From<CryptoParamError> for pyo3::PyErr {
+    fn from(e: CryptoParamError) -> Self {
+        match e {
+            CryptoParamError::SearchFailed(_) => crate::errors::SearchFailed::new_err(e.to_string()),
+            _ => crate::errors::InvalidParameters::new_err(e.to_string()),
+        }
+    }
+}
+
+/// Err unless n > 0
+pub fn check_dimension(n: usize) -> Result<(), CryptoParamError> {
+    if n == 0 {
+        return Err(CryptoParamError::InvalidDimension("n must be positive".to_string()));
+    }
+    Ok(())
+}
+
+/// Err unless q >= 2
+pub fn check_modulus(q: u64) -> Result<(), CryptoParamError> {
+    if q < 2 {
+        return Err(CryptoParamError::InvalidModulus("q must be >= 2".to_string()));
+    }
+    Ok(())
+}
+
+/// Err unless σ is finite and > 0; NaN fails both
+pub fn check_sigma(sigma: f64) -> Result<(), CryptoParamError> {
+    if !sigma.is_finite() {
+        return Err(CryptoParamError::InvalidSigma(format!("sigma must be finite, got {}", sigma)));
+    }
+    if sigma <= 0.0 {
+        return Err(CryptoParamError::InvalidSigma("sigma must be positive".to_string()));
+    }
+    Ok(())
+}
+
+/// log2 q, or PrecisionLoss when q exceeds 2^53 and the f64 conversion
+/// rounds it
+pub fn exact_log2_q(q: u64) -> Result<f64, CryptoParamError> {
+    if q > crate::warnings::F64_EXACT_Q {
+        return Err(CryptoParamError::PrecisionLoss(format!(
+            "q = {} exceeds 2^53; log2 q is computed in rounded f64",
+            q
+        )));
+    }
+    Ok((q as f64).log2())
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checks_name_the_parameter() {
+        assert!(matches!(check_dimension(0), Err(CryptoParamError::InvalidDimension(_))));
+        assert!(matches!(check_modulus(1), Err(CryptoParamError::InvalidModulus(_))));
+        assert!(matches!(check_sigma(0.0), Err(CryptoParamError::InvalidSigma(_))));
+        assert_eq!(check_sigma(-1.0).unwrap_err().to_string(), "sigma must be positive");
+        for sigma in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(check_sigma(sigma), Err(CryptoParamError::InvalidSigma(_))));
+        }
+        assert!(check_dimension(512).and(check_modulus(12289)).and(check_sigma(3.2)).is_ok());
+
+        assert_eq!(exact_log2_q(1 << 20), Ok(20.0));
+        assert!(matches!(exact_log2_q(u64::MAX), Err(CryptoParamError::PrecisionLoss(_))));
+    }
+}
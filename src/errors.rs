@@ -0,0 +1,35 @@
+//! Python exception and warning classes
+//!
+//! Every error derives from `CryptoParamError`, itself a `ValueError`, so
+//! callers can catch estimator failures specifically while code written
+//! against the former bare `ValueError` keeps working. Caveats about a
+//! result are issued as `CryptoParamWarning` through `warnings.warn`.
+
+use pyo3::create_exception;
+use pyo3::prelude::*;
+
+create_exception!(cryptoparam, CryptoParamError, pyo3::exceptions::PyValueError, "Base class of cryptoparam errors.");
+create_exception!(cryptoparam, InvalidParameters, CryptoParamError, "A parameter is out of range, e.g. n = 0 or q < 2.");
+create_exception!(
+    cryptoparam,
+    UnsupportedDistribution,
+    CryptoParamError,
+    "A secret or error distribution name is not recognised."
+);
+create_exception!(cryptoparam, SearchFailed, CryptoParamError, "No parameter value reaches the requested target.");
+create_exception!(
+    cryptoparam,
+    CryptoParamWarning,
+    pyo3::exceptions::PyUserWarning,
+    "Caveat about an estimate, e.g. a small σ or a capped sample count."
+);
+
+/// Add the exception and warning classes to the module
+pub fn register(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("CryptoParamError", py.get_type::<CryptoParamError>())?;
+    m.add("InvalidParameters", py.get_type::<InvalidParameters>())?;
+    m.add("UnsupportedDistribution", py.get_type::<UnsupportedDistribution>())?;
+    m.add("SearchFailed", py.get_type::<SearchFailed>())?;
+    m.add("CryptoParamWarning", py.get_type::<CryptoParamWarning>())?;
+    Ok(())
+}
@@ -0,0 +1,415 @@
+//! Reusable estimator configuration
+//!
+//! [`EstimateConfig`] bundles the options every estimate call would
+//! otherwise take — cost model, attack selection, sample bound, secret
+//! distribution, success probability, quantum bits and the margin a target
+//! is checked with — so they are set once and reused. The CLI maps its
+//! flags onto one, and [`Estimator`] exposes it to Python.
+//!
+//! The config wraps the core rather than being threaded through it:
+//! [`crate::estimate_core`] keeps its plain `sieving` flag, and
+//! [`EstimateConfig::estimate`] prices and adjusts the core's result.
+
+use crate::attacks::{attack_names, check_attack};
+use crate::cost_model::{self, CostModel};
+use crate::error::CryptoParamError;
+use crate::search::SecurityMargin;
+use crate::secret::SecretDist;
+use crate::{estimate_bounded, LweParams, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::attacks::{apply_plugins, LWE_ATTACKS};
+#[cfg(feature = "python")]
+use crate::{extract_params, parallel, Sigma};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// Options for an estimate, set with chained calls
+///
+/// ```
+/// use cryptoparam::estimator::EstimateConfig;
+/// let config = EstimateConfig::new().cost_model("matzov22").unwrap().quantum(true);
+/// let r = config.estimate(512, 12289, 3.2).unwrap();
+/// assert!(r.quantum_bits.is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EstimateConfig {
+    model: &'static CostModel,
+    attacks: Vec<String>,
+    max_samples: Option<usize>,
+    secret: SecretDist,
+    success_probability: f64,
+    quantum: bool,
+    margin: SecurityMargin,
+}
+
+impl Default for EstimateConfig {
+    fn default() -> Self {
+        Self {
+            model: cost_model::for_sieving(false),
+            attacks: attack_names(),
+            max_samples: None,
+            secret: SecretDist::Gaussian,
+            success_probability: 1.0,
+            quantum: false,
+            margin: SecurityMargin::None,
+        }
+    }
+}
+
+impl EstimateConfig {
+    /// Core-SVP pricing of every implemented attack, unbounded samples, a
+    /// Gaussian secret, certain success, classical bits only, no margin
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Price BKZ under the model registered as `name`
+    pub fn cost_model(self, name: &str) -> Result<Self, CryptoParamError> {
+        Ok(self.model(cost_model::by_name(name)?))
+    }
+
+    pub fn model(mut self, model: &'static CostModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// The sieving or core-SVP model, as the `sieving` flags elsewhere
+    pub fn sieving(self, sieving: bool) -> Self {
+        self.model(cost_model::for_sieving(sieving))
+    }
+
+    /// Attacks to consider; "all" selects every implemented one, including
+    /// those registered from Python
+    pub fn attacks(mut self, names: Vec<String>) -> Self {
+        self.attacks = names;
+        self
+    }
+
+    /// Attack names with "all" expanded
+    pub fn selected_attacks(&self) -> Vec<String> {
+        if self.attacks.iter().any(|a| a == "all") { attack_names() } else { self.attacks.clone() }
+    }
+
+    /// LWE samples available to the attacker; None for unbounded
+    pub fn max_samples(mut self, m_max: Option<usize>) -> Self {
+        self.max_samples = m_max;
+        self
+    }
+
+    pub fn secret(mut self, secret: SecretDist) -> Self {
+        self.secret = secret;
+        self
+    }
+
+    /// Probability p that one attack run succeeds; bits are then
+    /// log2(time / p), the usual bit-security measure
+    pub fn success_probability(mut self, p: f64) -> Self {
+        self.success_probability = p;
+        self
+    }
+
+    /// Also fill `quantum_bits`
+    pub fn quantum(mut self, quantum: bool) -> Self {
+        self.quantum = quantum;
+        self
+    }
+
+    /// Margin added on top of targets passed to [`EstimateConfig::meets`]
+    pub fn margin(mut self, margin: SecurityMargin) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// InvalidOption naming the first option out of range
+    pub fn validate(&self) -> Result<(), CryptoParamError> {
+        let invalid = |msg: String| Err(CryptoParamError::InvalidOption(msg));
+        if self.attacks.is_empty() {
+            return invalid("select at least one attack".to_string());
+        }
+        for name in &self.attacks {
+            check_attack(name).map_err(CryptoParamError::InvalidOption)?;
+        }
+        if self.max_samples == Some(0) {
+            return invalid("m_max must be positive".to_string());
+        }
+        if !(self.success_probability > 0.0 && self.success_probability <= 1.0) {
+            return invalid(format!("success probability {} is not in (0, 1]", self.success_probability));
+        }
+        Ok(())
+    }
+
+    /// Estimate (n, q, σ) under this configuration
+    pub fn estimate(&self, n: usize, q: u64, sigma: f64) -> Result<SecurityEstimate, CryptoParamError> {
+        LweParams::new(n, q, sigma)?;
+        let mut r = self.estimate_log2q(n, (q as f64).log2(), sigma)?;
+        r.q = q;
+        r.warnings.extend(crate::warnings::precision_warning(q));
+        Ok(r)
+    }
+
+    /// [`EstimateConfig::estimate`] with the modulus given as log2 q
+    pub fn estimate_log2q(&self, n: usize, log2_q: f64, sigma: f64) -> Result<SecurityEstimate, CryptoParamError> {
+        self.validate()?;
+        self.secret.check(n).map_err(CryptoParamError::InvalidOption)?;
+        let mut r = estimate_bounded(n, log2_q, sigma, self.secret, self.max_samples, false);
+        self.model.apply(&mut r);
+        if self.quantum {
+            r.add_quantum();
+        }
+        let extra = -self.success_probability.log2();
+        if extra > 0.0 {
+            r.classical_bits += extra;
+            r.quantum_bits = r.quantum_bits.map(|b| b + extra);
+        }
+        Ok(r)
+    }
+
+    /// Target after the configured margin
+    pub fn required_bits(&self, target_bits: f64) -> f64 {
+        self.margin.apply(target_bits)
+    }
+
+    /// Whether `r` reaches `target_bits` plus the configured margin
+    pub fn meets(&self, r: &SecurityEstimate, target_bits: f64) -> bool {
+        r.classical_bits >= self.required_bits(target_bits)
+    }
+}
+
+// ============================================================================
+// Estimator
+// ============================================================================
+
+/// Estimation options applied to every call
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam"))]
+#[derive(Debug, Clone, Default)]
+pub struct Estimator {
+    config: EstimateConfig,
+}
+
+impl Estimator {
+    /// Estimator with every option validated; `attacks` of None selects
+    /// all implemented attacks, including those registered from Python
+    pub fn new(
+        model: &str,
+        attacks: Option<Vec<String>>,
+        max_samples: Option<usize>,
+        secret: &str,
+        quantum: bool,
+    ) -> Result<Self, CryptoParamError> {
+        let mut config = EstimateConfig::new()
+            .cost_model(model)?
+            .max_samples(max_samples)
+            .secret(SecretDist::parse(secret).map_err(CryptoParamError::InvalidOption)?)
+            .quantum(quantum);
+        if let Some(a) = attacks {
+            config = config.attacks(a);
+        }
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    /// Estimator for an already built configuration
+    pub fn from_config(config: EstimateConfig) -> Result<Self, CryptoParamError> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    pub fn config(&self) -> &EstimateConfig {
+        &self.config
+    }
+
+    /// Estimate (n, q, σ) under this configuration
+    pub fn run(&self, n: usize, q: u64, sigma: f64) -> Result<SecurityEstimate, CryptoParamError> {
+        self.config.estimate(n, q, sigma)
+    }
+
+    /// Let the selected Python-registered attacks compete for the headline
+    #[cfg(feature = "python")]
+    fn apply_plugins(&self, py: Python, r: &mut SecurityEstimate) -> PyResult<()> {
+        let c = &self.config;
+        let attacks = c.selected_attacks();
+        if attacks.iter().all(|a| LWE_ATTACKS.contains(&a.as_str())) {
+            return Ok(());
+        }
+        let config = PyDict::new(py);
+        config.set_item("cost_model", c.model.name)?;
+        config.set_item("secret", c.secret.name())?;
+        config.set_item("m_max", c.max_samples)?;
+        apply_plugins(py, r, config, &attacks)
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Estimator {
+    /// Configure estimates once, then call `estimate` repeatedly.
+    ///
+    /// Args:
+    ///     cost_model: "core-svp", "sieving", "quantum", "matzov22", "gates" or "enum"
+    ///         (default: "core-svp")
+    ///     attacks: Attack names to consider, including ones added with
+    ///         register_attack (default: all)
+    ///     m_max: LWE samples available to the attacker (default: unbounded)
+    ///     secret: "gaussian", "binary", "ternary" or "sparse:h"
+    ///         (default: "gaussian")
+    ///     quantum: Also fill `quantum_bits` (default: False)
+    ///     success_probability: Probability p that one attack run
+    ///         succeeds; bits become log2(time / p) (default: 1.0)
+    ///
+    /// Example:
+    ///     >>> est = Estimator(cost_model="matzov22", quantum=True)
+    ///     >>> r = est.estimate(LweParams(512, 12289, 3.2))
+    #[new]
+    #[pyo3(signature = (
+        cost_model = "core-svp",
+        attacks = None,
+        m_max = None,
+        secret = "gaussian",
+        quantum = false,
+        success_probability = 1.0
+    ))]
+    fn py_new(
+        cost_model: &str,
+        attacks: Option<Vec<String>>,
+        m_max: Option<usize>,
+        secret: &str,
+        quantum: bool,
+        success_probability: f64,
+    ) -> PyResult<Self> {
+        SecretDist::parse(secret).map_err(crate::errors::UnsupportedDistribution::new_err)?;
+        let est = Self::new(cost_model, attacks, m_max, secret, quantum)?;
+        Ok(Self::from_config(est.config.success_probability(success_probability))?)
+    }
+
+    #[getter]
+    fn cost_model(&self) -> &'static str {
+        self.config.model.name
+    }
+
+    #[getter]
+    fn attacks(&self) -> Vec<String> {
+        self.config.selected_attacks()
+    }
+
+    #[getter]
+    fn m_max(&self) -> Option<usize> {
+        self.config.max_samples
+    }
+
+    #[getter]
+    fn secret(&self) -> String {
+        self.config.secret.name()
+    }
+
+    #[getter]
+    fn quantum(&self) -> bool {
+        self.config.quantum
+    }
+
+    #[getter]
+    fn success_probability(&self) -> f64 {
+        self.config.success_probability
+    }
+
+    /// Estimate one parameter set, given as LweParams or (n, q, sigma)
+    fn estimate(&self, py: Python, params: &PyAny) -> PyResult<SecurityEstimate> {
+        let p = extract_params(params)?;
+        self.estimate_lwe(py, p.n, p.q, Sigma(p.sigma))
+    }
+
+    /// Estimate (n, q, sigma) given as numbers
+    fn estimate_lwe(&self, py: Python, n: usize, q: u64, sigma: Sigma) -> PyResult<SecurityEstimate> {
+        let sigma = sigma.0;
+        LweParams::checked(n, q, sigma)?;
+        let mut r = self.run(n, q, sigma)?;
+        self.apply_plugins(py, &mut r)?;
+        crate::warnings::emit(py, [&r])?;
+        Ok(r)
+    }
+
+    /// Estimate a list of parameter sets in parallel, releasing the GIL
+    #[pyo3(signature = (params, n_threads = None))]
+    fn estimate_many(&self, py: Python, params: Vec<&PyAny>, n_threads: Option<usize>) -> PyResult<Vec<SecurityEstimate>> {
+        let params = params.into_iter().map(extract_params).collect::<PyResult<Vec<LweParams>>>()?;
+        let mut rows = py.allow_threads(|| {
+            parallel::with_threads(n_threads, || {
+                use rayon::prelude::*;
+                params.par_iter().map(|p| self.run(p.n, p.q, p.sigma)).collect::<Result<Vec<_>, _>>()
+            })
+        })?;
+        // Registered attacks need the GIL, so they run after the parallel part
+        for r in &mut rows {
+            self.apply_plugins(py, r)?;
+        }
+        crate::warnings::emit(py, &rows)?;
+        Ok(rows)
+    }
+
+    fn __repr__(&self) -> String {
+        let c = &self.config;
+        format!(
+            "Estimator(cost_model={:?}, attacks={:?}, m_max={}, secret={:?}, quantum={}, success_probability={})",
+            c.model.name,
+            c.selected_attacks(),
+            c.max_samples.map_or("None".to_string(), |k| k.to_string()),
+            c.secret.name(),
+            if c.quantum { "True" } else { "False" },
+            c.success_probability
+        )
+    }
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core;
+
+    #[test]
+    fn test_estimator_applies_options() {
+        let plain = Estimator::default().run(512, 12289, 3.2).unwrap();
+        assert_eq!(plain.classical_bits, estimate_core(512, 12289, 3.2, false).classical_bits);
+        assert!(plain.quantum_bits.is_none());
+
+        let est = Estimator::new("matzov22", Some(vec!["all".to_string()]), Some(300), "ternary", true).unwrap();
+        let r = est.run(512, 12289, 3.2).unwrap();
+        assert_eq!(r.provenance.cost_model, "matzov22");
+        assert_eq!((r.secret.as_deref(), r.max_samples), (Some("ternary"), Some(300)));
+        assert!(r.quantum_bits.is_some());
+        assert_eq!(est.config().selected_attacks(), crate::attacks::LWE_ATTACKS);
+
+        assert!(Estimator::new("core-svp", Some(vec!["bkw".to_string()]), None, "gaussian", false).is_err());
+        assert!(Estimator::new("core-svp", Some(vec![]), None, "gaussian", false).is_err());
+        assert!(Estimator::new("core-svp", None, None, "sparse:600", false).unwrap().run(512, 12289, 3.2).is_err());
+    }
+
+    #[test]
+    fn test_config_success_probability_and_margin() {
+        let base = EstimateConfig::new().estimate(512, 12289, 3.2).unwrap();
+        let halved = EstimateConfig::new().success_probability(0.5).quantum(true).estimate(512, 12289, 3.2).unwrap();
+        assert_eq!(halved.classical_bits, base.classical_bits + 1.0);
+        assert!(matches!(
+            EstimateConfig::new().success_probability(0.0).validate(),
+            Err(CryptoParamError::InvalidOption(_))
+        ));
+        assert!(matches!(EstimateConfig::new().estimate(0, 12289, 3.2), Err(CryptoParamError::InvalidDimension(_))));
+
+        let config = EstimateConfig::new().margin(SecurityMargin::Bits(16.0));
+        assert_eq!(config.required_bits(128.0), 144.0);
+        assert!(config.meets(&base, base.classical_bits - 16.0));
+        assert!(!config.meets(&base, base.classical_bits));
+    }
+}
@@ -0,0 +1,100 @@
+//! Decryption failure probability
+//!
+//! Gaussian model for Regev/LP-style encryption: the decryption noise
+//! e·r − s·e₁ + e₂ has variance 2nσ⁴ + σ², and a message bit is lost when
+//! the noise exceeds q/4.
+
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use std::f64::consts::LN_2;
+
+/// ln erfc(x) for x ≥ 0, relative error of erfc below 1.2e-7 everywhere.
+///
+/// Evaluated in the log domain so tails like 2^-200 stay representable.
+pub fn ln_erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let ln = t.ln() + poly;
+    if x >= 0.0 {
+        ln
+    } else {
+        (2.0 - ln.exp()).ln()
+    }
+}
+
+/// Standard deviation of the decryption noise
+pub fn decryption_noise_std(n: usize, sigma: f64) -> f64 {
+    (2.0 * n as f64 * sigma.powi(4) + sigma * sigma).sqrt()
+}
+
+/// log2 of the probability that decrypting `message_bits` bits fails
+/// (union bound over bits)
+pub fn failure_log2(n: usize, q: u64, sigma: f64, message_bits: usize) -> f64 {
+    failure_log2_log2q(n, (q as f64).log2(), sigma, message_bits)
+}
+
+/// [`failure_log2`] with the modulus given as log2 q
+pub fn failure_log2_log2q(n: usize, log2_q: f64, sigma: f64, message_bits: usize) -> f64 {
+    let s = decryption_noise_std(n, sigma);
+    let threshold = (log2_q - 2.0).exp2();
+    let per_bit = ln_erfc(threshold / (s * std::f64::consts::SQRT_2)) / LN_2;
+    (per_bit + (message_bits.max(1) as f64).log2()).min(0.0)
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// log2 of the decryption failure probability.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     message_bits: Bits encrypted per ciphertext (default: 256)
+///
+/// Returns:
+///     log2 Pr[failure], e.g. -140.2
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "failure_probability", signature = (n, q, sigma, message_bits = 256))]
+pub fn py_failure_probability(n: usize, q: u64, sigma: Sigma, message_bits: usize) -> PyResult<f64> {
+    let sigma = sigma.0;
+    crate::LweParams::new(n, q, sigma)?;
+    Ok(failure_log2(n, q, sigma, message_bits))
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_erfc() {
+        // erfc(1) = 0.157299..., erfc(10) = 2.088e-45
+        assert!((ln_erfc(1.0).exp() - 0.157_299_2).abs() < 1e-6);
+        assert!((ln_erfc(10.0) - (2.088_487_6e-45f64).ln()).abs() < 1e-5);
+        assert!((ln_erfc(0.0).exp() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_failure_grows_with_n_and_sigma() {
+        let base = failure_log2(256, 1 << 20, 3.0, 256);
+        assert!(failure_log2(512, 1 << 20, 3.0, 256) > base);
+        assert!(failure_log2(256, 1 << 20, 4.0, 256) > base);
+        assert!(failure_log2(256, 1 << 22, 3.0, 256) < base);
+    }
+}
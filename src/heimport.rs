@@ -0,0 +1,239 @@
+//! SEAL and OpenFHE parameter import
+//!
+//! Reads the JSON dump of a SEAL `EncryptionParameters` or an OpenFHE
+//! `CCParams` into a [`SchemeDescriptor`], so the estimate runs on exactly
+//! what the HE library is configured with.
+//!
+//! ```json
+//! {"scheme": "CKKS", "poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}
+//! {"scheme": "CKKS", "ringDim": 16384, "multiplicativeDepth": 10, "scalingModSize": 50}
+//! ```
+
+use crate::descriptor::SchemeDescriptor;
+use crate::error::CryptoParamError;
+use crate::hestd::{max_log_q, HeSecret, HE_STD_N};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::Deserialize;
+use std::path::Path;
+
+/// SEAL's error standard deviation
+pub const SEAL_SIGMA: f64 = 3.2;
+
+/// OpenFHE's default error standard deviation
+pub const OPENFHE_SIGMA: f64 = 3.19;
+
+/// SEAL `EncryptionParameters` fields
+#[derive(Debug, Deserialize)]
+struct SealParams {
+    #[serde(default)]
+    scheme: String,
+    poly_modulus_degree: usize,
+    /// Each entry is a prime, or a bit size when it is at most 64
+    /// (the `CoeffModulus::Create` form)
+    coeff_modulus: Vec<u64>,
+    /// 128, 192 or 256; SEAL's default is 128
+    #[serde(default)]
+    sec_level: Option<u32>,
+}
+
+/// OpenFHE `CCParams` fields, in OpenFHE's setter spelling
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenFheParams {
+    #[serde(default)]
+    scheme: String,
+    /// 0 or absent: chosen from the security level, like OpenFHE does
+    #[serde(default)]
+    ring_dim: usize,
+    #[serde(default = "default_depth")]
+    multiplicative_depth: u32,
+    #[serde(default = "default_scaling_mod_size")]
+    scaling_mod_size: u32,
+    #[serde(default = "default_first_mod_size")]
+    first_mod_size: u32,
+    #[serde(default)]
+    security_level: Option<String>,
+    #[serde(default)]
+    secret_key_dist: Option<String>,
+    #[serde(default)]
+    standard_deviation: Option<f64>,
+}
+
+fn default_depth() -> u32 {
+    1
+}
+
+fn default_scaling_mod_size() -> u32 {
+    50
+}
+
+fn default_first_mod_size() -> u32 {
+    60
+}
+
+/// Descriptor for a SEAL parameter dump
+pub fn from_seal_json(s: &str) -> Result<SchemeDescriptor, CryptoParamError> {
+    let p: SealParams =
+        serde_json::from_str(s).map_err(|e| CryptoParamError::InvalidInput(format!("invalid SEAL parameters: {}", e)))?;
+    if p.coeff_modulus.is_empty() {
+        return Err(CryptoParamError::InvalidModulus("coeff_modulus is empty".to_string()));
+    }
+    let log_q: f64 = p
+        .coeff_modulus
+        .iter()
+        .map(|&m| if m <= 64 { m as f64 } else { (m as f64).log2() })
+        .sum();
+    let claimed = p.sec_level.unwrap_or(128);
+    descriptor("seal", &p.scheme, p.poly_modulus_degree, log_q, SEAL_SIGMA, claimed as f64)
+}
+
+/// Descriptor for an OpenFHE `CCParams` dump.
+///
+/// log q is firstModSize + multiplicativeDepth·scalingModSize; the
+/// key-switching modulus is not included.
+pub fn from_openfhe_json(s: &str) -> Result<SchemeDescriptor, CryptoParamError> {
+    let p: OpenFheParams =
+        serde_json::from_str(s).map_err(|e| CryptoParamError::InvalidInput(format!("invalid OpenFHE parameters: {}", e)))?;
+    let log_q = p.first_mod_size as f64 + p.multiplicative_depth as f64 * p.scaling_mod_size as f64;
+    let level = match p.security_level.as_deref().unwrap_or("HEStd_128_classic") {
+        "HEStd_128_classic" => Some(128),
+        "HEStd_192_classic" => Some(192),
+        "HEStd_256_classic" => Some(256),
+        "HEStd_NotSet" => None,
+        other => return Err(CryptoParamError::InvalidInput(format!("unsupported securityLevel {:?}", other))),
+    };
+    let secret = match p.secret_key_dist.as_deref() {
+        Some("GAUSSIAN") => HeSecret::Gaussian,
+        _ => HeSecret::Ternary,
+    };
+
+    let n = match (p.ring_dim, level) {
+        (0, Some(bits)) => HE_STD_N
+            .iter()
+            .copied()
+            .find(|&n| max_log_q(n, bits, secret).is_some_and(|m| log_q <= m as f64))
+            .ok_or_else(|| CryptoParamError::SearchFailed(format!("no tabulated ring dimension fits log q = {}", log_q)))?,
+        (0, None) => return Err(CryptoParamError::InvalidDimension("ringDim is required with HEStd_NotSet".to_string())),
+        (n, _) => n,
+    };
+    let sigma = p.standard_deviation.unwrap_or(OPENFHE_SIGMA);
+    descriptor("openfhe", &p.scheme, n, log_q, sigma, level.unwrap_or(0) as f64)
+}
+
+/// Pick the importer by `library` ("seal" or "openfhe") and read `path`
+pub fn load(library: &str, path: &Path) -> Result<SchemeDescriptor, CryptoParamError> {
+    let text = std::fs::read_to_string(path).map_err(|e| CryptoParamError::InvalidInput(format!("{}: {}", path.display(), e)))?;
+    match library.to_ascii_lowercase().as_str() {
+        "seal" => from_seal_json(&text),
+        "openfhe" => from_openfhe_json(&text),
+        _ => Err(CryptoParamError::InvalidOption(format!("unknown library {:?} (expected seal or openfhe)", library))),
+    }
+}
+
+fn descriptor(
+    library: &str,
+    scheme: &str,
+    n: usize,
+    log_q: f64,
+    sigma: f64,
+    claimed_bits: f64,
+) -> Result<SchemeDescriptor, CryptoParamError> {
+    if n == 0 || !n.is_power_of_two() {
+        return Err(CryptoParamError::InvalidDimension(format!("ring dimension must be a power of two, got {}", n)));
+    }
+    let name = if scheme.is_empty() {
+        format!("{}-{}", library, n)
+    } else {
+        format!("{}-{}-{}", library, scheme.to_ascii_lowercase(), n)
+    };
+    Ok(SchemeDescriptor {
+        name,
+        problem: "RLWE".to_string(),
+        n,
+        k: 1,
+        q: if log_q < 64.0 { log_q.exp2().round() as u64 } else { u64::MAX },
+        log_q: Some(log_q),
+        sigma: Some(sigma),
+        eta: None,
+        claimed_bits,
+        category: None,
+        sis: None,
+    })
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+#[cfg(feature = "python")]
+fn read_source(source: &str) -> PyResult<String> {
+    if source.trim_start().starts_with('{') {
+        Ok(source.to_string())
+    } else {
+        std::fs::read_to_string(source).map_err(|e| crate::errors::InvalidParameters::new_err(format!("{}: {}", source, e)))
+    }
+}
+
+/// Import SEAL encryption parameters.
+///
+/// Args:
+///     source: Path to a JSON dump, or the JSON text itself
+///
+/// Returns:
+///     SchemeDescriptor claiming SEAL's security level
+///
+/// Example:
+///     >>> d = import_seal('{"poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}')
+///     >>> d.verify()
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "import_seal")]
+pub fn py_import_seal(source: &str) -> PyResult<SchemeDescriptor> {
+    Ok(from_seal_json(&read_source(source)?)?)
+}
+
+/// Import OpenFHE CCParams.
+///
+/// Args:
+///     source: Path to a JSON dump, or the JSON text itself
+///
+/// Returns:
+///     SchemeDescriptor claiming the configured securityLevel
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "import_openfhe")]
+pub fn py_import_openfhe(source: &str) -> PyResult<SchemeDescriptor> {
+    Ok(from_openfhe_json(&read_source(source)?)?)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_bit_sizes_and_primes() {
+        let bits = from_seal_json(r#"{"scheme": "CKKS", "poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}"#)
+            .unwrap();
+        assert_eq!(bits.log_q, Some(200.0));
+        assert_eq!(bits.name, "seal-ckks-8192");
+        let primes = from_seal_json(r#"{"poly_modulus_degree": 4096, "coeff_modulus": [68719403009, 68719230977]}"#)
+            .unwrap();
+        assert!((primes.log2_q() - 72.0).abs() < 0.01);
+        assert!(from_seal_json(r#"{"poly_modulus_degree": 1000, "coeff_modulus": [30]}"#).is_err());
+    }
+
+    #[test]
+    fn test_openfhe_ring_dim_from_level() {
+        let d = from_openfhe_json(r#"{"scheme": "CKKS", "multiplicativeDepth": 3, "scalingModSize": 40}"#).unwrap();
+        // 60 + 3·40 = 180 fits the 8192 row (218) but not 4096 (109)
+        assert_eq!(d.n, 8192);
+        assert_eq!(d.claimed_bits, 128.0);
+        assert_eq!(d.error_sigma(), OPENFHE_SIGMA);
+        assert!(from_openfhe_json(r#"{"securityLevel": "HEStd_NotSet"}"#).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! HomomorphicEncryption.org security standard tables
+//!
+//! The HE Standard (2018) lists, per ring dimension, the largest log2 q that
+//! keeps RLWE with σ ≈ 3.2 at 128, 192 or 256 bits of classical security.
+//! A pair can be checked against the table and against this crate's own
+//! estimate side by side.
+
+use crate::{estimate_core_log2q, SecurityEstimate};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Error standard deviation assumed by the tables
+pub const HE_STD_SIGMA: f64 = 3.2;
+
+/// Tabulated ring dimensions
+pub const HE_STD_N: [usize; 6] = [1024, 2048, 4096, 8192, 16384, 32768];
+
+/// Secret distribution column of the tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeSecret {
+    Ternary,
+    Gaussian,
+}
+
+impl HeSecret {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "ternary" => Ok(Self::Ternary),
+            "gaussian" | "error" => Ok(Self::Gaussian),
+            _ => Err(format!("unknown secret distribution {:?} (expected ternary or gaussian)", s)),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ternary => "ternary",
+            Self::Gaussian => "gaussian",
+        }
+    }
+}
+
+/// Max log2 q per [`HE_STD_N`] entry, classical security
+fn column(secret: HeSecret, security_bits: u32) -> Option<[u32; 6]> {
+    Some(match (secret, security_bits) {
+        (HeSecret::Ternary, 128) => [27, 54, 109, 218, 438, 881],
+        (HeSecret::Ternary, 192) => [19, 37, 75, 152, 305, 611],
+        (HeSecret::Ternary, 256) => [14, 29, 58, 118, 237, 476],
+        (HeSecret::Gaussian, 128) => [29, 56, 111, 220, 440, 883],
+        (HeSecret::Gaussian, 192) => [21, 39, 77, 154, 307, 613],
+        (HeSecret::Gaussian, 256) => [16, 31, 60, 120, 239, 478],
+        _ => return None,
+    })
+}
+
+/// Largest permitted log2 q for ring dimension `n`.
+///
+/// Dimensions between table rows use the next smaller row, the
+/// conservative reading; `None` below 1024 or for a level other than
+/// 128/192/256.
+pub fn max_log_q(n: usize, security_bits: u32, secret: HeSecret) -> Option<u32> {
+    let col = column(secret, security_bits)?;
+    let row = HE_STD_N.iter().rposition(|&t| t <= n)?;
+    Some(col[row])
+}
+
+/// Result of checking (n, log q) against the standard and the estimator
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeStdCheck {
+    pub n: usize,
+    pub log_q: f64,
+    pub security_bits: u32,
+    pub secret: String,
+    /// Table limit for n, None if n is below the table
+    pub table_max_log_q: Option<u32>,
+    pub table_ok: bool,
+    pub estimate: SecurityEstimate,
+    pub estimate_ok: bool,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl HeStdCheck {
+    fn __repr__(&self) -> String {
+        let limit = self.table_max_log_q.map_or("n/a".to_string(), |m| m.to_string());
+        format!(
+            "HeStdCheck(n={}, log q={}, {}-bit {}: table max {} → {}, estimate {:.1} bits → {})",
+            self.n,
+            self.log_q,
+            self.security_bits,
+            self.secret,
+            limit,
+            if self.table_ok { "ok" } else { "FAIL" },
+            self.estimate.classical_bits,
+            if self.estimate_ok { "ok" } else { "FAIL" }
+        )
+    }
+}
+
+/// Check (n, log2 q) against the table and against the crate's estimate
+pub fn check(
+    n: usize,
+    log_q: f64,
+    security_bits: u32,
+    secret: HeSecret,
+    sieving: bool,
+) -> Result<HeStdCheck, String> {
+    if column(secret, security_bits).is_none() {
+        return Err(format!("security level must be 128, 192 or 256, got {}", security_bits));
+    }
+    let table_max_log_q = max_log_q(n, security_bits, secret);
+    let estimate = estimate_core_log2q(n, log_q, HE_STD_SIGMA, sieving);
+    Ok(HeStdCheck {
+        n,
+        log_q,
+        security_bits,
+        secret: secret.name().to_string(),
+        table_max_log_q,
+        table_ok: table_max_log_q.is_some_and(|m| log_q <= m as f64),
+        estimate_ok: estimate.classical_bits >= security_bits as f64,
+        estimate,
+    })
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Check (n, log q) against the HomomorphicEncryption.org standard.
+///
+/// Args:
+///     n: Ring dimension
+///     log_q: log2 of the full modulus
+///     security_bits: 128, 192 or 256 (default: 128)
+///     secret: "ternary" or "gaussian" (default: "ternary")
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     HeStdCheck with the table verdict and the crate's own estimate
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "he_standard_check",
+    signature = (n, log_q, security_bits = 128, secret = "ternary", sieving = false)
+)]
+pub fn py_he_standard_check(
+    n: usize,
+    log_q: f64,
+    security_bits: u32,
+    secret: &str,
+    sieving: bool,
+) -> PyResult<HeStdCheck> {
+    if n == 0 || log_q < 1.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0 and log_q >= 1"));
+    }
+    let secret = HeSecret::parse(secret).map_err(crate::errors::UnsupportedDistribution::new_err)?;
+    check(n, log_q, security_bits, secret, sieving).map_err(crate::errors::InvalidParameters::new_err)
+}
+
+/// Largest log2 q the HE standard permits for ring dimension n.
+///
+/// Returns:
+///     int, or None if n is below the smallest tabulated dimension
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "he_standard_max_log_q", signature = (n, security_bits = 128, secret = "ternary"))]
+pub fn py_he_standard_max_log_q(n: usize, security_bits: u32, secret: &str) -> PyResult<Option<u32>> {
+    let secret = HeSecret::parse(secret).map_err(crate::errors::UnsupportedDistribution::new_err)?;
+    if column(secret, security_bits).is_none() {
+        return Err(crate::errors::InvalidParameters::new_err("security_bits must be 128, 192 or 256"));
+    }
+    Ok(max_log_q(n, security_bits, secret))
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_lookup() {
+        assert_eq!(max_log_q(8192, 128, HeSecret::Ternary), Some(218));
+        assert_eq!(max_log_q(32768, 256, HeSecret::Gaussian), Some(478));
+        // Between rows: conservative
+        assert_eq!(max_log_q(6000, 128, HeSecret::Ternary), Some(109));
+        assert_eq!(max_log_q(512, 128, HeSecret::Ternary), None);
+        assert_eq!(max_log_q(8192, 100, HeSecret::Ternary), None);
+    }
+
+    #[test]
+    fn test_check_against_table() {
+        let ok = check(8192, 218.0, 128, HeSecret::Ternary, false).unwrap();
+        assert!(ok.table_ok);
+        let bad = check(8192, 219.0, 128, HeSecret::Ternary, false).unwrap();
+        assert!(!bad.table_ok);
+        assert_eq!(bad.estimate.log2_q, 219.0);
+        assert!(check(8192, 200.0, 100, HeSecret::Ternary, false).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Self-contained HTML reports
+//!
+//! One file with the parameter set, the per-attack breakdown, both cost
+//! models side by side and inline SVG plots of security against n and
+//! log2 q, for attaching to design reviews. No external assets.
+
+use crate::report::beta_cell;
+use crate::curve::{security_vs_log_q, security_vs_n, CurvePoint};
+use crate::{bkz_cost, estimate_core, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Samples per embedded plot
+const PLOT_POINTS: usize = 128;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:60em;margin:2em auto;color:#222}\
+table{border-collapse:collapse;margin:1em 0}th,td{padding:0.3em 0.8em;border-bottom:1px solid #ccc;text-align:right}\
+th:first-child,td:first-child{text-align:left}svg{background:#fafafa;border:1px solid #ddd}";
+
+fn bits_text(bits: f64) -> String {
+    if bits.is_finite() { format!("{:.1}", bits) } else { "∞ (no attack found)".to_string() }
+}
+
+/// Inline SVG line plot of `points`, with a marker at x = `mark`
+pub fn svg_plot(points: &[CurvePoint], x_label: &str, mark: f64) -> String {
+    const W: f64 = 560.0;
+    const H: f64 = 260.0;
+    const PAD: f64 = 44.0;
+    let finite: Vec<&CurvePoint> = points.iter().filter(|p| p.bits.is_finite()).collect();
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return String::new();
+    };
+    let (x0, x1) = (first.x, last.x.max(first.x + 1e-9));
+    let y1 = finite.iter().map(|p| p.bits).fold(1.0, f64::max) * 1.05;
+    let sx = |x: f64| PAD + (x - x0) / (x1 - x0) * (W - 2.0 * PAD);
+    let sy = |y: f64| H - PAD - y / y1 * (H - 2.0 * PAD);
+
+    let path: Vec<String> = finite.iter().map(|p| format!("{:.1},{:.1}", sx(p.x), sy(p.bits))).collect();
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{W}\" height=\"{H}\" viewBox=\"0 0 {W} {H}\">\n"
+    );
+    svg.push_str(&format!(
+        "<line x1=\"{PAD}\" y1=\"{b}\" x2=\"{r}\" y2=\"{b}\" stroke=\"#888\"/><line x1=\"{PAD}\" y1=\"{PAD}\" x2=\"{PAD}\" y2=\"{b}\" stroke=\"#888\"/>\n",
+        b = H - PAD,
+        r = W - PAD
+    ));
+    svg.push_str(&format!(
+        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" font-size=\"12\">{}</text>\n",
+        W / 2.0,
+        H - 10.0,
+        x_label
+    ));
+    svg.push_str(&format!(
+        "<text x=\"12\" y=\"{:.1}\" font-size=\"12\" transform=\"rotate(-90 12 {:.1})\" text-anchor=\"middle\">bits</text>\n",
+        H / 2.0,
+        H / 2.0
+    ));
+    for (x, anchor) in [(x0, "start"), (x1, "end")] {
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"11\" text-anchor=\"{}\">{}</text>\n",
+            sx(x),
+            H - PAD + 14.0,
+            anchor,
+            (x * 10.0).round() / 10.0
+        ));
+    }
+    svg.push_str(&format!(
+        "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"11\" text-anchor=\"end\">{:.0}</text>\n",
+        PAD - 4.0,
+        PAD + 4.0,
+        y1
+    ));
+    svg.push_str(&format!(
+        "<polyline fill=\"none\" stroke=\"#1f6feb\" stroke-width=\"1.5\" points=\"{}\"/>\n",
+        path.join(" ")
+    ));
+    if mark >= x0 && mark <= x1 {
+        svg.push_str(&format!(
+            "<line x1=\"{m:.1}\" y1=\"{PAD}\" x2=\"{m:.1}\" y2=\"{b}\" stroke=\"#d73a49\" stroke-dasharray=\"4 3\"/>\n",
+            m = sx(mark),
+            b = H - PAD
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// BKZ cost of an attack's block size, infinite when it is infeasible
+fn beta_bits(beta: Option<usize>, sieving: bool) -> f64 {
+    beta.map_or(f64::INFINITY, |b| bkz_cost(b, sieving))
+}
+
+fn attack_rows(r: &SecurityEstimate) -> String {
+    format!(
+        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+        r.attack,
+        beta_cell(r.beta),
+        r.d,
+        r.m,
+        bits_text(beta_bits(r.beta, false)),
+        bits_text(beta_bits(r.beta, true))
+    )
+}
+
+/// Full HTML document for one (n, q, σ). `sieving` selects the cost model
+/// used in the plots; the comparison table always shows both.
+pub fn html_report(n: usize, q: u64, sigma: f64, sieving: bool) -> String {
+    let r = estimate_core(n, q, sigma, sieving);
+    let model = if sieving { "sieving" } else { "core-svp" };
+    let title = format!("LWE(n={}, q≈2^{:.1}, σ={})", n, r.log2_q, sigma);
+
+    let by_n = security_vs_n(q, sigma, (n / 2).max(2), n * 2, sieving, PLOT_POINTS);
+    let lo_q = (r.log2_q / 2.0).max(1.0);
+    let by_log_q = security_vs_log_q(n, sigma, lo_q, r.log2_q * 1.5, sieving, PLOT_POINTS);
+
+    let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>CryptoParam report: {}</title>\n<style>{}</style>\n</head>\n<body>\n", title, STYLE));
+    html.push_str(&format!("<h1>{}</h1>\n<p>Estimated security: <strong>{}</strong> bits ({})</p>\n", title, bits_text(r.classical_bits), model));
+
+    html.push_str("<h2>Parameters</h2>\n<table>\n");
+    html.push_str(&format!("<tr><td>n</td><td>{}</td></tr>\n<tr><td>q</td><td>{}</td></tr>\n", n, q));
+    html.push_str(&format!("<tr><td>log2 q</td><td>{:.3}</td></tr>\n<tr><td>σ</td><td>{}</td></tr>\n</table>\n", r.log2_q, sigma));
+
+    html.push_str("<h2>Attacks</h2>\n<table>\n<tr><th>attack</th><th>β</th><th>d</th><th>m</th><th>core-svp bits</th><th>sieving bits</th></tr>\n");
+    html.push_str(&attack_rows(&r));
+    html.push_str("</table>\n");
+
+    html.push_str("<h2>Cost models</h2>\n<table>\n<tr><th>model</th><th>cost</th><th>bits</th></tr>\n");
+    html.push_str(&format!("<tr><td>core-svp</td><td>0.292 β</td><td>{}</td></tr>\n", bits_text(beta_bits(r.beta, false))));
+    html.push_str(&format!("<tr><td>sieving</td><td>0.265 β</td><td>{}</td></tr>\n</table>\n", bits_text(beta_bits(r.beta, true))));
+
+    html.push_str("<h2>Security vs n</h2>\n");
+    html.push_str(&svg_plot(&by_n, "n", n as f64));
+    html.push_str("<h2>Security vs log2 q</h2>\n");
+    html.push_str(&svg_plot(&by_log_q, "log2 q", r.log2_q));
+    html.push_str(&format!("<footer><p><small>{}</small></p></footer>\n", r.provenance.summary()));
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+// ============================================================================
+// Notebook display
+// ============================================================================
+
+/// Rows shown by [`rows_html`] before the remainder is summarised
+pub const NOTEBOOK_MAX_ROWS: usize = 200;
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn warnings_html(r: &SecurityEstimate) -> String {
+    if r.warnings.is_empty() {
+        return String::new();
+    }
+    let items: Vec<String> = r.warnings.iter().map(|w| format!("<li>{}</li>", escape(&w.message))).collect();
+    format!("<ul>{}</ul>\n", items.join(""))
+}
+
+/// HTML fragment for one estimate: headline, attack breakdown, warnings
+pub fn estimate_html(r: &SecurityEstimate) -> String {
+    let mut html = format!(
+        "<div><p><strong>LWE(n={}, q={}, σ={})</strong>: {} bits ({})</p>\n",
+        r.n,
+        r.q,
+        r.sigma,
+        bits_text(r.classical_bits),
+        r.provenance.cost_model
+    );
+    html.push_str("<table>\n<tr><th>attack</th><th>β</th><th>d</th><th>m</th><th>core-svp bits</th><th>sieving bits</th></tr>\n");
+    html.push_str(&attack_rows(r));
+    html.push_str("</table>\n");
+    html.push_str(&warnings_html(r));
+    html.push_str("</div>");
+    html
+}
+
+/// HTML table for many estimates, one row each, truncated after
+/// [`NOTEBOOK_MAX_ROWS`]
+pub fn rows_html(rows: &[SecurityEstimate]) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th>n</th><th>log2 q</th><th>σ</th><th>attack</th><th>β</th><th>d</th><th>m</th><th>bits</th></tr>\n",
+    );
+    for r in rows.iter().take(NOTEBOOK_MAX_ROWS) {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{:.1}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            r.n,
+            r.log2_q,
+            r.sigma,
+            escape(&r.attack),
+            beta_cell(r.beta),
+            r.d,
+            r.m,
+            bits_text(r.classical_bits)
+        ));
+    }
+    html.push_str("</table>\n");
+    if rows.len() > NOTEBOOK_MAX_ROWS {
+        html.push_str(&format!("<p>… {} more rows</p>\n", rows.len() - NOTEBOOK_MAX_ROWS));
+    }
+    html
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Generate a self-contained HTML report for an LWE parameter set.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     sieving: Cost model for the plots (default: False)
+///     path: File to write; None only returns the text (default: None)
+///
+/// Returns:
+///     The HTML document
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "html_report", signature = (n, q, sigma, sieving = false, path = None))]
+pub fn py_html_report(py: Python, n: usize, q: u64, sigma: Sigma, sieving: bool, path: Option<&str>) -> PyResult<String> {
+    let sigma = sigma.0;
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, q >= 2 and sigma > 0"));
+    }
+    let html = py.allow_threads(|| html_report(n, q, sigma, sieving));
+    if let Some(path) = path {
+        std::fs::write(path, &html).map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("{}: {}", path, e)))?;
+    }
+    Ok(html)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_sections() {
+        let html = html_report(256, 7681, 8.0, false);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td>primal_usvp</td><td>250</td>"));
+        assert_eq!(html.matches("<svg").count(), 2);
+        // Self-contained: no linked scripts, stylesheets or images
+        assert!(!html.contains("src=") && !html.contains("<link"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_notebook_fragments() {
+        let r = estimate_core(256, 7681, 2.5, false);
+        let html = estimate_html(&r);
+        assert!(html.contains("<td>primal_usvp</td>"));
+        assert!(html.contains("σ = 2.5 &lt; 3"));
+
+        let rows = vec![r; NOTEBOOK_MAX_ROWS + 5];
+        let table = rows_html(&rows);
+        assert_eq!(table.matches("<tr>").count(), NOTEBOOK_MAX_ROWS + 1);
+        assert!(table.contains("… 5 more rows"));
+    }
+}
@@ -0,0 +1,357 @@
+//! lattice-estimator `LWE.Parameters` snippets
+//!
+//! Converts descriptors to and from the constructor syntax of the Python
+//! lattice-estimator, so the same parameter set can be cross-checked there:
+//!
+//! ```text
+//! LWE.Parameters(n=256*3, q=3329, Xs=ND.CenteredBinomial(2), Xe=ND.CenteredBinomial(2), tag="kyber768")
+//! ```
+//!
+//! Only the fields this crate models are read: `n`, `q`, `Xe` and `tag`.
+//! `Xs` is emitted equal to `Xe` (normal form) and ignored on parse.
+
+use crate::distribution::{Distribution, UniformInt};
+use crate::descriptor::{verify, SchemeDescriptor};
+use crate::presets::cbd_sigma;
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Emit an `LWE.Parameters(...)` snippet for a descriptor
+pub fn to_snippet(d: &SchemeDescriptor) -> String {
+    let n = if d.k > 1 { format!("{}*{}", d.n, d.k) } else { d.n.to_string() };
+    let q = match d.log_q {
+        Some(l) if l >= 64.0 || d.q == 0 => format!("2^{}", trim_float(l)),
+        _ => d.q.to_string(),
+    };
+    let dist = match (d.sigma, d.eta) {
+        (sigma, Some(eta)) if sigma.is_none_or(|s| s == cbd_sigma(eta)) => format!("ND.CenteredBinomial({})", eta),
+        _ => format!("ND.DiscreteGaussian({})", trim_float(d.error_sigma())),
+    };
+    let mut s = format!("LWE.Parameters(n={}, q={}, Xs={}, Xe={}", n, q, dist, dist);
+    if !d.name.is_empty() {
+        s.push_str(&format!(", tag={:?}", d.name));
+    }
+    s.push(')');
+    s
+}
+
+fn trim_float(x: f64) -> String {
+    if x.fract() == 0.0 && x.abs() < 1e15 {
+        format!("{}", x as i64)
+    } else {
+        format!("{}", x)
+    }
+}
+
+/// Parse an `LWE.Parameters(...)` snippet.
+///
+/// `claimed_bits` is not part of the estimator's syntax and must be given.
+pub fn from_snippet(s: &str, claimed_bits: f64) -> Result<SchemeDescriptor, String> {
+    let start = s.find("Parameters(").ok_or("expected LWE.Parameters(...)")? + "Parameters(".len();
+    let end = s.rfind(')').filter(|&e| e >= start).ok_or("unbalanced parentheses")?;
+
+    let mut n = None;
+    let mut log_q = None;
+    let mut sigma = None;
+    let mut eta = None;
+    let mut name = String::new();
+    for arg in split_top_level(&s[start..end]) {
+        let Some((key, value)) = arg.split_once('=') else {
+            return Err(format!("expected keyword argument, got {:?}", arg.trim()));
+        };
+        let value = value.trim();
+        match key.trim() {
+            "n" => n = Some(eval(value)?),
+            "q" => log_q = Some(eval(value)?.log2()),
+            "Xe" => match distribution(value)? {
+                Dist::CenteredBinomial(e) => eta = Some(e),
+                Dist::Sigma(sd) => sigma = Some(sd),
+            },
+            "tag" => name = value.trim_matches(|c| c == '"' || c == '\'').to_string(),
+            _ => {}
+        }
+    }
+
+    let n = n.ok_or("missing n")?;
+    let log_q = log_q.ok_or("missing q")?;
+    if sigma.is_none() && eta.is_none() {
+        return Err("missing Xe".to_string());
+    }
+    if n < 1.0 || n.fract() != 0.0 {
+        return Err(format!("n must be a positive integer, got {}", n));
+    }
+    Ok(SchemeDescriptor {
+        name,
+        problem: "LWE".to_string(),
+        n: n as usize,
+        k: 1,
+        q: if log_q < 64.0 { log_q.exp2().round() as u64 } else { u64::MAX },
+        log_q: if log_q < 64.0 { None } else { Some(log_q) },
+        sigma,
+        eta,
+        claimed_bits,
+        category: None,
+        sis: None,
+    })
+}
+
+/// Sage script running the lattice-estimator on the same parameters.
+///
+/// The crate's own numbers are embedded as comments and printed next to
+/// the estimator's, so one `sage` run gives the side-by-side comparison.
+/// `sieving` selects ADPS16's 0.265β constant (its "quantum" mode) to
+/// match this crate's sieving model; otherwise classical core-SVP.
+pub fn sage_script(d: &SchemeDescriptor, sieving: bool) -> String {
+    let report = verify(d, sieving);
+    let (model, mode) = if sieving { ("sieving", "quantum") } else { ("core-svp", "classical") };
+    let mut s = String::new();
+    s.push_str(&format!("# Cross-check of {} against the lattice-estimator\n", if d.name.is_empty() { "parameters" } else { &d.name }));
+    s.push_str("# Run with: sage this_file.sage  (needs https://github.com/malb/lattice-estimator on the path)\n");
+    s.push_str(&format!("# Generated by cryptoparam {}, {} model\n\n", env!("CARGO_PKG_VERSION"), model));
+    s.push_str("from estimator import *\n\n");
+    s.push_str(&format!("lwe = {}\n", to_snippet(d)));
+    if let Some(sis) = &d.sis {
+        s.push_str(&format!(
+            "sis = SIS.Parameters(n={}, q={}, length_bound={}, m={}, norm=oo)\n",
+            sis.n,
+            sis.q,
+            trim_float(sis.bound),
+            sis.m
+        ));
+    }
+    s.push_str(&format!(
+        "cost_model = lambda beta, d, B=None: RC.ADPS16(beta, d, B, mode=\"{}\")\n\n",
+        mode
+    ));
+    s.push_str("ours = {\n");
+    for a in &report.attacks {
+        s.push_str(&format!("    \"{}\": {},  # β={}\n", a.attack, python_float(a.bits), crate::report::beta_cell(a.beta)));
+    }
+    s.push_str("}\n");
+    s.push_str(&format!("claimed_bits = {}\n\n", python_float(d.claimed_bits)));
+    s.push_str("theirs = {\"primal_usvp\": LWE.primal_usvp(lwe, red_cost_model=cost_model)}\n");
+    if d.sis.is_some() {
+        s.push_str("theirs[\"sis\"] = SIS.lattice(sis, red_cost_model=cost_model)\n");
+    }
+    s.push_str(
+        "\nfor attack, cost in theirs.items():\n\
+         \x20   bits = float(log(cost[\"rop\"], 2))\n\
+         \x20   print(f\"{attack:12} cryptoparam {ours[attack]:8.1f}  lattice-estimator {bits:8.1f}  claimed {claimed_bits}\")\n\
+         \nprint(LWE.estimate.rough(lwe))\n",
+    );
+    s
+}
+
+fn python_float(x: f64) -> String {
+    if x.is_finite() { format!("{:.1}", x) } else { "float(\"inf\")".to_string() }
+}
+
+enum Dist {
+    CenteredBinomial(u32),
+    Sigma(f64),
+}
+
+/// `ND.CenteredBinomial(η)`, `ND.DiscreteGaussian(σ, ...)`, `ND.Uniform(a, b)`
+fn distribution(s: &str) -> Result<Dist, String> {
+    let open = s.find('(').ok_or_else(|| format!("expected a distribution, got {:?}", s))?;
+    let close = s.rfind(')').ok_or("unbalanced parentheses")?;
+    let args = split_top_level(&s[open + 1..close])
+        .iter()
+        .map(|a| eval(a.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let func = s[..open].trim();
+    let func = func.rsplit('.').next().unwrap_or(func);
+    match (func, args.as_slice()) {
+        ("CenteredBinomial", [eta, ..]) if *eta >= 1.0 => Ok(Dist::CenteredBinomial(*eta as u32)),
+        ("DiscreteGaussian", [sd, ..]) if *sd > 0.0 => Ok(Dist::Sigma(*sd)),
+        ("Uniform", [a, b]) if b > a => Ok(Dist::Sigma(UniformInt::new(*a as i64, *b as i64).std_dev(1))),
+        _ => Err(format!("unsupported error distribution {:?}", s)),
+    }
+}
+
+/// Split on commas outside parentheses
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut last = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[last..i]);
+                last = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !s[last..].trim().is_empty() {
+        parts.push(&s[last..]);
+    }
+    parts
+}
+
+/// Evaluate an arithmetic expression with + - * / ^ ** and parentheses
+fn eval(s: &str) -> Result<f64, String> {
+    let tokens: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut pos = 0;
+    let v = expr(&tokens, &mut pos)?;
+    if pos != tokens.len() {
+        return Err(format!("cannot evaluate {:?}", s));
+    }
+    Ok(v)
+}
+
+fn expr(t: &[char], pos: &mut usize) -> Result<f64, String> {
+    let mut v = term(t, pos)?;
+    while let Some(&op) = t.get(*pos).filter(|c| **c == '+' || **c == '-') {
+        *pos += 1;
+        let r = term(t, pos)?;
+        v = if op == '+' { v + r } else { v - r };
+    }
+    Ok(v)
+}
+
+fn term(t: &[char], pos: &mut usize) -> Result<f64, String> {
+    let mut v = power(t, pos)?;
+    while let Some(&op) = t.get(*pos).filter(|c| (**c == '*' && t.get(*pos + 1) != Some(&'*')) || **c == '/') {
+        *pos += 1;
+        let r = power(t, pos)?;
+        v = if op == '*' { v * r } else { v / r };
+    }
+    Ok(v)
+}
+
+fn power(t: &[char], pos: &mut usize) -> Result<f64, String> {
+    let base = atom(t, pos)?;
+    let op_len = match (t.get(*pos), t.get(*pos + 1)) {
+        (Some('^'), _) => 1,
+        (Some('*'), Some('*')) => 2,
+        _ => return Ok(base),
+    };
+    *pos += op_len;
+    Ok(base.powf(power(t, pos)?))
+}
+
+fn atom(t: &[char], pos: &mut usize) -> Result<f64, String> {
+    match t.get(*pos) {
+        Some('(') => {
+            *pos += 1;
+            let v = expr(t, pos)?;
+            if t.get(*pos) != Some(&')') {
+                return Err("unbalanced parentheses".to_string());
+            }
+            *pos += 1;
+            Ok(v)
+        }
+        Some('-') => {
+            *pos += 1;
+            Ok(-atom(t, pos)?)
+        }
+        _ => {
+            let start = *pos;
+            while t.get(*pos).is_some_and(|c| c.is_ascii_digit() || *c == '.' || *c == 'e') {
+                *pos += 1;
+            }
+            let lit: String = t[start..*pos].iter().collect();
+            lit.parse().map_err(|_| format!("expected a number at {:?}", lit))
+        }
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Parse a lattice-estimator LWE.Parameters snippet.
+///
+/// Args:
+///     snippet: e.g. 'LWE.Parameters(n=768, q=3329, Xs=..., Xe=ND.CenteredBinomial(2))'
+///     claimed_bits: Security level to verify against (default: 128)
+///
+/// Returns:
+///     SchemeDescriptor
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "from_lattice_estimator", signature = (snippet, claimed_bits = 128.0))]
+pub fn py_from_lattice_estimator(snippet: &str, claimed_bits: f64) -> PyResult<SchemeDescriptor> {
+    from_snippet(snippet, claimed_bits).map_err(crate::errors::InvalidParameters::new_err)
+}
+
+/// Sage script cross-checking an LWE parameter set with the lattice-estimator.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     sieving: Compare under the sieving model (default: False)
+///     path: File to write; None only returns the text (default: None)
+///
+/// Returns:
+///     Script text; run it with `sage <file>`
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "sage_script", signature = (n, q, sigma, sieving = false, path = None))]
+pub fn py_sage_script(n: usize, q: u64, sigma: Sigma, sieving: bool, path: Option<&str>) -> PyResult<String> {
+    let sigma = sigma.0;
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, q >= 2 and sigma > 0"));
+    }
+    let script = sage_script(&SchemeDescriptor::from_lwe(n, q, sigma), sieving);
+    if let Some(path) = path {
+        std::fs::write(path, &script).map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("{}: {}", path, e)))?;
+    }
+    Ok(script)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets;
+
+    #[test]
+    fn test_snippet_roundtrip() {
+        let d = SchemeDescriptor::from_preset(&presets::frodo640());
+        let s = to_snippet(&d);
+        assert!(s.starts_with("LWE.Parameters(n=640, q=32768, "));
+        let back = from_snippet(&s, d.claimed_bits).unwrap();
+        assert_eq!(back.dimension(), 640);
+        assert_eq!(back.q, 32768);
+        assert!((back.error_sigma() - d.error_sigma()).abs() < 1e-12);
+        assert_eq!(back.name, "frodo640");
+    }
+
+    #[test]
+    fn test_parse_estimator_syntax() {
+        let d = from_snippet(
+            "schemes.Kyber = LWE.Parameters(n=256 * 3, q=3329, Xs=ND.CenteredBinomial(2), \
+             Xe=ND.CenteredBinomial(2), m=256*3, tag=\"Kyber768\")",
+            128.0,
+        )
+        .unwrap();
+        assert_eq!((d.n, d.q, d.eta), (768, 3329, Some(2)));
+        let big = from_snippet("LWE.Parameters(n=2**15, q=2^881, Xs=ND.Uniform(-1, 1), Xe=ND.DiscreteGaussian(3.19))", 128.0)
+            .unwrap();
+        assert_eq!(big.log_q, Some(881.0));
+        assert!(from_snippet("LWE.Parameters(n=512, q=12289)", 128.0).is_err());
+
+        let kyber = to_snippet(&SchemeDescriptor::from_preset(&presets::kyber768()));
+        assert!(kyber.contains("n=256*3") && kyber.contains("Xe=ND.CenteredBinomial(2)"));
+    }
+
+    #[test]
+    fn test_sage_script() {
+        let script = sage_script(&SchemeDescriptor::from_preset(&presets::dilithium2()), true);
+        assert!(script.contains("from estimator import *"));
+        assert!(script.contains("lwe = LWE.Parameters(n=256*4, q=8380417, "));
+        assert!(script.contains("sis = SIS.Parameters(n=1024, q=8380417, "));
+        assert!(script.contains("mode=\"quantum\""));
+        assert!(script.contains("    \"sis\": "));
+    }
+}
@@ -1,88 +1,601 @@
 //! CryptoParam - Plain LWE Security Estimator
 //!
-//! Rust core with Python bindings via PyO3.
+//! Rust core with Python bindings via PyO3, behind the default `python`
+//! feature; `--no-default-features` builds the core and CLI without pyo3.
 
+#![allow(non_local_definitions)]
+
+#[cfg(feature = "python")]
+use pyo3::basic::CompareOp;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use attacks::{AttackCost, AttackOutcome};
+use distribution::Distribution;
+use provenance::Provenance;
+use trace::SearchStep;
+use warnings::Warning;
+use error::CryptoParamError;
+use std::cmp::Ordering;
+#[cfg(feature = "python")]
+use std::collections::hash_map::DefaultHasher;
 use std::f64::consts::{E, PI};
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "python")]
+pub mod aio;
+pub mod attacks;
+pub mod baseline;
+pub mod bgv;
+pub mod budget;
+pub mod chain;
+pub mod checkpoint;
+pub mod ckks;
+pub mod config;
+pub mod cost_model;
+pub mod curve;
+#[cfg(feature = "python")]
+pub mod defaults;
+pub mod descriptor;
+pub mod distribution;
+pub mod dual;
+pub mod error;
+#[cfg(feature = "python")]
+pub mod errors;
+pub mod estimator;
+pub mod explain;
+pub mod failure;
+pub mod heimport;
+pub mod lattice_estimator;
+pub mod hestd;
+pub mod html;
+pub mod optimize;
+pub mod parallel;
+#[cfg(feature = "python")]
+pub mod plot;
+pub mod presets;
+pub mod profile;
+pub mod progress;
+pub mod provenance;
+pub mod report;
+pub mod search;
+pub mod secret;
+pub mod sis;
+pub mod snap;
+pub mod stubgen;
+pub mod sweep;
+pub mod trace;
+pub mod warnings;
 
 // ============================================================================
 // Core Types
 // ============================================================================
 
+/// σ as accepted from Python: any real number (float, int, Fraction,
+/// Decimal) or a decimal or rational string such as "3.19" or "16/5".
+/// Everything but floats goes through an exact `fractions.Fraction`, so
+/// the f64 is the correctly rounded value rather than a chain of float
+/// operations. NaN, infinities and values beyond the f64 range are
+/// rejected with InvalidParameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sigma(pub f64);
+
+#[cfg(feature = "python")]
+impl<'source> FromPyObject<'source> for Sigma {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        let invalid = || errors::InvalidParameters::new_err(format!("invalid sigma {}", ob.repr().map_or(String::new(), |r| r.to_string())));
+        let sigma = match ob.downcast::<pyo3::types::PyFloat>() {
+            Ok(f) => f.value(),
+            Err(_) => {
+                let fraction = ob.py().import("fractions")?.getattr("Fraction")?;
+                let exact = fraction.call1((ob,)).map_err(|e| {
+                    if e.is_instance_of::<pyo3::exceptions::PyTypeError>(ob.py()) {
+                        pyo3::exceptions::PyTypeError::new_err(format!(
+                            "sigma must be a real number or a numeric string, not {}",
+                            ob.get_type().name().unwrap_or("?")
+                        ))
+                    } else {
+                        invalid()
+                    }
+                })?;
+                // Fraction.__float__ raises OverflowError beyond the f64 range, e.g. "1e400"
+                exact.extract().map_err(|_| invalid())?
+            }
+        };
+        if !sigma.is_finite() {
+            return Err(invalid());
+        }
+        Ok(Sigma(sigma))
+    }
+}
+
+/// Required entry of a `from_dict` argument, KeyError when missing
+#[cfg(feature = "python")]
+pub(crate) fn dict_item<'py, T: FromPyObject<'py>>(d: &'py PyDict, key: &str) -> PyResult<T> {
+    match d.get_item(key)? {
+        Some(v) => v.extract(),
+        None => Err(pyo3::exceptions::PyKeyError::new_err(key.to_string())),
+    }
+}
+
+/// Python `__hash__` from the Rust `Hash`
+#[cfg(feature = "python")]
+pub(crate) fn py_hash<T: Hash>(value: &T) -> u64 {
+    let mut h = DefaultHasher::new();
+    value.hash(&mut h);
+    h.finish()
+}
+
 /// LWE problem parameters
-#[pyclass]
+///
+/// Equal when n, q and the bits of σ match, so parameter sets can be
+/// dict keys.
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LweParams {
-    #[pyo3(get)]
     pub n: usize,
-    #[pyo3(get)]
     pub q: u64,
-    #[pyo3(get)]
     pub sigma: f64,
 }
 
+impl LweParams {
+    pub fn new(n: usize, q: u64, sigma: f64) -> Result<Self, CryptoParamError> {
+        error::check_dimension(n)?;
+        error::check_modulus(q)?;
+        error::check_sigma(sigma)?;
+        Ok(Self { n, q, sigma })
+    }
+
+    /// [`LweParams::new`] raising InvalidParameters
+    #[cfg(feature = "python")]
+    pub(crate) fn checked(n: usize, q: u64, sigma: f64) -> PyResult<Self> {
+        Ok(Self::new(n, q, sigma)?)
+    }
+}
+
+#[cfg(feature = "python")]
 #[pymethods]
 impl LweParams {
     #[new]
-    pub fn new(n: usize, q: u64, sigma: f64) -> PyResult<Self> {
-        if n == 0 {
-            return Err(pyo3::exceptions::PyValueError::new_err("n must be positive"));
-        }
-        if q < 2 {
-            return Err(pyo3::exceptions::PyValueError::new_err("q must be >= 2"));
-        }
-        if sigma <= 0.0 {
-            return Err(pyo3::exceptions::PyValueError::new_err("sigma must be positive"));
-        }
-        Ok(Self { n, q, sigma })
+    fn py_new(n: usize, q: u64, sigma: Sigma) -> PyResult<Self> {
+        Self::checked(n, q, sigma.0)
     }
     
+    /// Kyber-512 as plain LWE (n = 512)
+    #[staticmethod]
+    #[pyo3(name = "kyber512_flattened")]
+    fn py_kyber512_flattened() -> Self {
+        Self::kyber512_flattened()
+    }
+
+    /// Kyber-768 as plain LWE (n = 768)
+    #[staticmethod]
+    #[pyo3(name = "kyber768_flattened")]
+    fn py_kyber768_flattened() -> Self {
+        Self::kyber768_flattened()
+    }
+
+    /// Kyber-1024 as plain LWE (n = 1024)
+    #[staticmethod]
+    #[pyo3(name = "kyber1024_flattened")]
+    fn py_kyber1024_flattened() -> Self {
+        Self::kyber1024_flattened()
+    }
+
+    /// FrodoKEM-640 LWE parameters
+    #[staticmethod]
+    #[pyo3(name = "frodo640")]
+    fn py_frodo640() -> Self {
+        Self::frodo640()
+    }
+
+    /// TFHE library default LWE key (n = 630, q = 2^32)
+    #[staticmethod]
+    #[pyo3(name = "tfhe_lwe_default")]
+    fn py_tfhe_lwe_default() -> Self {
+        Self::tfhe_lwe_default()
+    }
+
+    /// Copy with `n` replaced
+    fn with_n(&self, n: usize) -> PyResult<Self> {
+        Self::checked(n, self.q, self.sigma)
+    }
+
+    /// Copy with `q` replaced
+    fn with_q(&self, q: u64) -> PyResult<Self> {
+        Self::checked(self.n, q, self.sigma)
+    }
+
+    /// Copy with `sigma` replaced
+    fn with_sigma(&self, sigma: Sigma) -> PyResult<Self> {
+        Self::checked(self.n, self.q, sigma.0)
+    }
+
+    /// Copy with the given fields replaced, like `dataclasses.replace`;
+    /// LweParams stays immutable so it can key dicts
+    #[pyo3(signature = (*, n = None, q = None, sigma = None))]
+    fn replace(&self, n: Option<usize>, q: Option<u64>, sigma: Option<Sigma>) -> PyResult<Self> {
+        Self::checked(n.unwrap_or(self.n), q.unwrap_or(self.q), sigma.map_or(self.sigma, |s| s.0))
+    }
+
+    /// {"n", "q", "sigma"}
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("n", self.n)?;
+        d.set_item("q", self.q)?;
+        d.set_item("sigma", self.sigma)?;
+        Ok(d)
+    }
+
+    /// Inverse of `to_dict`; extra keys are ignored
+    #[staticmethod]
+    fn from_dict(d: &PyDict) -> PyResult<Self> {
+        Self::checked(dict_item(d, "n")?, dict_item(d, "q")?, dict_item(d, "sigma")?)
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Same as `__copy__`: the fields are plain values
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    /// Rebuilt via the constructor, so pickles are validated on load
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (usize, u64, f64))> {
+        let py = slf.py();
+        let p = slf.borrow();
+        Ok((slf.get_type().into_py(py), (p.n, p.q, p.sigma)))
+    }
+
     fn __repr__(&self) -> String {
         format!("LweParams(n={}, q={}, sigma={})", self.n, self.q, self.sigma)
     }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match (other.extract::<PyRef<Self>>(), op) {
+            (Ok(o), CompareOp::Eq) => (*self == *o).into_py(py),
+            (Ok(o), CompareOp::Ne) => (*self != *o).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        py_hash(self)
+    }
+}
+
+impl PartialEq for LweParams {
+    fn eq(&self, other: &Self) -> bool {
+        (self.n, self.q, self.sigma.to_bits()) == (other.n, other.q, other.sigma.to_bits())
+    }
+}
+
+impl Eq for LweParams {}
+
+impl Hash for LweParams {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.n, self.q, self.sigma.to_bits()).hash(state);
+    }
+}
+
+/// Plain-LWE views of popular presets, for quick comparisons; see
+/// [`presets`] for the full descriptors
+impl LweParams {
+    pub fn kyber512_flattened() -> Self {
+        presets::kyber512().lwe_params()
+    }
+
+    pub fn kyber768_flattened() -> Self {
+        presets::kyber768().lwe_params()
+    }
+
+    pub fn kyber1024_flattened() -> Self {
+        presets::kyber1024().lwe_params()
+    }
+
+    pub fn frodo640() -> Self {
+        presets::frodo640().lwe_params()
+    }
+
+    pub fn tfhe_lwe_default() -> Self {
+        presets::tfhe_lib_lwe().lwe_params()
+    }
 }
 
 /// Security estimation result
-#[pyclass]
+///
+/// Ordered by `classical_bits`, ties broken by the parameters and attack
+/// outcome. Equality and hashing cover the same fields and ignore
+/// `provenance` and `warnings`, so re-running an estimate yields an equal
+/// value.
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityEstimate {
-    #[pyo3(get)]
     pub classical_bits: f64,
-    #[pyo3(get)]
-    pub beta: usize,
-    #[pyo3(get)]
+    /// BKZ block size of the attack; None when no attack is feasible
+    pub beta: Option<usize>,
     pub attack: String,
-    #[pyo3(get)]
     pub d: usize,
-    #[pyo3(get)]
     pub m: usize,
-    #[pyo3(get)]
     pub n: usize,
-    #[pyo3(get)]
     pub q: u64,
-    #[pyo3(get)]
+    /// log2 of the modulus; exact even when q does not fit in 64 bits
+    /// (q is then saturated to u64::MAX)
+    pub log2_q: f64,
     pub sigma: f64,
+    /// How the error width was given when not as σ, e.g. "2^-38·q"
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub noise: Option<String>,
+    /// Secret distribution when not the error distribution, e.g. "ternary"
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub secret: Option<String>,
+    /// Samples available to the attacker, when bounded
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub max_samples: Option<usize>,
+    /// Cost under the quantum model, filled by `--quantum` and
+    /// [`SecurityEstimate::add_quantum`]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub quantum_bits: Option<f64>,
+    /// Crate version, cost model and attacks behind this estimate
+    pub provenance: Provenance,
+    /// Caveats about this result
+    pub warnings: Vec<Warning>,
+    /// Every (m, β, cost) the optimizer considered; only when requested
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub trace: Option<Vec<SearchStep>>,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl SecurityEstimate {
     fn __repr__(&self) -> String {
-        let q_bits = (self.q as f64).log2();
-        if self.beta >= 10000 {
-            format!(
-                "LWE(n={}, q≈2^{:.0}, σ={}): No lattice attack found",
-                self.n, q_bits, self.sigma
-            )
-        } else {
-            format!(
-                "LWE(n={}, q≈2^{:.0}, σ={}): ~{:.0} bits ({}, β={})",
-                self.n, q_bits, self.sigma, self.classical_bits, self.attack, self.beta
-            )
-        }
+        self.to_string()
     }
     
     fn __str__(&self) -> String {
-        self.__repr__()
+        self.to_string()
+    }
+
+    /// Jupyter rich display
+    fn _repr_html_(&self) -> String {
+        html::estimate_html(self)
+    }
+
+    /// Step-by-step derivation: embedding, success condition, block size,
+    /// GSA slope and cost
+    fn explain(&self) -> String {
+        explain::explain(self)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match other.extract::<PyRef<Self>>() {
+            Ok(o) => op.matches(self.cmp(&o)).into_py(py),
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        py_hash(self)
+    }
+
+    /// Every field by name, ready for json.dumps or a DataFrame row
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("classical_bits", self.classical_bits)?;
+        d.set_item("beta", self.beta)?;
+        d.set_item("attack", &self.attack)?;
+        d.set_item("d", self.d)?;
+        d.set_item("m", self.m)?;
+        d.set_item("n", self.n)?;
+        d.set_item("q", self.q)?;
+        d.set_item("log2_q", self.log2_q)?;
+        d.set_item("sigma", self.sigma)?;
+        if let Some(noise) = &self.noise {
+            d.set_item("noise", noise)?;
+        }
+        if let Some(secret) = &self.secret {
+            d.set_item("secret", secret)?;
+        }
+        if let Some(k) = self.max_samples {
+            d.set_item("max_samples", k)?;
+        }
+        if let Some(bits) = self.quantum_bits {
+            d.set_item("quantum_bits", bits)?;
+        }
+        d.set_item("provenance", self.provenance.to_dict(py)?)?;
+        let warnings = self.warnings.iter().map(|w| w.to_dict(py)).collect::<PyResult<Vec<_>>>()?;
+        d.set_item("warnings", warnings)?;
+        if let Some(trace) = &self.trace {
+            let steps = trace.iter().map(|s| s.to_dict(py)).collect::<PyResult<Vec<_>>>()?;
+            d.set_item("trace", steps)?;
+        }
+        Ok(d)
+    }
+
+    /// Inverse of `to_dict`; `log2_q` defaults to log2(q), a missing
+    /// `provenance` to this build's core-SVP model stamped now and missing
+    /// `warnings` are recomputed; `noise`, `secret`, `max_samples`, `quantum_bits` and `trace` are
+    /// optional
+    #[staticmethod]
+    fn from_dict(d: &PyDict) -> PyResult<Self> {
+        let q: u64 = dict_item(d, "q")?;
+        let log2_q = match d.get_item("log2_q")? {
+            Some(v) => v.extract()?,
+            None => (q as f64).log2(),
+        };
+        let (n, sigma, m) = (dict_item(d, "n")?, dict_item(d, "sigma")?, dict_item(d, "m")?);
+        // None for an infeasible attack; older releases stored MAX_BETA
+        let beta = dict_item::<Option<usize>>(d, "beta")?.filter(|&b| b < MAX_BETA);
+        let warnings = match d.get_item("warnings")? {
+            Some(ws) => ws
+                .iter()?
+                .map(|w| Warning::from_py_dict(w?.downcast()?))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => warnings::lwe_warnings(n, sigma, beta, m),
+        };
+        let trace = match d.get_item("trace")? {
+            Some(t) if !t.is_none() => Some(
+                t.iter()?
+                    .map(|s| SearchStep::from_py_dict(s?.downcast()?))
+                    .collect::<PyResult<Vec<_>>>()?,
+            ),
+            _ => None,
+        };
+        Ok(Self {
+            classical_bits: dict_item(d, "classical_bits")?,
+            beta,
+            attack: dict_item(d, "attack")?,
+            d: dict_item(d, "d")?,
+            m,
+            n,
+            q,
+            log2_q,
+            sigma,
+            noise: d.get_item("noise")?.filter(|v| !v.is_none()).map(|v| v.extract()).transpose()?,
+            secret: d.get_item("secret")?.filter(|v| !v.is_none()).map(|v| v.extract()).transpose()?,
+            max_samples: d.get_item("max_samples")?.filter(|v| !v.is_none()).map(|v| v.extract()).transpose()?,
+            quantum_bits: d.get_item("quantum_bits")?.filter(|v| !v.is_none()).map(|v| v.extract()).transpose()?,
+            warnings,
+            trace,
+            provenance: match d.get_item("provenance")? {
+                Some(p) => Provenance::from_py_dict(p.downcast()?)?,
+                None => Provenance::new(false, &["primal_usvp"]),
+            },
+        })
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Deep already: warnings, trace and provenance are owned Rust values,
+    /// not shared Python objects
+    fn __deepcopy__(&self, _memo: &PyDict) -> Self {
+        self.clone()
+    }
+
+    /// Pickled as `from_dict(to_dict())`
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (&PyDict,))> {
+        let py = slf.py();
+        let from_dict = slf.get_type().getattr("from_dict")?;
+        Ok((from_dict.into_py(py), (slf.borrow().to_dict(py)?,)))
+    }
+}
+
+impl SecurityEstimate {
+    /// Price the same attack under [`cost_model::quantum`] as well
+    pub fn add_quantum(&mut self) {
+        self.quantum_bits = Some(cost_model::quantum().price(self.beta, self.d));
+    }
+
+    /// The cheapest attack as an [`AttackOutcome`]
+    pub fn outcome(&self) -> AttackOutcome {
+        match self.beta {
+            Some(beta) => AttackOutcome::Feasible(AttackCost {
+                attack: self.attack.clone(),
+                bits: self.classical_bits,
+                beta,
+                d: self.d,
+                samples: self.m,
+                memory_bits: attacks::memory_bits(beta),
+            }),
+            None => AttackOutcome::Infeasible { attack: self.attack.clone() },
+        }
+    }
+
+    fn sort_key(&self) -> (u64, usize, u64, u64, u64, &str, Option<usize>, usize, usize) {
+        (
+            self.classical_bits.to_bits(),
+            self.n,
+            self.q,
+            self.log2_q.to_bits(),
+            self.sigma.to_bits(),
+            &self.attack,
+            self.beta,
+            self.m,
+            self.d,
+        )
+    }
+}
+
+/// One line by default; the alternate form (`{:#}`) is the multi-line
+/// breakdown of `cryptoparam --verbose`
+impl std::fmt::Display for SecurityEstimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut noise = self.noise.as_ref().map_or(String::new(), |n| format!(" from {}", n));
+        if let Some(secret) = &self.secret {
+            noise.push_str(&format!(", {} secret", secret));
+        }
+        if let Some(k) = self.max_samples {
+            noise.push_str(&format!(", ≤{} samples", k));
+        }
+        if !f.alternate() {
+            return match self.beta {
+                None => write!(f, "LWE(n={}, q≈2^{:.0}, σ={}{}): No lattice attack found", self.n, self.log2_q, self.sigma, noise),
+                Some(beta) => {
+                    let quantum = self.quantum_bits.map_or(String::new(), |q| format!(" classical, ~{:.0} quantum", q));
+                    write!(
+                        f,
+                        "LWE(n={}, q≈2^{:.0}, σ={}{}): ~{:.0} bits{} ({}, β={})",
+                        self.n, self.log2_q, self.sigma, noise, self.classical_bits, quantum, self.attack, beta
+                    )
+                }
+            };
+        }
+        let model = cost_model::by_id(&self.provenance.cost_model).map_or(self.provenance.cost_model.as_str(), |m| m.name);
+        writeln!(f, "Parameters:")?;
+        writeln!(f, "  n     = {}", self.n)?;
+        writeln!(f, "  q     = {} (≈2^{:.1})", self.q, self.log2_q)?;
+        writeln!(f, "  σ     = {}{}", self.sigma, noise)?;
+        writeln!(f)?;
+        writeln!(f, "Attack: {}", self.attack)?;
+        writeln!(f, "  β     = {}", self.beta.map_or("—".to_string(), |b| b.to_string()))?;
+        writeln!(f, "  d     = {}", self.d)?;
+        writeln!(f, "  m     = {}", self.m)?;
+        writeln!(f)?;
+        if self.beta.is_none() {
+            return write!(f, "Security: No lattice attack found");
+        }
+        write!(f, "Security: {:.1} bits ({})", self.classical_bits, model)?;
+        match self.quantum_bits {
+            Some(q) => write!(f, "\nQuantum:  {:.1} bits ({})", q, cost_model::quantum().name),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Ord for SecurityEstimate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.classical_bits
+            .total_cmp(&other.classical_bits)
+            .then_with(|| self.sort_key().cmp(&other.sort_key()))
+    }
+}
+
+impl PartialOrd for SecurityEstimate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SecurityEstimate {
+    fn eq(&self, other: &Self) -> bool {
+        self.sort_key() == other.sort_key()
+    }
+}
+
+impl Eq for SecurityEstimate {}
+
+impl Hash for SecurityEstimate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sort_key().hash(state);
     }
 }
 
@@ -100,18 +613,23 @@ pub fn delta_0(beta: usize) -> f64 {
     (b / (2.0 * PI * E)).powf(1.0 / (2.0 * b - 2.0))
 }
 
-/// Find minimum β that achieves δ(β) ≤ target_delta
+/// Block sizes from this one up are out of reach: an attack that needs
+/// one is reported as infeasible
+pub const MAX_BETA: usize = 10000;
+
+/// Find minimum β that achieves δ(β) ≤ target_delta; None when no
+/// β < [`MAX_BETA`] does
 #[inline]
-pub fn beta_from_delta(target_delta: f64) -> usize {
+pub fn beta_from_delta(target_delta: f64) -> Option<usize> {
     if target_delta >= 1.0219 {
-        return 2;
+        return Some(2);
     }
     if target_delta <= 1.0 {
-        return 10000;
+        return None;
     }
     
     let mut lo: usize = 40;
-    let mut hi: usize = 10000;
+    let mut hi: usize = MAX_BETA;
     
     while lo < hi {
         let mid = (lo + hi) / 2;
@@ -121,7 +639,7 @@ pub fn beta_from_delta(target_delta: f64) -> usize {
             lo = mid + 1;
         }
     }
-    lo
+    (lo < MAX_BETA).then_some(lo)
 }
 
 /// BKZ-β cost in log2
@@ -130,54 +648,111 @@ pub fn bkz_cost(beta: usize, sieving: bool) -> f64 {
     if beta < 2 {
         return 0.0;
     }
-    if beta >= 10000 {
-        return f64::INFINITY;
-    }
     let b = beta as f64;
     if sieving { 0.265 * b } else { 0.292 * b }
 }
 
+/// The primal attack tries m < MAX_SAMPLES_FACTOR · n samples
+pub const MAX_SAMPLES_FACTOR: usize = 8;
+
 /// Find optimal attack parameters for primal uSVP
-/// Returns: (optimal_beta, optimal_m, optimal_d)
-pub fn primal_usvp(n: usize, q: u64, sigma: f64) -> (usize, usize, usize) {
-    let mut best_beta: usize = 10000;
-    let mut best_m: usize = n;
-    let mut best_d: usize = 2 * n;
+/// Returns: (optimal_beta, optimal_m, optimal_d), None when no sample
+/// count makes the attack feasible
+pub fn primal_usvp(n: usize, q: u64, sigma: f64) -> Option<(usize, usize, usize)> {
+    primal_usvp_log2q(n, (q as f64).log2(), sigma)
+}
+
+/// Primal uSVP with the modulus given as log2 q, for moduli beyond 64 bits
+pub fn primal_usvp_log2q(n: usize, log2_q: f64, sigma: f64) -> Option<(usize, usize, usize)> {
+    primal_usvp_with(n, log2_q, sigma, 0.0, None)
+}
+
+/// Sample counts the primal attack tries, n/2 ≤ m < 8n, capped at
+/// `max_samples` when the attacker only has that many
+pub fn sample_range(n: usize, max_samples: Option<usize>) -> std::ops::Range<usize> {
+    let (start, end) = ((n / 2).max(1), MAX_SAMPLES_FACTOR * n);
+    match max_samples {
+        Some(k) => start.min(k).max(1)..end.min(k + 1),
+        None => start..end,
+    }
+}
+
+/// Primal uSVP with the secret coordinates of the embedding scaled by
+/// ν = e^log_nu (Bai–Galbraith) and at most `max_samples` samples;
+/// log_nu = 0 and None are the standard embedding and search
+pub fn primal_usvp_with(
+    n: usize,
+    log2_q: f64,
+    sigma: f64,
+    log_nu: f64,
+    max_samples: Option<usize>,
+) -> Option<(usize, usize, usize)> {
+    let mut best: Option<(usize, usize, usize)> = None;
     
-    let log_q = (q as f64).ln();
+    let log_q = log2_q * std::f64::consts::LN_2;
     let log_sigma = sigma.ln();
     
-    let m_start = (n / 2).max(1);
-    let m_end = 8 * n;
-    
-    for m in m_start..m_end {
-        let d = m + n;
-        let d_f = d as f64;
-        let m_f = m as f64;
-        
-        let log_delta_max = (log_sigma + 0.5 * d_f.ln() - (m_f / d_f) * log_q) / d_f;
-        
-        if log_delta_max <= 0.0 {
+    for m in sample_range(n, max_samples) {
+        let Some(beta) = primal_beta_scaled(n, m, log_q, log_sigma, log_nu) else {
             continue;
-        }
-        
-        let delta_max = log_delta_max.exp();
-        let beta = beta_from_delta(delta_max);
+        };
         
-        if beta < best_beta {
-            best_beta = beta;
-            best_m = m;
-            best_d = d;
+        if best.is_none_or(|(b, _, _)| beta < b) {
+            best = Some((beta, m, m + n));
         }
     }
     
-    (best_beta, best_m, best_d)
+    best
+}
+
+/// Block size the primal attack needs with m samples, None when no
+/// β < [`MAX_BETA`] suffices. `log_q` and `log_sigma` are natural logarithms.
+#[inline]
+pub fn primal_beta_at(n: usize, m: usize, log_q: f64, log_sigma: f64) -> Option<usize> {
+    primal_beta_scaled(n, m, log_q, log_sigma, 0.0)
+}
+
+/// [`primal_beta_at`] with the secret scaled by e^log_nu ≥ 1, which
+/// credits n·log_nu / d against the (m/d)·ln q term, so a narrower secret
+/// never needs a larger β
+#[inline]
+pub fn primal_beta_scaled(n: usize, m: usize, log_q: f64, log_sigma: f64, log_nu: f64) -> Option<usize> {
+    let d_f = (m + n) as f64;
+    let m_f = m as f64;
+    
+    let log_delta_max = (log_sigma + 0.5 * d_f.ln() - (m_f / d_f) * log_q + (n as f64 / d_f) * log_nu) / d_f;
+    
+    if log_delta_max <= 0.0 {
+        return None;
+    }
+    
+    beta_from_delta(log_delta_max.exp())
+}
+
+/// (β, m, d) of a [`SecurityEstimate`]; an infeasible attack keeps the
+/// square embedding m = n, d = 2n
+fn unpack(best: Option<(usize, usize, usize)>, n: usize) -> (Option<usize>, usize, usize) {
+    best.map_or((None, n, 2 * n), |(beta, m, d)| (Some(beta), m, d))
 }
 
 /// Core estimation function
 pub fn estimate_core(n: usize, q: u64, sigma: f64, sieving: bool) -> SecurityEstimate {
-    let (beta, m, d) = primal_usvp(n, q, sigma);
-    let bits = bkz_cost(beta, sieving);
+    let mut r = estimate_core_log2q(n, (q as f64).log2(), sigma, sieving);
+    r.q = q;
+    r.warnings.extend(warnings::precision_warning(q));
+    r
+}
+
+/// Core estimation with the modulus given as log2 q (e.g. RNS chains)
+pub fn estimate_core_log2q(n: usize, log2_q: f64, sigma: f64, sieving: bool) -> SecurityEstimate {
+    primal_estimate(n, log2_q, sigma, primal_usvp_log2q(n, log2_q, sigma), sieving)
+}
+
+/// [`SecurityEstimate`] of a primal-uSVP search result `best`
+fn primal_estimate(n: usize, log2_q: f64, sigma: f64, best: Option<(usize, usize, usize)>, sieving: bool) -> SecurityEstimate {
+    let (beta, m, d) = unpack(best, n);
+    let bits = beta.map_or(f64::INFINITY, |b| bkz_cost(b, sieving));
+    let q = if log2_q < 64.0 { log2_q.exp2().round() as u64 } else { u64::MAX };
     
     SecurityEstimate {
         classical_bits: bits,
@@ -187,8 +762,73 @@ pub fn estimate_core(n: usize, q: u64, sigma: f64, sieving: bool) -> SecurityEst
         m,
         n,
         q,
+        log2_q,
         sigma,
+        noise: None,
+        secret: None,
+        max_samples: None,
+        quantum_bits: None,
+        provenance: Provenance::new(sieving, &["primal_usvp"]),
+        warnings: warnings::lwe_warnings(n, sigma, beta, m),
+        trace: None,
+    }
+}
+
+/// Primal-uSVP estimate with the secret drawn from `secret` and, when
+/// `max_samples` is set, at most that many samples
+pub fn estimate_bounded(
+    n: usize,
+    log2_q: f64,
+    sigma: f64,
+    secret: secret::SecretDist,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> SecurityEstimate {
+    if secret == secret::SecretDist::Gaussian && max_samples.is_none() {
+        return estimate_core_log2q(n, log2_q, sigma, sieving);
     }
+    let error = distribution::DiscreteGaussian::new(sigma);
+    let mut r = estimate_dist(n, log2_q, &*secret.resolve(sigma), &error, max_samples, sieving);
+    r.secret = (secret != secret::SecretDist::Gaussian).then(|| secret.name());
+    r
+}
+
+/// Primal uSVP for any secret and error distributions, with at most
+/// `max_samples` samples
+pub fn primal_usvp_dist<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+) -> Option<(usize, usize, usize)>
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let log_nu = distribution::embedding_log_scale(n, secret, error);
+    primal_usvp_with(n, log2_q, error.std_dev(n), log_nu, max_samples)
+}
+
+/// [`estimate_bounded`] for any secret and error distributions; the
+/// secret's name is recorded on the estimate
+pub fn estimate_dist<S, E>(
+    n: usize,
+    log2_q: f64,
+    secret: &S,
+    error: &E,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> SecurityEstimate
+where
+    S: Distribution + ?Sized,
+    E: Distribution + ?Sized,
+{
+    let best = primal_usvp_dist(n, log2_q, secret, error, max_samples);
+    let mut r = primal_estimate(n, log2_q, error.std_dev(n), best, sieving);
+    r.secret = Some(secret.name());
+    r.max_samples = max_samples;
+    r
 }
 
 // ============================================================================
@@ -202,6 +842,9 @@ pub fn estimate_core(n: usize, q: u64, sigma: f64, sieving: bool) -> SecurityEst
 ///     q: Modulus
 ///     sigma: Error standard deviation
 ///     sieving: Use aggressive sieving cost model (default: False)
+///     trace: Record every (m, β, cost) the optimizer considered in
+///         `result.trace` (default: False)
+///     samples: LWE samples available to the attacker (default: unbounded)
 ///
 /// Returns:
 ///     SecurityEstimate with bit-security and attack details
@@ -210,42 +853,144 @@ pub fn estimate_core(n: usize, q: u64, sigma: f64, sieving: bool) -> SecurityEst
 ///     >>> from cryptoparam import estimate_lwe
 ///     >>> r = estimate_lwe(256, 7681, 8.0)
 ///     >>> print(r.classical_bits)  # ~73
+#[cfg(feature = "python")]
 #[pyfunction]
-#[pyo3(signature = (n, q, sigma, sieving = false))]
-pub fn estimate_lwe(n: usize, q: u64, sigma: f64, sieving: bool) -> PyResult<SecurityEstimate> {
-    if n == 0 {
-        return Err(pyo3::exceptions::PyValueError::new_err("n must be positive"));
-    }
-    if q < 2 {
-        return Err(pyo3::exceptions::PyValueError::new_err("q must be >= 2"));
-    }
-    if sigma <= 0.0 {
-        return Err(pyo3::exceptions::PyValueError::new_err("sigma must be positive"));
+#[pyo3(signature = (n, q, sigma, sieving = false, trace = false, samples = None))]
+pub fn estimate_lwe(
+    py: Python,
+    n: usize,
+    q: u64,
+    sigma: Sigma,
+    sieving: bool,
+    trace: bool,
+    samples: Option<usize>,
+) -> PyResult<SecurityEstimate> {
+    let sigma = sigma.0;
+    LweParams::new(n, q, sigma)?;
+    
+    if samples == Some(0) {
+        return Err(crate::errors::InvalidParameters::new_err("samples must be positive"));
     }
     
-    Ok(estimate_core(n, q, sigma, sieving))
+    let mut r = py.allow_threads(|| match samples {
+        Some(k) => {
+            let mut r = estimate_bounded(n, (q as f64).log2(), sigma, secret::SecretDist::Gaussian, Some(k), sieving);
+            r.q = q;
+            r.warnings.extend(warnings::precision_warning(q));
+            if trace {
+                r.trace = Some(trace::primal_usvp_trace_with(n, r.log2_q, sigma, 0.0, Some(k), sieving));
+            }
+            r
+        }
+        None if trace => trace::estimate_core_traced(n, q, sigma, sieving),
+        None => estimate_core(n, q, sigma, sieving),
+    });
+    defaults::apply(&mut r, sieving);
+    warnings::emit(py, [&r])?;
+    Ok(r)
 }
 
-/// Estimate security from LweParams object.
+/// Estimate security from LweParams object, or a {"n", "q", "sigma"} dict.
+#[cfg(feature = "python")]
 #[pyfunction]
 #[pyo3(signature = (params, sieving = false))]
-pub fn estimate(params: &LweParams, sieving: bool) -> SecurityEstimate {
-    estimate_core(params.n, params.q, params.sigma, sieving)
+pub fn estimate(py: Python, params: &PyAny, sieving: bool) -> PyResult<SecurityEstimate> {
+    let params = extract_params(params)?;
+    let mut r = estimate_core(params.n, params.q, params.sigma, sieving);
+    defaults::apply(&mut r, sieving);
+    warnings::emit(py, [&r])?;
+    Ok(r)
+}
+
+/// LweParams, or an (n, q, sigma) tuple or {"n", "q", "sigma"} dict
+/// checked like `LweParams(...)`
+#[cfg(feature = "python")]
+pub(crate) fn extract_params(p: &PyAny) -> PyResult<LweParams> {
+    if let Ok(p) = p.extract::<LweParams>() {
+        return Ok(p);
+    }
+    if let Ok(d) = p.downcast::<PyDict>() {
+        return params_from_dict(d);
+    }
+    let (n, q, sigma): (usize, u64, Sigma) = p
+        .extract()
+        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("expected LweParams, a dict or an (n, q, sigma) tuple"))?;
+    LweParams::checked(n, q, sigma.0)
+}
+
+/// LweParams from a dict with exactly the keys "n", "q" and "sigma"
+#[cfg(feature = "python")]
+fn params_from_dict(d: &PyDict) -> PyResult<LweParams> {
+    const KEYS: [&str; 3] = ["n", "q", "sigma"];
+    for key in d.keys() {
+        let name = key.str()?.to_string();
+        if !KEYS.contains(&name.as_str()) {
+            return Err(errors::InvalidParameters::new_err(format!(
+                "unknown key '{}' in parameters (expected n, q, sigma)",
+                name
+            )));
+        }
+    }
+    fn field<'py, T: FromPyObject<'py>>(d: &'py PyDict, key: &str, ty: &str) -> PyResult<T> {
+        let v = d
+            .get_item(key)?
+            .ok_or_else(|| errors::InvalidParameters::new_err(format!("parameters need '{}'", key)))?;
+        v.extract().map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!("'{}' must be {}, not {}", key, ty, v.get_type().name().unwrap_or("?")))
+        })
+    }
+    LweParams::checked(field(d, "n", "an int")?, field(d, "q", "an int")?, field::<Sigma>(d, "sigma", "a number")?.0)
+}
+
+/// Estimate many parameter sets in parallel.
+///
+/// The GIL is released while rayon spreads the estimates over the worker
+/// threads, so other Python threads keep running.
+///
+/// Args:
+///     params: List of LweParams or (n, q, sigma) tuples
+///     sieving: Use aggressive sieving cost model (default: False)
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     List of SecurityEstimate, in the order of `params`
+///
+/// Example:
+///     >>> rs = estimate_many([(512, 12289, 3.2), LweParams.kyber768_flattened()])
+///     >>> len(rs)
+///     2
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (params, sieving = false, n_threads = None))]
+pub fn estimate_many(py: Python, params: Vec<&PyAny>, sieving: bool, n_threads: Option<usize>) -> PyResult<Vec<SecurityEstimate>> {
+    let params = params.into_iter().map(extract_params).collect::<PyResult<Vec<_>>>()?;
+    let mut rows: Vec<SecurityEstimate> = py.allow_threads(|| {
+        parallel::with_threads(n_threads, || {
+            use rayon::prelude::*;
+            params.par_iter().map(|p| estimate_core(p.n, p.q, p.sigma, sieving)).collect()
+        })
+    });
+    rows.iter_mut().for_each(|r| defaults::apply(r, sieving));
+    warnings::emit(py, &rows)?;
+    Ok(rows)
 }
 
 /// Get root Hermite factor for BKZ block size.
+#[cfg(feature = "python")]
 #[pyfunction]
 pub fn get_delta(beta: usize) -> f64 {
     delta_0(beta)
 }
 
-/// Get BKZ block size needed for target delta.
+/// Get BKZ block size needed for target delta, None when out of reach.
+#[cfg(feature = "python")]
 #[pyfunction]
-pub fn get_beta(target_delta: f64) -> usize {
+pub fn get_beta(target_delta: f64) -> Option<usize> {
     beta_from_delta(target_delta)
 }
 
 /// Get BKZ cost in bits.
+#[cfg(feature = "python")]
 #[pyfunction]
 #[pyo3(signature = (beta, sieving = false))]
 pub fn get_bkz_cost(beta: usize, sieving: bool) -> f64 {
@@ -265,15 +1010,77 @@ pub fn get_bkz_cost(beta: usize, sieving: bool) -> f64 {
 ///     >>> r = estimate_lwe(256, 7681, 8.0)
 ///     >>> print(r)
 ///     LWE(n=256, q≈2^13, σ=8): ~73 bits (primal_usvp, β=250)
+#[cfg(feature = "python")]
 #[pymodule]
-fn cryptoparam(_py: Python, m: &PyModule) -> PyResult<()> {
+fn cryptoparam(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<LweParams>()?;
     m.add_class::<SecurityEstimate>()?;
+    m.add_class::<provenance::Provenance>()?;
+    m.add_class::<warnings::Warning>()?;
+    m.add_class::<trace::SearchStep>()?;
+    errors::register(py, m)?;
     m.add_function(wrap_pyfunction!(estimate_lwe, m)?)?;
     m.add_function(wrap_pyfunction!(estimate, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_many, m)?)?;
+    m.add_class::<estimator::Estimator>()?;
+    m.add_class::<defaults::Config>()?;
+    m.add_function(wrap_pyfunction!(defaults::py_config, m)?)?;
+    m.add_function(wrap_pyfunction!(secret::py_estimate_small_secret, m)?)?;
     m.add_function(wrap_pyfunction!(get_delta, m)?)?;
     m.add_function(wrap_pyfunction!(get_beta, m)?)?;
     m.add_function(wrap_pyfunction!(get_bkz_cost, m)?)?;
+    m.add_class::<search::SearchResult>()?;
+    m.add_class::<search::TraceEntry>()?;
+    m.add_function(wrap_pyfunction!(search::py_search_n, m)?)?;
+    m.add_function(wrap_pyfunction!(search::py_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(search::py_search_joint, m)?)?;
+    m.add_class::<search::SearchOutcome>()?;
+    m.add_function(wrap_pyfunction!(search::py_search, m)?)?;
+    m.add_function(wrap_pyfunction!(aio::py_estimate_async, m)?)?;
+    m.add_function(wrap_pyfunction!(aio::py_search_async, m)?)?;
+    m.add_function(wrap_pyfunction!(failure::py_failure_probability, m)?)?;
+    m.add_class::<attacks::AttackCost>()?;
+    m.add_function(wrap_pyfunction!(attacks::py_attacks, m)?)?;
+    m.add_function(wrap_pyfunction!(attacks::py_register_attack, m)?)?;
+    m.add_function(wrap_pyfunction!(attacks::py_unregister_attack, m)?)?;
+    m.add_class::<descriptor::SchemeDescriptor>()?;
+    m.add_class::<descriptor::AttackBits>()?;
+    m.add_class::<descriptor::VerifyReport>()?;
+    m.add_function(wrap_pyfunction!(descriptor::py_load_descriptor, m)?)?;
+    m.add_function(wrap_pyfunction!(descriptor::py_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(descriptor::py_descriptor_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(heimport::py_import_seal, m)?)?;
+    m.add_function(wrap_pyfunction!(heimport::py_import_openfhe, m)?)?;
+    m.add_function(wrap_pyfunction!(lattice_estimator::py_from_lattice_estimator, m)?)?;
+    m.add_function(wrap_pyfunction!(lattice_estimator::py_sage_script, m)?)?;
+    m.add_function(wrap_pyfunction!(report::py_to_markdown, m)?)?;
+    m.add_function(wrap_pyfunction!(report::py_to_latex, m)?)?;
+    m.add_function(wrap_pyfunction!(html::py_html_report, m)?)?;
+    m.add_class::<bgv::BgvParams>()?;
+    m.add_function(wrap_pyfunction!(bgv::py_bgv_params, m)?)?;
+    m.add_class::<ckks::CkksParams>()?;
+    m.add_function(wrap_pyfunction!(ckks::py_ckks_params, m)?)?;
+    m.add_class::<chain::ModulusChain>()?;
+    m.add_function(wrap_pyfunction!(chain::py_modulus_chain, m)?)?;
+    m.add_function(wrap_pyfunction!(curve::py_curve_n, m)?)?;
+    m.add_function(wrap_pyfunction!(curve::py_curve_log_q, m)?)?;
+    m.add_class::<hestd::HeStdCheck>()?;
+    m.add_function(wrap_pyfunction!(hestd::py_he_standard_check, m)?)?;
+    m.add_function(wrap_pyfunction!(hestd::py_he_standard_max_log_q, m)?)?;
+    m.add_class::<optimize::Candidate>()?;
+    m.add_function(wrap_pyfunction!(optimize::py_optimize, m)?)?;
+    m.add_class::<presets::Preset>()?;
+    presets::register(py, m)?;
+    plot::register(py, m)?;
+    m.add_class::<sis::SisParams>()?;
+    m.add_class::<sis::SisEstimate>()?;
+    m.add_function(wrap_pyfunction!(sis::py_estimate_sis, m)?)?;
+    m.add_class::<snap::SnappedResult>()?;
+    m.add_function(wrap_pyfunction!(snap::py_snap, m)?)?;
+    m.add_class::<sweep::SweepTable>()?;
+    m.add_function(wrap_pyfunction!(sweep::py_sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(sweep::py_estimate_lwe_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(sweep::py_to_pandas, m)?)?;
     Ok(())
 }
 
@@ -297,7 +1104,7 @@ mod tests {
         let mut prev = 0.0;
         for n in [64, 128, 256, 512] {
             let r = estimate_core(n, 12289, 8.0, false);
-            assert!(r.classical_bits > prev || r.beta >= 10000);
+            assert!(r.classical_bits > prev || r.beta.is_none());
             prev = r.classical_bits;
         }
     }
@@ -316,11 +1123,74 @@ mod tests {
     fn test_matches_python() {
         // These should match our Python MVP exactly
         let r = estimate_core(256, 7681, 8.0, false);
-        assert_eq!(r.beta, 250);
+        assert_eq!(r.beta, Some(250));
         assert!((r.classical_bits - 73.0).abs() < 1.0);
         
         let r = estimate_core(512, 12289, 10.0, false);
-        assert_eq!(r.beta, 533);
+        assert_eq!(r.beta, Some(533));
         assert!((r.classical_bits - 155.6).abs() < 1.0);
     }
+    
+    #[test]
+    fn test_named_constructors() {
+        let p = LweParams::kyber768_flattened();
+        assert_eq!((p.n, p.q, p.sigma), (768, 3329, 1.0));
+        assert_eq!(LweParams::tfhe_lwe_default().q, 1 << 32);
+    }
+
+    #[test]
+    fn test_estimate_ordering() {
+        use std::collections::HashSet;
+        let weak = estimate_core(256, 7681, 8.0, false);
+        let strong = estimate_core(512, 12289, 3.0, false);
+        assert!(weak < strong);
+        // Provenance timestamps differ, the estimates do not
+        assert_eq!(weak, estimate_core(256, 7681, 8.0, false));
+        let set: HashSet<_> = [weak.clone(), strong, weak].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert_eq!(LweParams::frodo640(), LweParams::frodo640());
+    }
+
+    #[test]
+    fn test_display_modes() {
+        let r = estimate_core(256, 7681, 8.0, true);
+        assert_eq!(r.to_string(), "LWE(n=256, q≈2^13, σ=8): ~66 bits (primal_usvp, β=250)");
+        let verbose = format!("{:#}", r);
+        assert!(verbose.starts_with("Parameters:\n  n     = 256\n"));
+        assert!(verbose.ends_with("Security: 66.2 bits (sieving)"));
+        assert!(verbose.contains("\nAttack: primal_usvp\n"));
+        let sis = SecurityEstimate { attack: "sis".to_string(), ..r.clone() };
+        assert!(format!("{:#}", sis).contains("\nAttack: sis\n"));
+        let tagged = SecurityEstimate { noise: Some("2^-10·q".to_string()), ..r };
+        assert!(tagged.to_string().starts_with("LWE(n=256, q≈2^13, σ=8 from 2^-10·q): "));
+    }
+
+    #[test]
+    fn test_bounded_samples() {
+        let log2_q = 12289f64.log2();
+        let free = estimate_core_log2q(512, log2_q, 3.2, false);
+        let k = free.m / 2;
+        let r = estimate_bounded(512, log2_q, 3.2, secret::SecretDist::Gaussian, Some(k), false);
+        assert!(r.m <= k && r.m != free.m);
+        assert_eq!(r.max_samples, Some(k));
+        assert_eq!(sample_range(512, Some(10_000)), sample_range(512, None));
+        assert!(sample_range(512, Some(0)).is_empty());
+
+        let error = distribution::DiscreteGaussian::new(3.2);
+        let same = estimate_dist(512, log2_q, &error, &error, None, false);
+        assert_eq!((same.beta, same.m, same.d, same.classical_bits), (free.beta, free.m, free.d, free.classical_bits));
+        assert_eq!(same.warnings, free.warnings);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let est = estimate_core(512, 12289, 3.0, false);
+        let json = serde_json::to_string(&est).unwrap();
+        let back: SecurityEstimate = serde_json::from_str(&json).unwrap();
+        assert_eq!((back.beta, back.n, back.q), (est.beta, est.n, est.q));
+
+        let p: presets::Preset = serde_json::from_str(&serde_json::to_string(&presets::dilithium2()).unwrap()).unwrap();
+        assert_eq!(p, presets::dilithium2());
+    }
 }
@@ -1,123 +1,1131 @@
 //! CryptoParam CLI
 
-use cryptoparam::{estimate_core, SecurityEstimate};
+mod bench;
+mod cli;
+mod repl;
+
+use clap::Parser;
+use cli::{
+    man_page, parse_float_values, parse_int_values, parse_modulus, parse_number, parse_real, Cli, Commands, Modulus,
+};
+use cryptoparam::attacks::{
+    attack_details, attack_table, check_attack, descriptor_attacks, lwe_attack_results, lwe_attacks_with, AttackOutcome,
+};
+use cryptoparam::baseline::{self, bits_drift, diff_table, Baseline};
+use cryptoparam::curve::{
+    security_vs_log_q, security_vs_n, to_ascii, to_csv, to_json, CurvePoint, DEFAULT_MAX_POINTS,
+};
+use cryptoparam::config::RunConfig;
+use cryptoparam::cost_model::{self, CostModel};
+use cryptoparam::error::CryptoParamError;
+use cryptoparam::estimator::EstimateConfig;
+use cryptoparam::descriptor::{self, verify_with, SchemeDescriptor, CATEGORY_BITS};
+use cryptoparam::distribution::DiscreteGaussian;
+use cryptoparam::explain::explain;
+use cryptoparam::heimport;
+use cryptoparam::hestd;
+use cryptoparam::html;
+use cryptoparam::lattice_estimator;
+use cryptoparam::presets;
+use cryptoparam::profile::Profile;
+use cryptoparam::progress::{no_progress, Progress, ProgressBar};
+use cryptoparam::trace::{primal_usvp_trace_with, to_csv as trace_csv};
+use cryptoparam::secret::embedding_scale;
+use cryptoparam::search::{solve_checkpointed, solve_with_progress, SearchParam, SecurityMargin, MIN_SEARCH_N};
+use cryptoparam::report::{bits_only, compare_table, porcelain, default_columns, latex_table, markdown_table, Column, OutputFormat};
+use cryptoparam::sweep::{
+    csv_row as sweep_csv_row, json_row as sweep_json_row, sweep, sweep_checkpointed, sweep_with_progress,
+    to_csv as sweep_csv, to_json as sweep_json,
+};
+use std::io::Write;
+use cryptoparam::SecurityEstimate;
+use std::path::Path;
 use std::env;
 use std::process;
 
-fn print_usage() {
-    eprintln!("CryptoParam - Plain LWE Security Estimator");
-    eprintln!();
-    eprintln!("Usage: cryptoparam <n> <q> <sigma> [options]");
-    eprintln!();
-    eprintln!("Arguments:");
-    eprintln!("  n       LWE dimension");
-    eprintln!("  q       Modulus (supports 2**k notation)");
-    eprintln!("  sigma   Error standard deviation");
-    eprintln!();
-    eprintln!("Options:");
-    eprintln!("  -v, --verbose   Show detailed output");
-    eprintln!("  --sieving       Use aggressive sieving cost model");
-    eprintln!("  -h, --help      Show this help");
-}
-
-fn parse_number(s: &str) -> Result<u64, String> {
-    if s.contains("**") {
-        let parts: Vec<&str> = s.split("**").collect();
-        if parts.len() == 2 {
-            let base: u64 = parts[0].parse().map_err(|_| format!("Invalid: {}", s))?;
-            let exp: u32 = parts[1].parse().map_err(|_| format!("Invalid: {}", s))?;
-            return Ok(base.pow(exp));
-        }
-    }
-    if s.contains('^') {
-        let parts: Vec<&str> = s.split('^').collect();
-        if parts.len() == 2 {
-            let base: u64 = parts[0].parse().map_err(|_| format!("Invalid: {}", s))?;
-            let exp: u32 = parts[1].parse().map_err(|_| format!("Invalid: {}", s))?;
-            return Ok(base.pow(exp));
-        }
-    }
-    s.parse().map_err(|_| format!("Cannot parse '{}'", s))
-}
-
-fn format_result(r: &SecurityEstimate) -> String {
-    let q_bits = (r.q as f64).log2();
-    if r.beta >= 10000 {
-        format!(
-            "LWE(n={}, q≈2^{:.0}, σ={}): No lattice attack found",
-            r.n, q_bits, r.sigma
-        )
+/// Caveats go to stderr so stdout stays parseable
+fn print_warnings(r: &SecurityEstimate) {
+    for w in &r.warnings {
+        eprintln!("warning: {}", w.message);
+    }
+}
+
+/// Reprice `rows` under `--model` when given, then add quantum bits for
+/// `--quantum`
+fn apply_model(model: Option<&CostModel>, quantum: bool, rows: &mut [SecurityEstimate]) {
+    if let Some(model) = model {
+        rows.iter_mut().for_each(|r| model.apply(r));
+    }
+    if quantum {
+        rows.iter_mut().for_each(SecurityEstimate::add_quantum);
+    }
+}
+
+/// Text-output colouring by security level: green at or above the first
+/// threshold, yellow at or above the second, red below. Off for `--no-color`,
+/// a set NO_COLOR or when stdout is not a terminal.
+struct Palette {
+    enabled: bool,
+    good: f64,
+    weak: f64,
+}
+
+impl Palette {
+    /// Default `--thresholds`, in bits
+    const THRESHOLDS: (f64, f64) = (128.0, 100.0);
+
+    fn new(no_color: bool, thresholds: Option<(f64, f64)>) -> Self {
+        use std::io::IsTerminal;
+
+        let (good, weak) = thresholds.unwrap_or(Self::THRESHOLDS);
+        let no_color = no_color || env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Self { enabled: !no_color && std::io::stdout().is_terminal(), good, weak }
+    }
+
+    fn from_args(args: &cli::OutputArgs) -> Self {
+        Self::new(args.no_color, args.thresholds)
+    }
+
+    fn paint(&self, bits: f64, text: &str) -> String {
+        if !self.enabled {
+            return text.to_string();
+        }
+        let color = if bits >= self.good { 32 } else if bits >= self.weak { 33 } else { 31 };
+        format!("\x1b[{}m{}\x1b[0m", color, text)
+    }
+}
+
+/// Take option defaults from the user config file and `--profile`
+fn load_profile(cli: &mut Cli) {
+    let profile = match Profile::default_path() {
+        Some(path) => Profile::load(&path),
+        None => Ok(Profile::default()),
+    };
+    let defaults = profile.and_then(|p| p.resolve(cli.global.profile.as_deref()));
+    if let Err(e) = defaults.map_err(|e| e.to_string()).and_then(|d| cli.apply_defaults(&d)) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Exit statuses, for the man page
+const EXIT_STATUS: &[(i32, &str)] = &[
+    (0, "Success"),
+    (1, "Invalid arguments or input, or a descriptor that fails verify"),
+    (EXIT_BELOW_REQUIRED, "A result fell below --require-bits or --require-quantum-bits"),
+    (EXIT_BASELINE_DRIFT, "The estimate moved further than --tolerance from --baseline"),
+];
+
+/// Exit status when an estimate misses `--require-bits` or
+/// `--require-quantum-bits`; 1 is kept for usage and input errors
+const EXIT_BELOW_REQUIRED: i32 = 2;
+
+/// Exit status when an estimate drifts from its `--baseline`
+const EXIT_BASELINE_DRIFT: i32 = 3;
+
+/// Saved results of `--baseline` and the allowed `--tolerance` in bits,
+/// read before estimating so a bad file fails fast
+fn baseline_opt(args: &cli::EstimateArgs) -> Option<(Vec<Baseline>, f64)> {
+    let path = args.baseline.as_ref()?;
+    match Baseline::load(path) {
+        Ok(saved) => Some((saved, args.tolerance.unwrap_or(0.0))),
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    }
+}
+
+/// Security levels the results must reach
+struct Required {
+    classical: Option<f64>,
+    quantum: Option<f64>,
+}
+
+impl Required {
+    fn from_args(args: &cli::RequireArgs) -> Self {
+        Self { classical: args.require_bits, quantum: args.require_quantum_bits }
+    }
+
+    /// Report the levels `r` misses; true if there was one
+    fn report(&self, r: &SecurityEstimate) -> bool {
+        let mut missed = false;
+        let quantum_bits = r.quantum_bits.unwrap_or_else(|| cost_model::quantum().price(r.beta, r.d));
+        for (kind, bits, required) in [("classical", r.classical_bits, self.classical), ("quantum", quantum_bits, self.quantum)] {
+            if let Some(required) = required.filter(|&t| bits < t) {
+                eprintln!(
+                    "error: n={}, log2 q={:.1}, σ={}: {:.1} {} bits, below the required {}",
+                    r.n, r.log2_q, r.sigma, bits, kind, required
+                );
+                missed = true;
+            }
+        }
+        missed
+    }
+
+    /// Report every miss and exit with [`EXIT_BELOW_REQUIRED`] if there was one
+    fn check(&self, rows: &[SecurityEstimate]) {
+        if rows.iter().fold(false, |failed, r| self.report(r) | failed) {
+            process::exit(EXIT_BELOW_REQUIRED);
+        }
+    }
+}
+
+fn run_curve_n(args: &cli::CurveNArgs) {
+    let (q, sigma, n_min, n_max) = (args.q, args.sigma, args.n_min as usize, args.n_max as usize);
+    if q < 2 || sigma <= 0.0 || n_min == 0 || n_max < n_min {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let points = security_vs_n(q, sigma, n_min, n_max, args.sieving, DEFAULT_MAX_POINTS);
+    print_curve(&points, "n", &args.curve);
+}
+
+fn run_curve_logq(args: &cli::CurveLogqArgs) {
+    let (n, sigma, lo, hi) = (args.n as usize, args.sigma, args.logq_min, args.logq_max);
+    if n == 0 || sigma <= 0.0 || lo < 1.0 || hi < lo {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let points = security_vs_log_q(n, sigma, lo, hi, args.sieving, DEFAULT_MAX_POINTS);
+    print_curve(&points, "log2_q", &args.curve);
+}
+
+fn run_sweep(args: &cli::SweepArgs) {
+    let sieving = args.model.sieving;
+    let model = args.model.model;
+    let required = Required::from_args(&args.require);
+    let positional = &args.params;
+    // `--n 256..1024:256` or positionally, `256,512 7681 3.19`
+    fn axis<'a>(flag: &str, option: Option<&'a String>, positional: Option<&'a String>) -> &'a str {
+        match (option, positional) {
+            (Some(v), None) | (None, Some(v)) => v.as_str(),
+            (Some(_), Some(_)) => { eprintln!("Error: give {} either as an option or positionally", flag); process::exit(1); }
+            (None, None) => { eprintln!("Error: {} is required (see cryptoparam sweep --help)", flag); process::exit(1); }
+        }
+    }
+    let ns: Vec<usize> = match parse_int_values(axis("--n", args.n.as_ref(), positional.first())) {
+        Ok(v) => v.into_iter().map(|n| n as usize).collect(),
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    let qs: Vec<u64> = match parse_int_values(axis("--q", args.q.as_ref(), positional.get(1))) {
+        Ok(v) => v,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    let sigmas: Vec<f64> = match parse_float_values(axis("--sigma", args.sigma.as_ref(), positional.get(2))) {
+        Ok(v) => v,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    if ns.contains(&0) || qs.iter().any(|&q| q < 2) || sigmas.iter().any(|&s| s <= 0.0) {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let format = args.output.format.unwrap_or_default();
+
+    let bar = progress_bar(&args.progress, format);
+    let show_progress = bar.is_some();
+    let update = |p: &Progress| bar.as_ref().map_or((), |b| b.update(p));
+    let progress: &(dyn Fn(&Progress) + Sync) = if show_progress { &update } else { &no_progress };
+
+    let mut rows = match &args.checkpoint {
+        Some(path) => {
+            match sweep_checkpointed(&ns, &qs, &sigmas, sieving, model, path, args.resume, progress) {
+                Ok(rows) => rows,
+                Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+            }
+        }
+        None if show_progress => sweep_with_progress(&ns, &qs, &sigmas, sieving, progress),
+        None => sweep(&ns, &qs, &sigmas, sieving),
+    };
+    if let Some(b) = &bar {
+        b.finish();
+    }
+    apply_model(model, args.quantum, &mut rows);
+    print_rows(&rows, None, format, &args.output);
+    required.check(&rows);
+}
+
+fn run_presets(args: &cli::PresetsArgs) {
+    let sieving = args.sieving;
+    let positional = &args.action;
+    if let Some(format) = &args.export {
+        match presets::ExportFormat::parse(format) {
+            Ok(f) => print!("{}", presets::export(f, sieving)),
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        }
+        return;
+    }
+    let lookup = |name: Option<&String>| {
+        let Some(name) = name else {
+            eprintln!("Error: Expected a preset name (see cryptoparam presets list)");
+            process::exit(1);
+        };
+        match presets::by_name(name) {
+            Some(p) => p,
+            None => { eprintln!("Error: Unknown preset '{}'", name); process::exit(1); }
+        }
+    };
+    match positional.first().map(|s| s.as_str()) {
+        None | Some("list") => list_presets(args),
+        Some("show") => show_preset(&lookup(positional.get(1))),
+        Some("estimate") => estimate_preset(&lookup(positional.get(1)), sieving),
+        Some(_) if positional.len() > 1 => {
+            eprintln!("Error: Unknown presets action '{}' (expected list, show or estimate)", positional[0]);
+            process::exit(1);
+        }
+        // `presets <name>`: show and estimate
+        Some(_) => {
+            let p = lookup(positional.first());
+            if args.lattice_estimator {
+                println!("{}", lattice_estimator::to_snippet(&SchemeDescriptor::from_preset(&p)));
+                return;
+            }
+            show_preset(&p);
+            println!();
+            estimate_preset(&p, sieving);
+        }
+    }
+}
+
+fn list_presets(args: &cli::PresetsArgs) {
+    let filter = presets::PresetFilter {
+        scheme: args.scheme.clone(),
+        group: args.group.clone(),
+        nist_level: args.level,
+    };
+    let list = presets::list(&filter);
+    let name_w = list.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    let scheme_w = list.iter().map(|p| p.scheme.len()).max().unwrap_or(0);
+    for p in &list {
+        println!(
+            "{:<name_w$}  {:<scheme_w$}  {:<10} NIST level {}",
+            p.name, p.scheme, p.problem, p.nist_level
+        );
+    }
+}
+
+fn show_preset(p: &presets::Preset) {
+    println!("{} ({}, {})", p.name, p.scheme, p.problem);
+    println!("  n     = {}", p.n);
+    println!("  k     = {}", p.k);
+    println!("  q     = {}", p.q);
+    if let Some(eta) = p.eta {
+        println!("  η     = {}", eta);
+    }
+    println!("  σ     ≈ {:.3}", p.sigma);
+    if let Some(rp) = p.p {
+        println!("  p     = {} (LWR rounding)", rp);
+    }
+    if let Some(m) = p.samples {
+        println!("  m     = {} samples", m);
+    }
+    println!("  NIST level {}, claimed {:.0} bits", p.nist_level, p.claimed_bits);
+}
+
+fn estimate_preset(p: &presets::Preset, sieving: bool) {
+    let r = p.estimate(sieving);
+    println!("{}", r);
+    if let Some(sis) = p.sis_estimate(sieving) {
+        match sis.beta {
+            None => println!(
+                "SIS(n={}, m={}, bound={}): No lattice attack found",
+                sis.params.n, sis.params.m, sis.params.bound
+            ),
+            Some(beta) => println!(
+                "SIS(n={}, m={}, bound={}): ~{:.0} bits (β={})",
+                sis.params.n, sis.params.m, sis.params.bound, sis.classical_bits, beta
+            ),
+        }
+    }
+    print_warnings(&r);
+}
+
+fn run_he_std(args: &cli::HeStdArgs) {
+    let (n, log_q, security, secret) = (args.n as usize, args.log_q, args.security, args.secret);
+    if n == 0 || log_q < 1.0 {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let c = match hestd::check(n, log_q, security, secret, args.sieving) {
+        Ok(c) => c,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    match c.table_max_log_q {
+        Some(max) => println!(
+            "HE standard ({}-bit, {} secret): log q <= {} for n={} → {}",
+            security,
+            secret.name(),
+            max,
+            n,
+            if c.table_ok { "PASS" } else { "FAIL" }
+        ),
+        None => println!("HE standard: n={} is below the smallest tabulated dimension → FAIL", n),
+    }
+    println!(
+        "Estimate: {} → {}",
+        c.estimate,
+        if c.estimate_ok { "PASS" } else { "FAIL" }
+    );
+}
+
+fn run_nearest(args: &cli::NearestArgs) {
+    let (n, q, sigma) = (args.n as usize, args.q, args.sigma);
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    for d in presets::nearest(n, q, sigma, args.count, args.sieving) {
+        println!(
+            "{:<14} Δn={:+}  Δlog q={:+.2}  Δσ={:+.3}  Δbits={:+.1}",
+            d.preset.name, d.delta_n, d.delta_log_q, d.delta_sigma, d.delta_bits
+        );
+    }
+}
+
+fn run_report(args: &cli::ReportArgs) {
+    let (n, q, sigma) = (args.n as usize, args.q, args.sigma);
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let html = html::html_report(n, q, sigma, args.sieving);
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, html) {
+                eprintln!("Error: {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", html),
+    }
+}
+
+/// `<n> <q> <sigma>` or a preset name / descriptor file
+fn descriptor_operand(positional: &[String]) -> SchemeDescriptor {
+    match positional {
+        [n, q, sigma, ..] => {
+            let (n, q) = match (parse_number(n), parse_number(q)) {
+                (Ok(n), Ok(q)) => (n as usize, q),
+                (Err(e), _) | (_, Err(e)) => { eprintln!("Error: {}", e); process::exit(1); }
+            };
+            let sigma: f64 = match parse_real(sigma) {
+                Ok(v) => v,
+                Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+            };
+            if n == 0 || q < 2 || sigma <= 0.0 {
+                eprintln!("Error: Invalid parameters");
+                process::exit(1);
+            }
+            SchemeDescriptor::from_lwe(n, q, sigma)
+        }
+        [name] => match presets::by_name(name) {
+            Some(p) => SchemeDescriptor::from_preset(&p),
+            None => match SchemeDescriptor::load(Path::new(name)) {
+                Ok(d) => d,
+                Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+            },
+        },
+        _ => {
+            eprintln!("Error: Expected n, q, sigma or a preset name / descriptor file");
+            process::exit(1);
+        }
+    }
+}
+
+/// Reprice per-attack costs under `--model` when given
+fn reprice_attacks(model: Option<&CostModel>, costs: &mut [AttackOutcome]) {
+    if let Some(model) = model {
+        costs.iter_mut().for_each(|c| c.reprice(model));
+    }
+}
+
+fn run_attacks(args: &cli::AttacksArgs) {
+    let d = descriptor_operand(&args.operand);
+    let mut costs = descriptor_attacks(&d, args.model.sieving);
+    reprice_attacks(args.model.model, &mut costs);
+    print!("{}", attack_table(&costs));
+}
+
+fn run_sage(args: &cli::SageArgs) {
+    let d = descriptor_operand(&args.operand);
+    let script = lattice_estimator::sage_script(&d, args.sieving);
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, script) {
+                eprintln!("Error: {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", script),
+    }
+}
+
+fn run_verify(args: &cli::VerifyArgs) {
+    let model = args.model.cost_model();
+    let mut failed = false;
+    for path in &args.paths {
+        let loaded = match &args.from {
+            Some(library) => heimport::load(library, path),
+            None => SchemeDescriptor::load(path).map_err(CryptoParamError::InvalidInput),
+        };
+        let d = match loaded {
+            Ok(d) => d,
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        };
+        let r = verify_with(&d, model);
+        for a in &r.attacks {
+            println!("  {:<12} {:.1} bits (β={})", a.attack, a.bits, cryptoparam::report::beta_cell(a.beta));
+        }
+        if let Some(c) = r.claimed_category {
+            println!(
+                "  category     claimed {}, reached {}, margin {:+.1} against category {}'s {:.0} bits",
+                c,
+                r.derived_category,
+                r.estimated_bits - CATEGORY_BITS[c as usize - 1],
+                c,
+                CATEGORY_BITS[c as usize - 1]
+            );
+        }
+        println!(
+            "{}: {} — estimated {:.1} bits ({}), claimed {:.0}, margin {:+.1}",
+            if d.name.is_empty() { path.display().to_string() } else { d.name.clone() },
+            if r.passed { "PASS" } else { "FAIL" },
+            r.estimated_bits,
+            r.model,
+            r.claimed_bits,
+            r.margin_bits
+        );
+        failed |= !r.passed;
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_config(args: &cli::ConfigArgs, threads: Option<usize>, path: &Path) {
+    let mut config = match RunConfig::load(path) {
+        Ok(c) => c,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    set_threads(threads.or(config.threads));
+
+    config.sieving |= args.sieving;
+    let required = Required { classical: args.require_bits, quantum: args.require_quantum_bits };
+    // --format beats the file's format, which beats the profile's
+    let format = match (args.format, config.format.as_deref().map(OutputFormat::parse)) {
+        (Some(f), _) | (None, Some(Ok(f))) => f,
+        (None, Some(Err(e))) => { eprintln!("Error: {}", e); process::exit(1); }
+        (None, None) => args.profile_format.unwrap_or_default(),
+    };
+    let mut rows = config.estimates();
+    if args.quantum {
+        rows.iter_mut().for_each(SecurityEstimate::add_quantum);
+    }
+    let names: Vec<String> = config.params.iter().map(|p| p.name.clone()).collect();
+    let labels = if names.iter().any(|n| !n.is_empty()) { Some(names.as_slice()) } else { None };
+    let palette = Palette::new(args.no_color, args.thresholds);
+    print_table(&rows, labels, format, args.columns.as_deref(), &palette);
+    required.check(&rows);
+}
+
+/// Progress bar on stderr: forced by `--progress`, off with
+/// `--no-progress`, else shown when stderr is a terminal and the output is
+/// not JSON
+fn progress_bar(args: &cli::ProgressArgs, format: OutputFormat) -> Option<ProgressBar> {
+    use std::io::IsTerminal;
+
+    let show = if args.no_progress {
+        false
     } else {
-        format!(
-            "LWE(n={}, q≈2^{:.0}, σ={}): ~{:.0} bits ({}, β={})",
-            r.n, q_bits, r.sigma, r.classical_bits, r.attack, r.beta
-        )
+        args.progress || (std::io::stderr().is_terminal() && format != OutputFormat::Json)
+    };
+    show.then(|| ProgressBar::new(40))
+}
+
+fn print_rows(rows: &[SecurityEstimate], labels: Option<&[String]>, format: OutputFormat, args: &cli::OutputArgs) {
+    print_table(rows, labels, format, args.columns.as_deref(), &Palette::from_args(args));
+}
+
+/// `rows` in `format`; LaTeX tables take `--columns`
+fn print_table(rows: &[SecurityEstimate], labels: Option<&[String]>, format: OutputFormat, columns: Option<&str>, palette: &Palette) {
+    match format {
+        OutputFormat::Text => {
+            for (i, r) in rows.iter().enumerate() {
+                let line = match labels.and_then(|l| l.get(i)).filter(|l| !l.is_empty()) {
+                    Some(l) => format!("{}: {}", l, r),
+                    None => r.to_string(),
+                };
+                println!("{}", palette.paint(r.classical_bits, &line));
+                print_warnings(r);
+            }
+        }
+        OutputFormat::Csv => print!("{}", sweep_csv(rows)),
+        OutputFormat::Json => print!("{}", sweep_json(rows)),
+        OutputFormat::Markdown => print!("{}", markdown_table(rows, labels)),
+        OutputFormat::Latex => {
+            let columns = match columns.map(Column::parse_list) {
+                None => {
+                    let mut columns = default_columns(labels.is_some());
+                    if rows.iter().any(|r| r.quantum_bits.is_some()) {
+                        columns.push(Column::Quantum);
+                    }
+                    columns
+                }
+                Some(Ok(c)) => c,
+                Some(Err(e)) => { eprintln!("Error: {}", e); process::exit(1); }
+            };
+            print!("{}", latex_table(rows, labels, &columns));
+        }
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    
-    if args.len() < 2 || args.iter().any(|a| a == "-h" || a == "--help") {
-        print_usage();
-        process::exit(if args.len() < 2 { 1 } else { 0 });
+fn print_curve(points: &[CurvePoint], x_name: &str, args: &cli::CurveArgs) {
+    if args.plot {
+        print!("{}", to_ascii(points, x_name, args.ascii));
+    } else if args.json {
+        print!("{}", to_json(points, x_name));
+    } else {
+        print!("{}", to_csv(points, x_name));
     }
+}
+
+/// Rows estimated in parallel before their results are written
+const BATCH_CHUNK: usize = 1024;
+
+/// Columns of one `--batch` row, in file order
+struct BatchColumns {
+    n: usize,
+    q: usize,
+    sigma: usize,
+    name: Option<usize>,
+}
+
+impl BatchColumns {
+    /// Column positions from a header line, or None when `line` is data
+    fn from_header(line: &[&str]) -> Option<Result<Self, String>> {
+        if parse_number(line[0]).is_ok() {
+            return None;
+        }
+        let find = |name: &str| line.iter().position(|c| c.eq_ignore_ascii_case(name));
+        Some(match (find("n"), find("q"), find("sigma")) {
+            (Some(n), Some(q), Some(sigma)) => Ok(Self { n, q, sigma, name: find("name") }),
+            _ => Err("header must name the columns n, q and sigma".to_string()),
+        })
+    }
+
+    fn parse(&self, line: &[&str]) -> Result<(String, usize, Modulus, f64), String> {
+        let field = |i: usize| line.get(i).copied().ok_or_else(|| format!("expected {} columns", line.len().max(i + 1)));
+        let n = parse_number(field(self.n)?)? as usize;
+        let q = parse_modulus(field(self.q)?)?;
+        let sigma = parse_real(field(self.sigma)?)?;
+        if n == 0 || q.log2() < 1.0 || sigma <= 0.0 {
+            return Err("Invalid parameters".to_string());
+        }
+        let name = self.name.and_then(|i| line.get(i)).map_or(String::new(), |s| s.to_string());
+        Ok((name, n, q, sigma))
+    }
+}
+
+/// Estimate every `n,q,sigma` row of a CSV file, or stdin for `-`, writing
+/// results as each chunk finishes. An optional header may reorder the
+/// columns and add a `name`; `#` starts a comment. CSV and text stream row
+/// by row, JSON as one object per line, markdown and LaTeX tables at the end.
+fn run_batch(args: &cli::EstimateArgs, path: &str) {
+    use rayon::prelude::*;
+    use std::io::BufRead;
+
+    let config = estimate_config(args);
+    let required = Required::from_args(&args.require);
+    let format = args.output.format.unwrap_or_default();
+    let palette = Palette::from_args(&args.output);
+    let quiet = args.porcelain || args.bits_only;
+    let secret = args.secret_dist;
+    let input: Box<dyn BufRead> = if path == "-" {
+        Box::new(std::io::stdin().lock())
+    } else {
+        match std::fs::File::open(path) {
+            Ok(f) => Box::new(std::io::BufReader::new(f)),
+            Err(e) => { eprintln!("Error: {}: {}", path, e); process::exit(1); }
+        }
+    };
+
+    let mut columns: Option<BatchColumns> = None;
+    let (mut bad_rows, mut failed) = (false, false);
+    let (mut tables, mut labels): (Vec<SecurityEstimate>, Vec<String>) = (Vec::new(), Vec::new());
+    let mut chunk: Vec<(String, usize, Modulus, f64)> = Vec::with_capacity(BATCH_CHUNK);
+    if format == OutputFormat::Csv && !quiet {
+        match args.quantum {
+            true => println!("{},{}", cryptoparam::sweep::CSV_COLUMNS, cryptoparam::sweep::CSV_QUANTUM_COLUMN),
+            false => println!("{}", cryptoparam::sweep::CSV_COLUMNS),
+        }
+    }
+    let mut lines = input.lines().enumerate().peekable();
+    while lines.peek().is_some() {
+        for (i, line) in lines.by_ref() {
+            let line = match line {
+                Ok(l) => l,
+                Err(e) => { eprintln!("Error: {}: {}", path, e); process::exit(1); }
+            };
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if columns.is_none() {
+                match BatchColumns::from_header(&fields) {
+                    Some(Ok(c)) => { columns = Some(c); continue; }
+                    Some(Err(e)) => { eprintln!("Error: {}:{}: {}", path, i + 1, e); process::exit(1); }
+                    None => {}
+                }
+            }
+            let cols = columns.get_or_insert(BatchColumns { n: 0, q: 1, sigma: 2, name: None });
+            match cols.parse(&fields).and_then(|row| secret.check(row.1).map(|_| row)) {
+                Ok(row) => chunk.push(row),
+                Err(e) => { eprintln!("Error: {}:{}: {}", path, i + 1, e); bad_rows = true; }
+            }
+            if chunk.len() == BATCH_CHUNK {
+                break;
+            }
+        }
+        let rows: Vec<SecurityEstimate> = chunk
+            .par_iter()
+            .map(|&(_, n, q, sigma)| estimate_modulus(&config, n, q, sigma).expect("rows are checked when read"))
+            .collect();
+        let mut out = std::io::stdout().lock();
+        for ((name, ..), r) in chunk.drain(..).zip(rows) {
+            let written = match format {
+                _ if args.bits_only => writeln!(out, "{}", bits_only(&r)),
+                _ if args.porcelain => writeln!(out, "{}", porcelain(&r)),
+                OutputFormat::Text if name.is_empty() => writeln!(out, "{}", palette.paint(r.classical_bits, &r.to_string())),
+                OutputFormat::Text => writeln!(out, "{}", palette.paint(r.classical_bits, &format!("{}: {}", name, r))),
+                OutputFormat::Csv => writeln!(out, "{}", sweep_csv_row(&r)),
+                OutputFormat::Json => writeln!(out, "{}", sweep_json_row(&r)),
+                OutputFormat::Markdown | OutputFormat::Latex => Ok(()),
+            };
+            // A closed pipe, e.g. `| head`, ends the run quietly
+            if written.is_err() {
+                return;
+            }
+            if format == OutputFormat::Text && !quiet {
+                print_warnings(&r);
+            }
+            failed |= required.report(&r);
+            if matches!(format, OutputFormat::Markdown | OutputFormat::Latex) && !quiet {
+                labels.push(name);
+                tables.push(r);
+            }
+        }
+    }
+    if !tables.is_empty() {
+        let labels = if labels.iter().any(|n| !n.is_empty()) { Some(labels.as_slice()) } else { None };
+        print_table(&tables, labels, format, args.output.columns.as_deref(), &palette);
+    }
+    if bad_rows {
+        process::exit(1);
+    }
+    if failed {
+        process::exit(EXIT_BELOW_REQUIRED);
+    }
+}
+
+/// Dump the (m, β, cost) candidates behind `r` to stderr or `--trace-file`
+fn write_trace(args: &cli::EstimateArgs, r: &SecurityEstimate) {
+    let mut steps = primal_usvp_trace_with(r.n, r.log2_q, r.sigma, embedding_scale(r), r.max_samples, false);
+    let model = cost_model::by_id(&r.provenance.cost_model).unwrap_or(cost_model::for_sieving(false));
+    steps.iter_mut().for_each(|s| s.bits = model.cost(s.beta, s.d));
+    let csv = trace_csv(&steps);
+    match &args.trace_file {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, csv) {
+                eprintln!("Error: {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+        None => eprint!("{}", csv),
+    }
+}
+
+/// σ and the convention it was given in, from `--noise-bits` or `--eta`
+fn noise_opt(args: &cli::EstimateArgs, log2_q: f64) -> Result<Option<(f64, String)>, String> {
+    // --noise-bits B is the TFHE convention σ = 2^-B·q
+    if let Some(b) = args.noise_bits {
+        return Ok(Some(((log2_q - b).exp2(), format!("2^-{}·q", b))));
+    }
+    if let Some(eta) = args.eta {
+        return match eta {
+            1..=0xffff_ffff => Ok(Some((presets::cbd_sigma(eta as u32), format!("CBD(η={})", eta)))),
+            _ => Err(format!("invalid eta '{}'", eta)),
+        };
+    }
+    Ok(None)
+}
+
+/// Estimate options from `--model` (or `--sieving`), `--secret-dist`,
+/// `--samples` and `--quantum`
+fn estimate_config(args: &cli::EstimateArgs) -> EstimateConfig {
+    EstimateConfig::new()
+        .model(args.model.cost_model())
+        .secret(args.secret_dist)
+        .max_samples(args.samples)
+        .quantum(args.quantum)
+}
+
+/// Estimate with an exact modulus when there is one, else from its log2
+fn estimate_modulus(config: &EstimateConfig, n: usize, q: Modulus, sigma: f64) -> Result<SecurityEstimate, CryptoParamError> {
+    match q {
+        Modulus::Exact(q) => config.estimate(n, q, sigma),
+        Modulus::Log2(log2_q) => config.estimate_log2q(n, log2_q, sigma),
+    }
+}
+
+fn run_estimate(args: &cli::EstimateArgs) {
+    if let Some(path) = &args.batch {
+        return run_batch(args, path);
+    }
+    let verbose = args.verbose;
+    let sieving = args.model.sieving;
     
-    let verbose = args.iter().any(|a| a == "-v" || a == "--verbose");
-    let sieving = args.iter().any(|a| a == "--sieving");
-    
-    let positional: Vec<&String> = args[1..]
-        .iter()
-        .filter(|a| !a.starts_with('-'))
-        .collect();
+    let positional = &args.params;
     
-    if positional.len() < 3 {
-        eprintln!("Error: Expected 3 arguments: n, q, sigma");
-        process::exit(1);
+    let noise_given = args.noise_bits.is_some() || args.eta.is_some();
+    match (positional.len(), noise_given) {
+        (3, true) => {
+            eprintln!("Error: give either sigma or one of --noise-bits, --eta, not both");
+            process::exit(1);
+        }
+        (2, true) | (3, false) => {}
+        _ => {
+            eprintln!("Error: Expected 3 arguments: n, q, sigma");
+            process::exit(1);
+        }
     }
     
-    let n: usize = match parse_number(positional[0]) {
+    let n: usize = match parse_number(&positional[0]) {
         Ok(v) => v as usize,
         Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
     };
     
-    let q: u64 = match parse_number(positional[1]) {
+    let q = match parse_modulus(&positional[1]) {
         Ok(v) => v,
         Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
     };
     
-    let sigma: f64 = match positional[2].parse() {
-        Ok(v) => v,
-        Err(_) => { eprintln!("Error: Invalid sigma"); process::exit(1); }
+    let (sigma, noise) = match noise_opt(args, q.log2()) {
+        Ok(Some((sigma, noise))) => (sigma, Some(noise)),
+        Ok(None) => match parse_real(&positional[2]) {
+            Ok(v) => (v, None),
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        },
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
     };
     
-    if n == 0 || q < 2 || sigma <= 0.0 {
+    if n == 0 || q.log2() < 1.0 || sigma <= 0.0 {
         eprintln!("Error: Invalid parameters");
         process::exit(1);
     }
     
-    let result = estimate_core(n, q, sigma, sieving);
+    let required = Required::from_args(&args.require);
+    let baseline = baseline_opt(args);
+    let palette = Palette::from_args(&args.output);
+    let config = estimate_config(args);
+    let (secret, samples) = (args.secret_dist, args.samples);
+    let mut result = match estimate_modulus(&config, n, q, sigma) {
+        Ok(r) => r,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    };
+    result.noise = noise;
+    if args.trace || args.trace_file.is_some() {
+        write_trace(args, &result);
+    }
+
+    if let Some((saved, tolerance)) = baseline {
+        let b = match baseline::find(&saved, &result) {
+            Ok(b) => b,
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        };
+        print!("{}", diff_table(b, &result));
+        print_warnings(&result);
+        required.check(std::slice::from_ref(&result));
+        let drift = bits_drift(b, &result);
+        if drift > tolerance {
+            eprintln!("error: estimate moved {:.2} bits from the baseline, beyond the tolerance of {}", drift, tolerance);
+            process::exit(EXIT_BASELINE_DRIFT);
+        }
+        return;
+    }
     
-    if verbose {
-        let q_bits = (q as f64).log2();
-        let model = if sieving { "sieving" } else { "core-svp" };
-        
-        println!("Parameters:");
-        println!("  n     = {}", n);
-        println!("  q     = {} (≈2^{:.1})", q, q_bits);
-        println!("  σ     = {}", sigma);
-        println!();
-        println!("Attack: primal uSVP");
-        println!("  β     = {}", result.beta);
-        println!("  d     = {}", result.d);
-        println!("  m     = {}", result.m);
-        println!();
-        if result.beta < 10000 {
-            println!("Security: {:.1} bits ({})", result.classical_bits, model);
-        } else {
-            println!("Security: No lattice attack found");
+    if let Some(attack) = &args.attack {
+        if let Err(e) = check_attack(attack) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
         }
+        // The headline is the primal attack; any other name is evaluated alone
+        if attack == "all" || *attack != result.attack {
+            let chosen = |a: &str| attack == "all" || a == attack;
+            let mut costs = lwe_attacks_with(n, result.log2_q, sigma, secret, samples, sieving);
+            costs.retain(|c| chosen(c.attack()));
+            reprice_attacks(args.model.model, &mut costs);
+            print!("{}", attack_table(&costs));
+            if verbose {
+                let error = DiscreteGaussian::new(sigma);
+                let mut results = lwe_attack_results(n, result.log2_q, &*secret.resolve(sigma), &error, samples, sieving);
+                results.retain(|r| chosen(r.attack()));
+                print!("\n{}", attack_details(&results));
+            }
+            print_warnings(&result);
+            return required.check(std::slice::from_ref(&result));
+        }
+    }
+
+    if args.bits_only {
+        println!("{}", bits_only(&result));
+    } else if args.porcelain {
+        println!("{}", porcelain(&result));
+    } else if let Some(f) = args.output.format {
+        print_rows(std::slice::from_ref(&result), None, f, &args.output);
+    } else if verbose {
+        println!("{}", palette.paint(result.classical_bits, &format!("{:#}", result)));
+        print_warnings(&result);
+    } else if args.explain {
+        println!("{}\n", palette.paint(result.classical_bits, &result.to_string()));
+        print!("{}", explain(&result));
+        print_warnings(&result);
     } else {
-        println!("{}", format_result(&result));
+        println!("{}", palette.paint(result.classical_bits, &result.to_string()));
+        print_warnings(&result);
+    }
+    required.check(std::slice::from_ref(&result));
+}
+
+fn run_search(args: &cli::SearchArgs) {
+    let (param, target, sieving) = (args.vary, args.target_bits, args.sieving);
+    let margin = args.margin.unwrap_or(SecurityMargin::None);
+    let (mut n, mut q, mut sigma) = (None, None, None);
+    for fix in &args.fix {
+        let Some((name, value)) = fix.split_once('=') else {
+            eprintln!("Error: --fix expects NAME=VALUE, got '{}'", fix);
+            process::exit(1);
+        };
+        let parsed = match SearchParam::parse(name) {
+            Ok(p) if p == param => Err(format!("{} is both fixed and varied", name)),
+            Ok(SearchParam::N) => parse_number(value).map(|v| n = Some(v as usize)),
+            Ok(SearchParam::LogQ) => parse_number(value).map(|v| q = Some(v)),
+            Ok(SearchParam::Sigma) => parse_real(value).map(|v| sigma = Some(v)),
+            Ok(SearchParam::H) => Err("h can only be varied, not fixed".to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = parsed {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+    let missing: Vec<&str> = [("n", n.is_none()), ("q", q.is_none()), ("sigma", sigma.is_none())]
+        .iter()
+        .filter(|&&(name, unset)| unset && SearchParam::parse(name) != Ok(param))
+        .map(|&(name, _)| name)
+        .collect();
+    if !missing.is_empty() {
+        eprintln!("Error: fix {} with --fix NAME=VALUE", missing.join(" and "));
+        process::exit(1);
+    }
+    let (n, q, sigma) = (n.unwrap_or(MIN_SEARCH_N), q.unwrap_or(2), sigma.unwrap_or(1.0));
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        eprintln!("Error: Invalid parameters");
+        process::exit(1);
+    }
+    let bar = progress_bar(&args.progress, OutputFormat::Text);
+    let update = |p: &Progress| bar.as_ref().map_or((), |b| b.update(p));
+    let found = match &args.checkpoint {
+        Some(path) => {
+            match solve_checkpointed(param, n, q, sigma, target, margin, sieving, false, path, args.resume, &update) {
+                Ok(found) => found,
+                Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+            }
+        }
+        None => solve_with_progress(param, n, q, sigma, target, margin, sieving, false, &update),
+    };
+    if let Some(b) = &bar {
+        b.finish();
+    }
+    let Some(r) = found else {
+        eprintln!("Error: no value in the search range reaches {} bits", margin.apply(target));
+        process::exit(1);
+    };
+    match param {
+        SearchParam::N => println!("Recommended n = {}", r.n),
+        SearchParam::LogQ => println!("Recommended q = {} (≈2^{:.1})", r.q, r.estimate.log2_q),
+        SearchParam::Sigma => println!("Recommended σ = {}", r.sigma),
+        SearchParam::H => println!("Recommended h = {}", r.h.unwrap_or_default()),
+    }
+    println!("  {}", r.estimate);
+    println!(
+        "  target {} bits, margin {} → {:.1} bits needed, {:+.1} bits to spare",
+        r.target_bits,
+        r.margin,
+        r.effective_target_bits,
+        r.estimate.classical_bits - r.effective_target_bits
+    );
+    print_warnings(&r.estimate);
+}
+
+/// Estimate for a `compare` operand: a preset name, a run-configuration
+/// file with one parameter set, or inline `n,q,sigma`
+fn compare_operand(arg: &str, sieving: bool) -> (String, SecurityEstimate) {
+    if let Some(p) = presets::by_name(arg) {
+        return (p.name.clone(), p.estimate(sieving));
+    }
+    let path = Path::new(arg);
+    if path.exists() {
+        let mut config = match RunConfig::load(path) {
+            Ok(c) => c,
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        };
+        if config.params.len() != 1 {
+            eprintln!("Error: {}: expected one parameter set, found {}", arg, config.params.len());
+            process::exit(1);
+        }
+        config.sieving |= sieving;
+        let name = match config.params[0].name.as_str() {
+            "" => path.file_stem().map_or(arg.to_string(), |s| s.to_string_lossy().into_owned()),
+            name => name.to_string(),
+        };
+        return (name, config.estimates().remove(0));
+    }
+    match arg.split(',').collect::<Vec<_>>().as_slice() {
+        [n, q, sigma] => match (parse_number(n.trim()), parse_modulus(q.trim()), parse_real(sigma.trim())) {
+            (Ok(n), Ok(q), Ok(sigma)) if n > 0 && q.log2() >= 1.0 && sigma > 0.0 => {
+                match estimate_modulus(&EstimateConfig::new().sieving(sieving), n as usize, q, sigma) {
+                    Ok(r) => (arg.to_string(), r),
+                    Err(e) => { eprintln!("Error: {}: {}", arg, e); process::exit(1); }
+                }
+            }
+            _ => { eprintln!("Error: Invalid parameters '{}'", arg); process::exit(1); }
+        },
+        _ => {
+            eprintln!("Error: '{}' is not a preset, a file or n,q,sigma", arg);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_compare(args: &cli::CompareArgs) {
+    let sieving = args.model.sieving;
+    let operands: Vec<String> = match args.operands.as_slice() {
+        [a, b] => vec![a.clone(), b.clone()],
+        [n1, q1, s1, n2, q2, s2] => vec![format!("{},{},{}", n1, q1, s1), format!("{},{},{}", n2, q2, s2)],
+        _ => {
+            eprintln!("Error: 'compare' expects two operands or <n> <q> <sigma> twice");
+            process::exit(1);
+        }
+    };
+    let (la, mut a) = compare_operand(&operands[0], sieving);
+    let (lb, mut b) = compare_operand(&operands[1], sieving);
+    apply_model(args.model.model, args.quantum, std::slice::from_mut(&mut a));
+    apply_model(args.model.model, args.quantum, std::slice::from_mut(&mut b));
+    print!("{}", compare_table(&a, &b, [&la, &lb]));
+    print_warnings(&a);
+    print_warnings(&b);
+}
+
+fn run_repl(args: &cli::ReplArgs) {
+    use std::io::IsTerminal;
+
+    let positional = &args.params;
+    let number = |i: usize| {
+        positional.get(i).map(|v| match parse_number(v) {
+            Ok(v) => v,
+            Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+        })
+    };
+    let (n, q) = (number(0).map(|n| n as usize), number(1));
+    let sigma = positional.get(2).map(|s| match parse_real(s) {
+        Ok(v) => v,
+        Err(e) => { eprintln!("Error: {}", e); process::exit(1); }
+    });
+    let model = args.model.cost_model();
+    let mut session = repl::Session::new(n, q, sigma, model);
+    let stdin = std::io::stdin();
+    let prompt = stdin.is_terminal();
+    if let Err(e) = repl::run(&mut session, stdin.lock(), std::io::stdout().lock(), prompt) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run_schema() {
+    println!("{}", serde_json::to_string_pretty(&descriptor::json_schema()).unwrap_or_default());
+}
+
+fn run_bench(args: &cli::BenchArgs) {
+    if cfg!(debug_assertions) {
+        eprintln!("note: debug build; build with --release for representative timings");
+    }
+    print!("{}", bench::table(&bench::run(std::time::Duration::from_millis(args.budget))));
+}
+
+fn run(command: &Commands) {
+    match command {
+        Commands::Estimate(args) => run_estimate(args),
+        Commands::Search(args) => run_search(args),
+        Commands::Attacks(args) => run_attacks(args),
+        Commands::Compare(args) => run_compare(args),
+        Commands::CurveN(args) => run_curve_n(args),
+        Commands::CurveLogq(args) => run_curve_logq(args),
+        Commands::Sweep(args) => run_sweep(args),
+        Commands::Presets(args) => run_presets(args),
+        Commands::Nearest(args) => run_nearest(args),
+        Commands::Verify(args) => run_verify(args),
+        Commands::Report(args) => run_report(args),
+        Commands::Sage(args) => run_sage(args),
+        Commands::Repl(args) => run_repl(args),
+        Commands::Schema => run_schema(),
+        Commands::HeStd(args) => run_he_std(args),
+        Commands::Bench(args) => run_bench(args),
+    }
+}
+
+fn set_threads(threads: Option<usize>) {
+    if let Some(t) = threads {
+        if let Err(e) = rayon::ThreadPoolBuilder::new().num_threads(t).build_global() {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let mut cli = match Cli::try_parse_from(cli::with_default_command(env::args().collect())) {
+        Ok(cli) => cli,
+        // --help and --version go to stdout; usage errors exit with 1,
+        // as 2 means a result below --require-bits
+        Err(e) => {
+            let _ = e.print();
+            process::exit(if e.use_stderr() { 1 } else { 0 });
+        }
+    };
+    if let Some(dir) = &cli.generate_man {
+        let written = match dir {
+            Some(dir) => cli::write_man_pages(dir, EXIT_STATUS),
+            None => man_page(EXIT_STATUS).and_then(|page| std::io::stdout().write_all(&page)),
+        };
+        if let Err(e) = written {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+    load_profile(&mut cli);
+
+    match (&cli.command, &cli.config) {
+        (None, Some(path)) => run_config(&cli.run, cli.global.threads, path),
+        (Some(command), None) => {
+            set_threads(cli.global.threads);
+            run(command);
+        }
+        (Some(_), Some(_)) => {
+            eprintln!("Error: --config takes no subcommand (see cryptoparam --help)");
+            process::exit(1);
+        }
+        (None, None) => {
+            eprintln!("Error: expected a command or --config (see cryptoparam --help)");
+            process::exit(1);
+        }
     }
 }
@@ -0,0 +1,246 @@
+//! Cost-weighted parameter optimization
+//!
+//! Picks the cheapest parameter sets that still meet a security target,
+//! where "cheap" is a user-weighted mix of size and speed proxies.
+
+use crate::budget::{ByteBudget, SizeTemplate};
+#[cfg(feature = "python")]
+use crate::parallel::with_threads;
+use crate::search::{solve, SearchParam, SecurityMargin};
+use crate::SecurityEstimate;
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+// ============================================================================
+// Cost Model
+// ============================================================================
+
+/// Weights of the individual cost terms; zero disables a term
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CostWeights {
+    /// Weight per ciphertext byte, sized by the budget's template
+    pub ciphertext_bytes: f64,
+    /// Weight per public-key byte, sized by the budget's template
+    pub public_key_bytes: f64,
+    /// Weight per unit of the n·⌈log q⌉ arithmetic time proxy
+    pub time_proxy: f64,
+}
+
+impl Default for CostWeights {
+    fn default() -> Self {
+        Self { ciphertext_bytes: 1.0, public_key_bytes: 0.0, time_proxy: 0.0 }
+    }
+}
+
+/// Size/speed metrics of a parameter set: (ciphertext bytes, public-key
+/// bytes, time proxy)
+pub fn cost_terms(template: SizeTemplate, n: usize, q: u64) -> (f64, f64, f64) {
+    let (ct, pk) = template.sizes(n, q);
+    let time = n as f64 * (q as f64).log2().ceil();
+    (ct, pk, time)
+}
+
+// ============================================================================
+// Optimizer
+// ============================================================================
+
+/// A parameter set meeting the target, with its trade-offs
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate {
+    pub n: usize,
+    pub q: u64,
+    pub sigma: f64,
+    pub ciphertext_bytes: f64,
+    pub public_key_bytes: f64,
+    pub time_proxy: f64,
+    /// Weighted total being minimised
+    pub cost: f64,
+    pub estimate: SecurityEstimate,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Candidate {
+    fn __repr__(&self) -> String {
+        format!(
+            "Candidate(n={}, q={}, sigma={}, bits={:.1}, ct={:.0}B, pk={:.0}B, cost={:.1})",
+            self.n,
+            self.q,
+            self.sigma,
+            self.estimate.classical_bits,
+            self.ciphertext_bytes,
+            self.public_key_bytes,
+            self.cost
+        )
+    }
+}
+
+/// Cheapest `top` parameter sets reaching `target_bits` (plus margin).
+///
+/// For every (q, σ) pair the smallest sufficient n is found by bisection;
+/// pairs whose sizes blow `budget` are dropped, and the rest are ranked by
+/// the weighted cost.
+#[allow(clippy::too_many_arguments)]
+pub fn optimize(
+    q_values: &[u64],
+    sigma_values: &[f64],
+    target_bits: f64,
+    margin: SecurityMargin,
+    weights: CostWeights,
+    budget: ByteBudget,
+    top: usize,
+    sieving: bool,
+) -> Vec<Candidate> {
+    let pairs: Vec<(u64, f64)> = q_values
+        .iter()
+        .flat_map(|&q| sigma_values.iter().map(move |&s| (q, s)))
+        .collect();
+
+    let mut candidates: Vec<Candidate> = pairs
+        .par_iter()
+        .filter_map(|&(q, sigma)| {
+            let r = solve(SearchParam::N, 0, q, sigma, target_bits, margin, sieving).ok()?;
+            if !budget.admits(r.n, q) {
+                return None;
+            }
+            let (ct, pk, time) = cost_terms(budget.template, r.n, q);
+            let cost = weights.ciphertext_bytes * ct
+                + weights.public_key_bytes * pk
+                + weights.time_proxy * time;
+            Some(Candidate {
+                n: r.n,
+                q,
+                sigma,
+                ciphertext_bytes: ct,
+                public_key_bytes: pk,
+                time_proxy: time,
+                cost,
+                estimate: r.estimate,
+            })
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| a.cost.total_cmp(&b.cost));
+    candidates.truncate(top);
+    candidates
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Find the cheapest parameter sets meeting a security target.
+///
+/// Args:
+///     q_values: Candidate moduli
+///     sigma_values: Candidate error standard deviations
+///     target_bits: Required security in bits
+///     ciphertext_weight: Weight per ciphertext byte (default: 1.0)
+///     public_key_weight: Weight per public-key byte (default: 0.0)
+///     time_weight: Weight of the n·log q time proxy (default: 0.0)
+///     top: Number of candidates to return (default: 5)
+///     template: Size layout, "lwe", "rlwe" or "mlwe:<rank>" (default: "lwe")
+///     max_ciphertext_bytes: Reject larger ciphertexts (default: no limit)
+///     max_public_key_bytes: Reject larger public keys (default: no limit)
+///     margin: Safety margin, e.g. "+16" or "15%" (default: none)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     List of Candidate, cheapest first
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "optimize",
+    signature = (
+        q_values, sigma_values, target_bits,
+        ciphertext_weight = 1.0, public_key_weight = 0.0, time_weight = 0.0,
+        top = 5, template = "lwe", max_ciphertext_bytes = None,
+        max_public_key_bytes = None, margin = None, sieving = false, n_threads = None
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_optimize(
+    py: Python,
+    q_values: Vec<u64>,
+    sigma_values: Vec<Sigma>,
+    target_bits: f64,
+    ciphertext_weight: f64,
+    public_key_weight: f64,
+    time_weight: f64,
+    top: usize,
+    template: &str,
+    max_ciphertext_bytes: Option<f64>,
+    max_public_key_bytes: Option<f64>,
+    margin: Option<&str>,
+    sieving: bool,
+    n_threads: Option<usize>,
+) -> PyResult<Vec<Candidate>> {
+    let sigma_values = sigma_values.into_iter().map(|s| s.0).collect::<Vec<f64>>();
+    q_values.iter().try_for_each(|&q| crate::error::check_modulus(q))?;
+    sigma_values.iter().try_for_each(|&s| crate::error::check_sigma(s))?;
+    let margin = match margin {
+        Some(s) => SecurityMargin::parse(s)?,
+        None => SecurityMargin::None,
+    };
+    let weights = CostWeights {
+        ciphertext_bytes: ciphertext_weight,
+        public_key_bytes: public_key_weight,
+        time_proxy: time_weight,
+    };
+    let budget = ByteBudget {
+        template: SizeTemplate::parse(template).map_err(crate::errors::InvalidParameters::new_err)?,
+        max_ciphertext_bytes,
+        max_public_key_bytes,
+    };
+    Ok(py.allow_threads(|| {
+        with_threads(n_threads, || {
+            optimize(&q_values, &sigma_values, target_bits, margin, weights, budget, top, sieving)
+        })
+    }))
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimize_ranks_by_cost() {
+        let c = optimize(
+            &[3329, 7681, 12289],
+            &[3.0, 8.0],
+            80.0,
+            SecurityMargin::None,
+            CostWeights::default(),
+            ByteBudget::default(),
+            3,
+            false,
+        );
+        assert_eq!(c.len(), 3);
+        assert!(c.windows(2).all(|w| w[0].cost <= w[1].cost));
+        assert!(c.iter().all(|x| x.estimate.classical_bits >= 80.0));
+    }
+
+    #[test]
+    fn test_optimize_respects_budget() {
+        let run = |budget| {
+            let qs = [3329, 7681, 12289];
+            optimize(&qs, &[3.0, 8.0], 80.0, SecurityMargin::None, CostWeights::default(), budget, 10, false)
+        };
+        let all = run(ByteBudget::default());
+        let cap = all.iter().map(|c| c.ciphertext_bytes).fold(f64::INFINITY, f64::min);
+        let c = run(ByteBudget { max_ciphertext_bytes: Some(cap), ..Default::default() });
+        assert!(!c.is_empty() && c.len() < all.len());
+        assert!(c.iter().all(|x| x.ciphertext_bytes <= cap));
+    }
+}
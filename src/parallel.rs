@@ -0,0 +1,32 @@
+//! Thread-count control for the parallel engines
+//!
+//! Sweeps, curves and multi-candidate searches run on rayon. By default they
+//! use the global pool (one thread per core, or `RAYON_NUM_THREADS`);
+//! [`with_threads`] runs them on a dedicated pool of a given size instead.
+
+/// Run `f` on a pool of `threads` workers; `None` or `Some(0)` uses the
+/// global pool.
+pub fn with_threads<R, F>(threads: Option<usize>, f: F) -> R
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    match threads {
+        None | Some(0) => f(),
+        Some(t) => match rayon::ThreadPoolBuilder::new().num_threads(t).build() {
+            Ok(pool) => pool.install(f),
+            Err(_) => f(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_threads_sets_pool_size() {
+        assert_eq!(with_threads(Some(3), rayon::current_num_threads), 3);
+        assert_eq!(with_threads(None, rayon::current_num_threads), rayon::current_num_threads());
+    }
+}
@@ -0,0 +1,206 @@
+//! matplotlib plotting helpers
+//!
+//! The `cryptoparam.plot` submodule draws the common figures (security
+//! against n or log2 q, the cost/security trade-off of `optimize`) in one
+//! call. matplotlib is imported on first use, so it stays an optional
+//! dependency.
+
+use crate::curve::{security_vs_log_q, security_vs_n, CurvePoint, DEFAULT_MAX_POINTS};
+use crate::optimize::Candidate;
+use crate::Sigma;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Points with finite security, as (x, bits) columns; "no attack" samples
+/// would otherwise wreck the y-axis autoscaling
+pub fn finite_xy(points: &[CurvePoint]) -> (Vec<f64>, Vec<f64>) {
+    points.iter().filter(|p| p.bits.is_finite()).map(|p| (p.x, p.bits)).unzip()
+}
+
+/// `ax`, or the axes of a fresh figure
+fn axes<'py>(py: Python<'py>, ax: Option<&'py PyAny>) -> PyResult<&'py PyAny> {
+    if let Some(ax) = ax {
+        return Ok(ax);
+    }
+    let plt = py
+        .import("matplotlib.pyplot")
+        .map_err(|_| pyo3::exceptions::PyImportError::new_err("plotting needs matplotlib (pip install matplotlib)"))?;
+    plt.call_method0("subplots")?.get_item(1)
+}
+
+/// β-staircase of `points` on `ax`, with an optional target line
+fn draw_curve<'py>(
+    py: Python<'py>,
+    ax: &'py PyAny,
+    points: &[CurvePoint],
+    x_label: &str,
+    label: String,
+    target_bits: Option<f64>,
+) -> PyResult<&'py PyAny> {
+    let (xs, bits) = finite_xy(points);
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("where", "post")?;
+    kwargs.set_item("label", label)?;
+    ax.call_method("step", (xs, bits), Some(kwargs))?;
+    if let Some(t) = target_bits {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("linestyle", "--")?;
+        kwargs.set_item("color", "grey")?;
+        kwargs.set_item("label", format!("target {} bits", t))?;
+        ax.call_method("axhline", (t,), Some(kwargs))?;
+    }
+    ax.call_method1("set_xlabel", (x_label,))?;
+    ax.call_method1("set_ylabel", ("security (bits)",))?;
+    ax.call_method0("legend")?;
+    Ok(ax)
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Plot security against n for fixed q and σ.
+///
+/// Args:
+///     q: Modulus
+///     sigma: Error standard deviation
+///     n_min, n_max: Range of dimensions
+///     sieving: Use aggressive sieving cost model (default: False)
+///     target_bits: Draw a horizontal target line (default: None)
+///     ax: matplotlib Axes to draw on; a new figure when None (default: None)
+///
+/// Returns:
+///     The Axes
+///
+/// Example:
+///     >>> plot.plot_security_vs_n(12289, 3.19, 256, 1024, target_bits=128)
+#[pyfunction]
+#[pyo3(
+    name = "plot_security_vs_n",
+    signature = (q, sigma, n_min, n_max, sieving = false, target_bits = None, ax = None)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_plot_security_vs_n<'py>(
+    py: Python<'py>,
+    q: u64,
+    sigma: Sigma,
+    n_min: usize,
+    n_max: usize,
+    sieving: bool,
+    target_bits: Option<f64>,
+    ax: Option<&'py PyAny>,
+) -> PyResult<&'py PyAny> {
+    let sigma = sigma.0;
+    if n_min == 0 || n_max < n_min || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need 0 < n_min <= n_max, q >= 2 and sigma > 0"));
+    }
+    let ax = axes(py, ax)?;
+    let points = py.allow_threads(|| security_vs_n(q, sigma, n_min, n_max, sieving, DEFAULT_MAX_POINTS));
+    draw_curve(py, ax, &points, "n", format!("q={}, σ={}", q, sigma), target_bits)
+}
+
+/// Plot security against log2 q for fixed n and σ.
+///
+/// Args:
+///     n: LWE dimension
+///     sigma: Error standard deviation
+///     log_q_min, log_q_max: Range of log2 q (may exceed 64)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     target_bits: Draw a horizontal target line (default: None)
+///     ax: matplotlib Axes to draw on; a new figure when None (default: None)
+///
+/// Returns:
+///     The Axes
+#[pyfunction]
+#[pyo3(
+    name = "plot_security_vs_log_q",
+    signature = (n, sigma, log_q_min, log_q_max, sieving = false, target_bits = None, ax = None)
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_plot_security_vs_log_q<'py>(
+    py: Python<'py>,
+    n: usize,
+    sigma: Sigma,
+    log_q_min: f64,
+    log_q_max: f64,
+    sieving: bool,
+    target_bits: Option<f64>,
+    ax: Option<&'py PyAny>,
+) -> PyResult<&'py PyAny> {
+    let sigma = sigma.0;
+    if n == 0 || !(1.0..=log_q_max).contains(&log_q_min) || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, 1 <= log_q_min <= log_q_max and sigma > 0"));
+    }
+    let ax = axes(py, ax)?;
+    let points = py.allow_threads(|| security_vs_log_q(n, sigma, log_q_min, log_q_max, sieving, DEFAULT_MAX_POINTS));
+    draw_curve(py, ax, &points, "log2 q", format!("n={}, σ={}", n, sigma), target_bits)
+}
+
+/// Scatter the cost/security trade-off of `optimize` candidates.
+///
+/// Args:
+///     frontier: List of Candidate, e.g. from `optimize(...)`
+///     x: Cost axis, "ciphertext_bytes", "public_key_bytes", "time_proxy"
+///         or "cost" (default: "ciphertext_bytes")
+///     ax: matplotlib Axes to draw on; a new figure when None (default: None)
+///
+/// Returns:
+///     The Axes; each point is annotated with its n
+#[pyfunction]
+#[pyo3(name = "plot_tradeoff", signature = (frontier, x = "ciphertext_bytes", ax = None))]
+pub fn py_plot_tradeoff<'py>(
+    py: Python<'py>,
+    frontier: Vec<Candidate>,
+    x: &str,
+    ax: Option<&'py PyAny>,
+) -> PyResult<&'py PyAny> {
+    let cost = |c: &Candidate| match x {
+        "ciphertext_bytes" => Ok(c.ciphertext_bytes),
+        "public_key_bytes" => Ok(c.public_key_bytes),
+        "time_proxy" => Ok(c.time_proxy),
+        "cost" => Ok(c.cost),
+        _ => Err(crate::errors::InvalidParameters::new_err(format!("unknown cost axis {:?}", x))),
+    };
+    let xs = frontier.iter().map(cost).collect::<PyResult<Vec<_>>>()?;
+    let bits: Vec<f64> = frontier.iter().map(|c| c.estimate.classical_bits).collect();
+    let ax = axes(py, ax)?;
+    ax.call_method1("scatter", (xs.clone(), bits.clone()))?;
+    for ((c, x), y) in frontier.iter().zip(&xs).zip(&bits) {
+        ax.call_method1("annotate", (format!("n={}", c.n), (*x, *y)))?;
+    }
+    ax.call_method1("set_xlabel", (x.replace('_', " "),))?;
+    ax.call_method1("set_ylabel", ("security (bits)",))?;
+    Ok(ax)
+}
+
+/// Add the `cryptoparam.plot` submodule
+pub fn register(py: Python, parent: &PyModule) -> PyResult<()> {
+    let m = PyModule::new(py, "plot")?;
+    m.add("__doc__", "matplotlib plotting helpers")?;
+    m.add_function(wrap_pyfunction!(py_plot_security_vs_n, m)?)?;
+    m.add_function(wrap_pyfunction!(py_plot_security_vs_log_q, m)?)?;
+    m.add_function(wrap_pyfunction!(py_plot_tradeoff, m)?)?;
+    parent.add_submodule(m)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("cryptoparam.plot", m)?;
+    Ok(())
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finite_xy_drops_infinite_samples() {
+        let points = [
+            CurvePoint { x: 1.0, bits: 80.0, beta: Some(274) },
+            CurvePoint { x: 2.0, bits: f64::INFINITY, beta: None },
+        ];
+        assert_eq!(finite_xy(&points), (vec![1.0], vec![80.0]));
+    }
+}
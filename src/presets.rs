@@ -0,0 +1,961 @@
+//! Named parameter sets of published schemes
+//!
+//! Each preset records the scheme's lattice problem as published, plus the
+//! plain-LWE view (dimension n·k, q, σ) the estimator works on. Signature
+//! schemes additionally carry the SIS instance a forger has to solve.
+
+use crate::distribution::{CenteredBinomial, Distribution};
+use crate::sis::{sis_core, SisEstimate, SisParams};
+use crate::{estimate_core, LweParams, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Standard deviation of the centered binomial distribution CBD(η)
+pub fn cbd_sigma(eta: u32) -> f64 {
+    CenteredBinomial::new(eta).std_dev(1)
+}
+
+/// Standard deviation of a FrodoKEM-style error table.
+///
+/// `cdf[k]` is the 15-bit cumulative count for |e| <= k; the sign is an
+/// independent fair bit.
+pub fn table_sigma(cdf: &[u16]) -> f64 {
+    let var: f64 = cdf
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            let k = (i + 1) as f64;
+            k * k * (w[1] - w[0]) as f64
+        })
+        .sum();
+    (var / 32768.0).sqrt()
+}
+
+/// Standard deviation of the deterministic error of rounding from Z_q to
+/// Z_p, the usual LWR-to-LWE conversion: uniform over q/p values
+pub fn lwr_sigma(q: u64, p: u64) -> f64 {
+    let r = q as f64 / p as f64;
+    ((r * r - 1.0) / 12.0).sqrt()
+}
+
+/// Standard deviation of the uniform distribution on [-η, η]
+pub fn uniform_sigma(eta: u32) -> f64 {
+    let e = eta as f64;
+    (e * (e + 1.0) / 3.0).sqrt()
+}
+
+/// A published parameter set
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preset {
+    /// Lookup name, e.g. "kyber768"
+    pub name: String,
+    /// Scheme family, e.g. "ML-KEM"
+    pub scheme: String,
+    /// Collection the preset belongs to: "nist", "legacy" or "tfhe"
+    pub group: String,
+    /// Underlying problem, e.g. "MLWE"
+    pub problem: String,
+    /// Ring degree (the LWE dimension for unstructured schemes)
+    pub n: usize,
+    /// Module rank of the secret; 1 for ring and plain LWE
+    pub k: usize,
+    pub q: u64,
+    /// η of the secret and key-generation error distribution, if bounded
+    pub eta: Option<u32>,
+    /// Error standard deviation used for estimation
+    pub sigma: f64,
+    /// NIST security category
+    pub nist_level: u8,
+    /// Classical core-SVP bits claimed by the designers
+    pub claimed_bits: f64,
+    /// Forgery problem of signature schemes
+    pub sis: Option<SisParams>,
+    /// LWE samples available to an attacker, when the scheme bounds them
+    pub samples: Option<usize>,
+    /// Cumulative error table for table-sampled errors, see [`table_sigma`]
+    pub error_cdf: Option<Vec<u16>>,
+    /// Rounding modulus of LWR schemes; `sigma` is then the rounding error
+    pub p: Option<u64>,
+}
+
+impl Preset {
+    /// LWE dimension seen by lattice attacks, n·k
+    pub fn dimension(&self) -> usize {
+        self.n * self.k
+    }
+
+    pub fn lwe_params(&self) -> LweParams {
+        LweParams { n: self.dimension(), q: self.q, sigma: self.sigma }
+    }
+
+    pub fn estimate(&self, sieving: bool) -> SecurityEstimate {
+        estimate_core(self.dimension(), self.q, self.sigma, sieving)
+    }
+
+    /// Estimate of the SIS side, for signature schemes
+    pub fn sis_estimate(&self, sieving: bool) -> Option<SisEstimate> {
+        self.sis.as_ref().map(|s| sis_core(s, sieving))
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Preset {
+    /// Plain-LWE view of the preset
+    #[pyo3(name = "lwe_params")]
+    fn py_lwe_params(&self) -> LweParams {
+        self.lwe_params()
+    }
+
+    /// Estimate the preset with this crate's model
+    #[pyo3(name = "estimate", signature = (sieving = false))]
+    fn py_estimate(&self, sieving: bool) -> SecurityEstimate {
+        self.estimate(sieving)
+    }
+
+    /// Estimate the SIS side, or None for encryption schemes
+    #[pyo3(name = "sis_estimate", signature = (sieving = false))]
+    fn py_sis_estimate(&self, sieving: bool) -> Option<SisEstimate> {
+        self.sis_estimate(sieving)
+    }
+
+    fn __repr__(&self) -> String {
+        let eta = self.eta.map(|e| format!(", η={}", e)).unwrap_or_default();
+        format!(
+            "Preset({}: {} n={}, k={}, q={}{}, NIST level {}, claimed {:.0} bits)",
+            self.name, self.problem, self.n, self.k, self.q, eta, self.nist_level, self.claimed_bits
+        )
+    }
+}
+
+// ============================================================================
+// ML-KEM / Kyber
+// ============================================================================
+
+const KYBER_Q: u64 = 3329;
+
+fn kyber(name: &str, k: usize, eta1: u32, nist_level: u8, claimed_bits: f64) -> Preset {
+    Preset {
+        name: name.to_string(),
+        scheme: "ML-KEM".to_string(),
+        group: "nist".to_string(),
+        problem: "MLWE".to_string(),
+        n: 256,
+        k,
+        q: KYBER_Q,
+        eta: Some(eta1),
+        sigma: cbd_sigma(eta1),
+        nist_level,
+        claimed_bits,
+        ..Default::default()
+    }
+}
+
+/// ML-KEM-512 (Kyber-512)
+pub fn kyber512() -> Preset {
+    kyber("kyber512", 2, 3, 1, 118.0)
+}
+
+/// ML-KEM-768 (Kyber-768)
+pub fn kyber768() -> Preset {
+    kyber("kyber768", 3, 2, 3, 182.0)
+}
+
+/// ML-KEM-1024 (Kyber-1024)
+pub fn kyber1024() -> Preset {
+    kyber("kyber1024", 4, 2, 5, 256.0)
+}
+
+// ============================================================================
+// ML-DSA / Dilithium
+// ============================================================================
+
+const DILITHIUM_Q: u64 = 8380417;
+
+/// Bits dropped from t
+const DILITHIUM_D: u32 = 13;
+
+/// Dilithium parameter set (k, l) with its η, τ, γ1, γ2 and β.
+///
+/// The MLWE side is key recovery on t = A·s1 + s2 with s1, s2 uniform in
+/// [-η, η]. The MSIS side is MSIS_{k, l+1} with
+/// ζ = max(γ1 − β, 2γ2 + 1 + 2^(d−1)·τ).
+#[allow(clippy::too_many_arguments)]
+fn dilithium(
+    name: &str,
+    k: usize,
+    l: usize,
+    eta: u32,
+    tau: u64,
+    gamma1: u64,
+    gamma2: u64,
+    beta: u64,
+    nist_level: u8,
+    claimed_bits: f64,
+) -> Preset {
+    let zeta = (gamma1 - beta).max(2 * gamma2 + 1 + (1 << (DILITHIUM_D - 1)) * tau);
+    Preset {
+        name: name.to_string(),
+        scheme: "ML-DSA".to_string(),
+        group: "nist".to_string(),
+        problem: "MLWE+MSIS".to_string(),
+        n: 256,
+        k: l,
+        q: DILITHIUM_Q,
+        eta: Some(eta),
+        sigma: uniform_sigma(eta),
+        nist_level,
+        claimed_bits,
+        sis: Some(SisParams {
+            n: k * 256,
+            m: (k + l + 1) * 256,
+            q: DILITHIUM_Q,
+            bound: zeta as f64,
+        }),
+        ..Default::default()
+    }
+}
+
+/// ML-DSA-44 (Dilithium2)
+pub fn dilithium2() -> Preset {
+    dilithium("dilithium2", 4, 4, 2, 39, 1 << 17, (DILITHIUM_Q - 1) / 88, 78, 2, 123.0)
+}
+
+/// ML-DSA-65 (Dilithium3)
+pub fn dilithium3() -> Preset {
+    dilithium("dilithium3", 6, 5, 4, 49, 1 << 19, (DILITHIUM_Q - 1) / 32, 196, 3, 182.0)
+}
+
+/// ML-DSA-87 (Dilithium5)
+pub fn dilithium5() -> Preset {
+    dilithium("dilithium5", 8, 7, 2, 60, 1 << 19, (DILITHIUM_Q - 1) / 32, 120, 5, 252.0)
+}
+
+// ============================================================================
+// Falcon
+// ============================================================================
+
+const FALCON_Q: u64 = 12289;
+
+/// Falcon over Z[x]/(x^n + 1). Secrets f, g are Gaussian with
+/// σ = 1.17·√(q / 2n). The spec states security only as NIST categories,
+/// so `claimed_bits` holds the category's nominal level.
+///
+/// Until a dedicated NTRU estimator exists, `estimate` treats key recovery
+/// as LWE in dimension n (h·f − g = 0 mod q).
+fn falcon(name: &str, n: usize, nist_level: u8, claimed_bits: f64) -> Preset {
+    Preset {
+        name: name.to_string(),
+        scheme: "Falcon".to_string(),
+        group: "nist".to_string(),
+        problem: "NTRU".to_string(),
+        n,
+        k: 1,
+        q: FALCON_Q,
+        sigma: 1.17 * (FALCON_Q as f64 / (2 * n) as f64).sqrt(),
+        nist_level,
+        claimed_bits,
+        ..Default::default()
+    }
+}
+
+/// Falcon-512
+pub fn falcon512() -> Preset {
+    falcon("falcon512", 512, 1, 128.0)
+}
+
+/// Falcon-1024
+pub fn falcon1024() -> Preset {
+    falcon("falcon1024", 1024, 5, 256.0)
+}
+
+// ============================================================================
+// FrodoKEM
+// ============================================================================
+
+/// Plain-LWE FrodoKEM with n̄ = 8. The attacker sees n + n̄ samples per
+/// secret column and errors follow the spec's rounded-Gaussian table. As
+/// with Falcon, `claimed_bits` holds the NIST category's nominal level.
+fn frodo(name: &str, n: usize, log_q: u32, cdf: &[u16], nist_level: u8, claimed_bits: f64) -> Preset {
+    Preset {
+        name: name.to_string(),
+        scheme: "FrodoKEM".to_string(),
+        group: "nist".to_string(),
+        problem: "LWE".to_string(),
+        n,
+        k: 1,
+        q: 1 << log_q,
+        sigma: table_sigma(cdf),
+        nist_level,
+        claimed_bits,
+        samples: Some(n + 8),
+        error_cdf: Some(cdf.to_vec()),
+        ..Default::default()
+    }
+}
+
+/// FrodoKEM-640
+pub fn frodo640() -> Preset {
+    const CDF: [u16; 13] = [
+        4643, 13363, 20579, 25843, 29227, 31145, 32103, 32525, 32689, 32745, 32762, 32766, 32767,
+    ];
+    frodo("frodo640", 640, 15, &CDF, 1, 128.0)
+}
+
+/// FrodoKEM-976
+pub fn frodo976() -> Preset {
+    const CDF: [u16; 11] = [5638, 15915, 23689, 28571, 31116, 32217, 32613, 32731, 32760, 32766, 32767];
+    frodo("frodo976", 976, 16, &CDF, 3, 192.0)
+}
+
+/// FrodoKEM-1344
+pub fn frodo1344() -> Preset {
+    const CDF: [u16; 7] = [9142, 23462, 30338, 32361, 32725, 32765, 32767];
+    frodo("frodo1344", 1344, 16, &CDF, 5, 256.0)
+}
+
+// ============================================================================
+// Saber
+// ============================================================================
+
+/// Module-LWR Saber with q = 2^13 and p = 2^10. The secret is CBD(μ/2);
+/// the rounding error is estimated as LWE error via [`lwr_sigma`].
+fn saber(name: &str, l: usize, mu: u32, nist_level: u8, claimed_bits: f64) -> Preset {
+    let (q, p) = (1 << 13, 1 << 10);
+    Preset {
+        name: name.to_string(),
+        scheme: "Saber".to_string(),
+        group: "nist".to_string(),
+        problem: "MLWR".to_string(),
+        n: 256,
+        k: l,
+        q,
+        eta: Some(mu / 2),
+        sigma: lwr_sigma(q, p),
+        nist_level,
+        claimed_bits,
+        p: Some(p),
+        ..Default::default()
+    }
+}
+
+/// LightSaber
+pub fn lightsaber() -> Preset {
+    saber("lightsaber", 2, 10, 1, 118.0)
+}
+
+/// Saber
+pub fn saber_kem() -> Preset {
+    saber("saber", 3, 8, 3, 203.0)
+}
+
+/// FireSaber
+pub fn firesaber() -> Preset {
+    saber("firesaber", 4, 6, 5, 283.0)
+}
+
+// ============================================================================
+// Legacy (NIST round 2)
+// ============================================================================
+
+/// Ring-LWE/LWR schemes that did not advance past the second NIST round,
+/// kept for historical comparison. `claimed_bits` holds the NIST
+/// category's nominal level.
+#[allow(clippy::too_many_arguments)]
+fn legacy(
+    name: &str,
+    scheme: &str,
+    problem: &str,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    nist_level: u8,
+    claimed_bits: f64,
+) -> Preset {
+    Preset {
+        name: name.to_string(),
+        scheme: scheme.to_string(),
+        group: "legacy".to_string(),
+        problem: problem.to_string(),
+        n,
+        k: 1,
+        q,
+        sigma,
+        nist_level,
+        claimed_bits,
+        ..Default::default()
+    }
+}
+
+/// NewHope512: CBD(8) over q = 12289
+pub fn newhope512() -> Preset {
+    Preset { eta: Some(8), ..legacy("newhope512", "NewHope", "RLWE", 512, 12289, cbd_sigma(8), 1, 128.0) }
+}
+
+/// NewHope1024: CBD(8) over q = 12289
+pub fn newhope1024() -> Preset {
+    Preset { eta: Some(8), ..legacy("newhope1024", "NewHope", "RLWE", 1024, 12289, cbd_sigma(8), 5, 256.0) }
+}
+
+/// LAC-128: ternary errors with Pr[±1] = 1/4 each over q = 251
+pub fn lac128() -> Preset {
+    legacy("lac128", "LAC", "RLWE", 512, 251, 0.5f64.sqrt(), 1, 128.0)
+}
+
+/// LAC-192: ternary errors with Pr[±1] = 1/8 each over q = 251
+pub fn lac192() -> Preset {
+    legacy("lac192", "LAC", "RLWE", 1024, 251, 0.5, 3, 192.0)
+}
+
+/// LAC-256: ternary errors with Pr[±1] = 1/4 each over q = 251
+pub fn lac256() -> Preset {
+    legacy("lac256", "LAC", "RLWE", 1024, 251, 0.5f64.sqrt(), 5, 256.0)
+}
+
+/// Round5 ring (R5ND_{1,3,5}KEM_0d): rounding from q to p, sparse ternary
+/// secret, error estimated via [`lwr_sigma`]
+fn round5(name: &str, n: usize, log_q: u32, log_p: u32, nist_level: u8, claimed_bits: f64) -> Preset {
+    let (q, p) = (1u64 << log_q, 1u64 << log_p);
+    Preset {
+        p: Some(p),
+        ..legacy(name, "Round5", "RLWR", n, q, lwr_sigma(q, p), nist_level, claimed_bits)
+    }
+}
+
+/// R5ND_1KEM_0d
+pub fn round5_1() -> Preset {
+    round5("r5nd_1kem_0d", 618, 11, 8, 1, 128.0)
+}
+
+/// R5ND_3KEM_0d
+pub fn round5_3() -> Preset {
+    round5("r5nd_3kem_0d", 786, 13, 9, 3, 192.0)
+}
+
+/// R5ND_5KEM_0d
+pub fn round5_5() -> Preset {
+    round5("r5nd_5kem_0d", 1018, 14, 9, 5, 256.0)
+}
+
+// ============================================================================
+// TFHE
+// ============================================================================
+
+/// One component of a TFHE parameter set: the LWE key (k = 1, n = LWE
+/// dimension) or the GLWE bootstrapping key (k = GLWE dimension, n =
+/// polynomial size). `std_dev` is relative to q as in the libraries; a
+/// 2^64 modulus is saturated to u64::MAX, which estimates as exactly 64
+/// bits. The libraries target 128 bits, i.e. NIST category 1.
+fn tfhe(name: &str, scheme: &str, problem: &str, n: usize, k: usize, log_q: u32, std_dev: f64) -> Preset {
+    let q = if log_q >= 64 { u64::MAX } else { 1 << log_q };
+    Preset {
+        name: name.to_string(),
+        scheme: scheme.to_string(),
+        group: "tfhe".to_string(),
+        problem: problem.to_string(),
+        n,
+        k,
+        q,
+        sigma: std_dev * (log_q as f64).exp2(),
+        nist_level: 1,
+        claimed_bits: 128.0,
+        ..Default::default()
+    }
+}
+
+/// TFHE library default gate-bootstrapping LWE key (n = 630, α = 2^-15)
+pub fn tfhe_lib_lwe() -> Preset {
+    tfhe("tfhe_lib_lwe", "TFHE", "LWE", 630, 1, 32, 2f64.powi(-15))
+}
+
+/// TFHE library default bootstrapping key (N = 1024, k = 1, α = 2^-25)
+pub fn tfhe_lib_glwe() -> Preset {
+    tfhe("tfhe_lib_glwe", "TFHE", "GLWE", 1024, 1, 32, 2f64.powi(-25))
+}
+
+/// TFHE-rs boolean DEFAULT_PARAMETERS, LWE key
+pub fn tfhe_rs_boolean_lwe() -> Preset {
+    tfhe("tfhe_rs_boolean_lwe", "TFHE-rs boolean", "LWE", 722, 1, 32, 0.000013071021089943935)
+}
+
+/// TFHE-rs boolean DEFAULT_PARAMETERS, GLWE key
+pub fn tfhe_rs_boolean_glwe() -> Preset {
+    tfhe("tfhe_rs_boolean_glwe", "TFHE-rs boolean", "GLWE", 512, 2, 32, 0.00000004990272175010415)
+}
+
+/// TFHE-rs shortint PARAM_MESSAGE_2_CARRY_2, LWE key
+pub fn tfhe_rs_integer_lwe() -> Preset {
+    tfhe("tfhe_rs_integer_lwe", "TFHE-rs integer", "LWE", 742, 1, 64, 0.000007069849454709433)
+}
+
+/// TFHE-rs shortint PARAM_MESSAGE_2_CARRY_2, GLWE key
+pub fn tfhe_rs_integer_glwe() -> Preset {
+    tfhe(
+        "tfhe_rs_integer_glwe",
+        "TFHE-rs integer",
+        "GLWE",
+        2048,
+        1,
+        64,
+        0.00000000000000029403601535432533,
+    )
+}
+
+/// Every built-in preset
+pub fn all() -> Vec<Preset> {
+    vec![
+        kyber512(),
+        kyber768(),
+        kyber1024(),
+        dilithium2(),
+        dilithium3(),
+        dilithium5(),
+        falcon512(),
+        falcon1024(),
+        frodo640(),
+        frodo976(),
+        frodo1344(),
+        lightsaber(),
+        saber_kem(),
+        firesaber(),
+        newhope512(),
+        newhope1024(),
+        lac128(),
+        lac192(),
+        lac256(),
+        round5_1(),
+        round5_3(),
+        round5_5(),
+        tfhe_lib_lwe(),
+        tfhe_lib_glwe(),
+        tfhe_rs_boolean_lwe(),
+        tfhe_rs_boolean_glwe(),
+        tfhe_rs_integer_lwe(),
+        tfhe_rs_integer_glwe(),
+    ]
+}
+
+/// Preset by name, ignoring case
+pub fn by_name(name: &str) -> Option<Preset> {
+    all().into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+/// Registry query; unset fields match everything, strings ignore case
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PresetFilter {
+    /// Scheme family, e.g. "ML-KEM"
+    pub scheme: Option<String>,
+    /// Collection, e.g. "legacy"
+    pub group: Option<String>,
+    /// Claimed NIST category
+    pub nist_level: Option<u8>,
+}
+
+impl PresetFilter {
+    pub fn matches(&self, p: &Preset) -> bool {
+        let eq = |want: &Option<String>, have: &str| want.as_ref().is_none_or(|w| w.eq_ignore_ascii_case(have));
+        eq(&self.scheme, &p.scheme)
+            && eq(&self.group, &p.group)
+            && self.nist_level.is_none_or(|l| l == p.nist_level)
+    }
+}
+
+/// Presets matching `filter`, in registry order
+pub fn list(filter: &PresetFilter) -> Vec<Preset> {
+    all().into_iter().filter(|p| filter.matches(p)).collect()
+}
+
+// ============================================================================
+// Nearest Preset
+// ============================================================================
+
+/// How a parameter set differs from a preset's plain-LWE view; deltas are
+/// yours minus the preset's
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PresetDiff {
+    pub preset: Preset,
+    pub delta_n: i64,
+    pub delta_log_q: f64,
+    pub delta_sigma: f64,
+    /// 0 when both sides are out of reach of the attacks (infinite bits)
+    pub delta_bits: f64,
+    /// Combined relative distance used for ranking
+    pub distance: f64,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PresetDiff {
+    fn __repr__(&self) -> String {
+        format!(
+            "PresetDiff({}: Δn={:+}, Δlog q={:+.2}, Δσ={:+.3}, Δbits={:+.1})",
+            self.preset.name, self.delta_n, self.delta_log_q, self.delta_sigma, self.delta_bits
+        )
+    }
+}
+
+/// The `count` presets closest to (n, q, σ), nearest first.
+///
+/// Distance is the Euclidean norm of ln(n / n'), the relative log q
+/// difference and ln(σ / σ'), so each parameter counts in proportion.
+pub fn nearest(n: usize, q: u64, sigma: f64, count: usize, sieving: bool) -> Vec<PresetDiff> {
+    let mine = estimate_core(n, q, sigma, sieving);
+    let log_q = (q as f64).log2();
+    let mut diffs: Vec<PresetDiff> = all()
+        .into_iter()
+        .map(|p| {
+            let theirs = p.estimate(sieving);
+            let p_log_q = (p.q as f64).log2();
+            let dn = (n as f64 / p.dimension() as f64).ln();
+            let dq = (log_q - p_log_q) / p_log_q;
+            let ds = (sigma / p.sigma).ln();
+            let delta_bits = if mine.classical_bits.is_infinite() && theirs.classical_bits.is_infinite() {
+                0.0
+            } else {
+                mine.classical_bits - theirs.classical_bits
+            };
+            PresetDiff {
+                delta_n: n as i64 - p.dimension() as i64,
+                delta_log_q: log_q - p_log_q,
+                delta_sigma: sigma - p.sigma,
+                delta_bits,
+                distance: (dn * dn + dq * dq + ds * ds).sqrt(),
+                preset: p,
+            }
+        })
+        .collect();
+    diffs.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    diffs.truncate(count);
+    diffs
+}
+
+// ============================================================================
+// Database Export
+// ============================================================================
+
+/// Output format of [`export`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("unknown export format {:?} (expected json or csv)", s)),
+        }
+    }
+}
+
+const CSV_HEADER: &str =
+    "name,scheme,group,problem,n,k,q,eta,sigma,p,samples,nist_level,claimed_bits,model,lwe_bits,lwe_beta,sis_bits,margin_bits";
+
+/// The whole catalog with estimates under one cost model.
+///
+/// `margin_bits` is the cheaper of the LWE and SIS sides minus the claim.
+/// Infinite bits are written as `null` in JSON and `inf` in CSV; absent
+/// optional fields are `null` / empty.
+pub fn export(format: ExportFormat, sieving: bool) -> String {
+    let model = if sieving { "sieving" } else { "core-svp" };
+    let rows = all().into_iter().map(|p| {
+        let lwe = p.estimate(sieving);
+        let sis = p.sis_estimate(sieving);
+        let weakest = sis.as_ref().map_or(lwe.classical_bits, |s| s.classical_bits.min(lwe.classical_bits));
+        (p, lwe, sis, weakest)
+    });
+    match format {
+        ExportFormat::Json => {
+            let records: Vec<serde_json::Value> = rows
+                .map(|(p, lwe, sis, weakest)| {
+                    serde_json::json!({
+                        "name": p.name,
+                        "scheme": p.scheme,
+                        "group": p.group,
+                        "problem": p.problem,
+                        "n": p.n,
+                        "k": p.k,
+                        "q": p.q,
+                        "eta": p.eta,
+                        "sigma": p.sigma,
+                        "p": p.p,
+                        "samples": p.samples,
+                        "nist_level": p.nist_level,
+                        "claimed_bits": p.claimed_bits,
+                        "model": model,
+                        "lwe_bits": lwe.classical_bits,
+                        "lwe_beta": lwe.beta,
+                        "sis_bits": sis.map(|s| s.classical_bits),
+                        "margin_bits": weakest - p.claimed_bits,
+                        "provenance": {
+                            "crate_version": lwe.provenance.crate_version,
+                            "cost_model": lwe.provenance.cost_model,
+                            "attacks": lwe.provenance.attacks,
+                            "timestamp": lwe.provenance.timestamp,
+                        },
+                    })
+                })
+                .collect();
+            let mut out = serde_json::to_string_pretty(&records).unwrap_or_default();
+            out.push('\n');
+            out
+        }
+        ExportFormat::Csv => {
+            let opt = |v: Option<String>| v.unwrap_or_default();
+            let mut out = format!("{}\n", CSV_HEADER);
+            for (p, lwe, sis, weakest) in rows {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{},{},{},{:.4},{},{},{},{},{},{:.1},{},{},{:.1}\n",
+                    p.name,
+                    p.scheme,
+                    p.group,
+                    p.problem,
+                    p.n,
+                    p.k,
+                    p.q,
+                    opt(p.eta.map(|e| e.to_string())),
+                    p.sigma,
+                    opt(p.p.map(|v| v.to_string())),
+                    opt(p.samples.map(|m| m.to_string())),
+                    p.nist_level,
+                    p.claimed_bits,
+                    model,
+                    lwe.classical_bits,
+                    opt(lwe.beta.map(|b| b.to_string())),
+                    opt(sis.map(|s| format!("{:.1}", s.classical_bits))),
+                    weakest - p.claimed_bits,
+                ));
+            }
+            out
+        }
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Dump the preset catalog with estimates.
+///
+/// Args:
+///     format: "json" or "csv" (default: "json")
+///     path: File to write; None returns the text (default: None)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     The exported text
+///
+/// Example:
+///     >>> from cryptoparam import presets
+///     >>> presets.export("csv", "presets.csv")
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "export", signature = (format = "json", path = None, sieving = false))]
+pub fn py_export(format: &str, path: Option<&str>, sieving: bool) -> PyResult<String> {
+    let format = ExportFormat::parse(format).map_err(crate::errors::InvalidParameters::new_err)?;
+    let text = export(format, sieving);
+    if let Some(path) = path {
+        std::fs::write(path, &text).map_err(|e| pyo3::exceptions::PyOSError::new_err(format!("{}: {}", path, e)))?;
+    }
+    Ok(text)
+}
+
+/// Find the built-in presets closest to a parameter set.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     count: Number of presets to return (default: 3)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     List of PresetDiff, nearest first
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "nearest", signature = (n, q, sigma, count = 3, sieving = false))]
+pub fn py_nearest(n: usize, q: u64, sigma: Sigma, count: usize, sieving: bool) -> PyResult<Vec<PresetDiff>> {
+    let sigma = sigma.0;
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, q >= 2 and sigma > 0"));
+    }
+    Ok(nearest(n, q, sigma, count, sieving))
+}
+
+/// List built-in presets, optionally filtered.
+///
+/// Args:
+///     scheme: Scheme family, e.g. "ML-KEM" (default: any)
+///     group: Collection, "nist", "legacy" or "tfhe" (default: any)
+///     nist_level: Claimed NIST category (default: any)
+///
+/// Returns:
+///     List of Preset in registry order
+///
+/// Example:
+///     >>> from cryptoparam import presets
+///     >>> [p.name for p in presets.list(scheme="ml-kem")]
+///     ['kyber512', 'kyber768', 'kyber1024']
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "list", signature = (scheme = None, group = None, nist_level = None))]
+pub fn py_list(scheme: Option<String>, group: Option<String>, nist_level: Option<u8>) -> Vec<Preset> {
+    list(&PresetFilter { scheme, group, nist_level })
+}
+
+/// Look up a preset by name, ignoring case.
+///
+/// Raises:
+///     KeyError: if no preset has that name
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "get")]
+pub fn py_get(name: &str) -> PyResult<Preset> {
+    by_name(name).ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(name.to_string()))
+}
+
+/// Register the `cryptoparam.presets` submodule
+#[cfg(feature = "python")]
+pub fn register(py: Python, parent: &PyModule) -> PyResult<()> {
+    let m = PyModule::new(py, "presets")?;
+    m.add("__doc__", "Named parameter sets of published schemes")?;
+    for p in all() {
+        let name = p.name.clone();
+        m.add(name.as_str(), Py::new(py, p)?)?;
+    }
+    m.add_function(wrap_pyfunction!(py_list, m)?)?;
+    m.add_function(wrap_pyfunction!(py_get, m)?)?;
+    m.add_function(wrap_pyfunction!(py_nearest, m)?)?;
+    m.add_function(wrap_pyfunction!(py_export, m)?)?;
+    m.add_class::<PresetDiff>()?;
+    parent.add_submodule(m)?;
+    // Make `import cryptoparam.presets` work, not just attribute access
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("cryptoparam.presets", m)?;
+    Ok(())
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kyber_descriptors() {
+        let p = kyber768();
+        assert_eq!((p.n, p.k, p.q, p.eta), (256, 3, 3329, Some(2)));
+        assert_eq!(p.dimension(), 768);
+        assert_eq!(kyber512().eta, Some(3));
+        assert_eq!(by_name("Kyber1024").unwrap().k, 4);
+        assert!(kyber768().sis_estimate(false).is_none());
+    }
+
+    #[test]
+    fn test_dilithium_sides() {
+        let p = dilithium2();
+        assert_eq!(p.dimension(), 1024);
+        let sis = p.sis.clone().unwrap();
+        assert_eq!((sis.n, sis.m, sis.bound), (1024, 2304, 350209.0));
+        assert!(p.sis_estimate(false).unwrap().beta.is_some());
+        assert_eq!(dilithium3().sis.unwrap().bound, 724481.0);
+    }
+
+    #[test]
+    fn test_falcon_ntru() {
+        let p = falcon512();
+        assert_eq!((p.problem.as_str(), p.n, p.q), ("NTRU", 512, 12289));
+        assert!((p.sigma - 4.05).abs() < 0.01);
+        assert_eq!(falcon1024().dimension(), 1024);
+    }
+
+    #[test]
+    fn test_frodo_tables() {
+        // Spec σ: 2.8, 2.3, 1.4
+        assert!((frodo640().sigma - 2.8).abs() < 0.02);
+        assert!((frodo976().sigma - 2.3).abs() < 0.02);
+        assert!((frodo1344().sigma - 1.4).abs() < 0.03);
+        assert_eq!(frodo640().samples, Some(648));
+        assert_eq!(frodo976().q, 1 << 16);
+    }
+
+    #[test]
+    fn test_saber_lwr_conversion() {
+        let p = saber_kem();
+        assert_eq!((p.problem.as_str(), p.k, p.q, p.p), ("MLWR", 3, 8192, Some(1024)));
+        assert!((p.sigma - (63.0f64 / 12.0).sqrt()).abs() < 1e-12);
+        assert_eq!(by_name("saber").unwrap(), p);
+        assert_eq!(firesaber().eta, Some(3));
+    }
+
+    #[test]
+    fn test_legacy_group() {
+        let legacy: Vec<Preset> = all().into_iter().filter(|p| p.group == "legacy").collect();
+        assert_eq!(legacy.len(), 8);
+        assert!(legacy.iter().any(|p| p.scheme == "NewHope"));
+        assert_eq!(newhope1024().sigma, 2.0);
+        assert_eq!(round5_3().p, Some(512));
+        assert_eq!(kyber512().group, "nist");
+    }
+
+    #[test]
+    fn test_registry_filters() {
+        let all_names: Vec<String> = all().into_iter().map(|p| p.name).collect();
+        let mut unique = all_names.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), all_names.len());
+
+        let kem = list(&PresetFilter { scheme: Some("ml-kem".into()), ..Default::default() });
+        assert_eq!(kem.len(), 3);
+        let level5 = list(&PresetFilter { nist_level: Some(5), group: Some("nist".into()), ..Default::default() });
+        assert!(level5.iter().all(|p| p.nist_level == 5 && p.group == "nist"));
+        assert!(level5.iter().any(|p| p.name == "kyber1024"));
+    }
+
+    #[test]
+    fn test_nearest_preset() {
+        let d = nearest(770, 3329, 1.0, 2, false);
+        assert_eq!(d.len(), 2);
+        assert_eq!(d[0].preset.name, "kyber768");
+        assert_eq!(d[0].delta_n, 2);
+        assert_eq!(d[0].delta_log_q, 0.0);
+        assert!(d[0].distance <= d[1].distance);
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let csv = export(ExportFormat::Csv, false);
+        assert_eq!(csv.lines().next(), Some(CSV_HEADER));
+        assert_eq!(csv.lines().count(), all().len() + 1);
+        assert!(csv.contains("\nkyber768,ML-KEM,nist,MLWE,256,3,3329,2,"));
+
+        let json: serde_json::Value = serde_json::from_str(&export(ExportFormat::Json, true)).unwrap();
+        let rows = json.as_array().unwrap();
+        assert_eq!(rows.len(), all().len());
+        assert_eq!(rows[0]["model"], "sieving");
+        assert!(rows.iter().any(|r| r["sis_bits"].is_number()));
+    }
+
+    #[test]
+    fn test_tfhe_components() {
+        let lwe = tfhe_lib_lwe();
+        assert_eq!((lwe.n, lwe.k, lwe.q, lwe.sigma), (630, 1, 1 << 32, 131072.0));
+        let glwe = tfhe_rs_boolean_glwe();
+        assert_eq!(glwe.dimension(), 1024);
+        let big = tfhe_rs_integer_glwe();
+        assert_eq!(big.estimate(false).log2_q, 64.0);
+    }
+}
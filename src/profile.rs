@@ -0,0 +1,115 @@
+//! User configuration profiles
+//!
+//! `~/.config/cryptoparam/config.toml` (under `$XDG_CONFIG_HOME` when set)
+//! holds option defaults, so a team can share settings without wrapping
+//! the binary. Top-level keys apply to every run; `--profile NAME` layers
+//! `[profile.NAME]` on top:
+//!
+//! ```toml
+//! model = "matzov22"
+//! thresholds = [128, 100]
+//!
+//! [profile.review]
+//! attack = "all"
+//! format = "markdown"
+//! ```
+//!
+//! Options given on the command line always win.
+
+use crate::error::CryptoParamError;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Option defaults of one profile
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Cost model, as for `--model`
+    pub model: Option<String>,
+    /// Attack selection, as for `--attack`
+    pub attack: Option<String>,
+    /// Colour thresholds [GOOD, WEAK] in bits
+    pub thresholds: Option<[f64; 2]>,
+    /// Output format, as for `--format`
+    pub format: Option<String>,
+    /// Named profiles; only allowed at top level
+    #[serde(default, rename = "profile")]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl Profile {
+    pub fn from_toml(s: &str) -> Result<Self, CryptoParamError> {
+        let p: Self = toml::from_str(s).map_err(|e| CryptoParamError::InvalidInput(format!("invalid profile TOML: {}", e)))?;
+        if p.profiles.values().any(|n| !n.profiles.is_empty()) {
+            return Err(CryptoParamError::InvalidInput("profiles cannot be nested".to_string()));
+        }
+        Ok(p)
+    }
+
+    /// Default location of the user config file, if a home is known
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match std::env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(base.join("cryptoparam").join("config.toml"))
+    }
+
+    /// Load `path`; a missing file is an empty profile
+    pub fn load(path: &Path) -> Result<Self, CryptoParamError> {
+        let in_file = |e: &dyn std::fmt::Display| CryptoParamError::InvalidInput(format!("{}: {}", path.display(), e));
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text).map_err(|e| in_file(&e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(in_file(&e)),
+        }
+    }
+
+    /// Defaults as (long option, value) pairs, with `[profile.name]`
+    /// overriding the top level when `name` is given
+    pub fn resolve(&self, name: Option<&str>) -> Result<Vec<(&'static str, String)>, CryptoParamError> {
+        let named = match name {
+            None => None,
+            Some(n) => Some(self.profiles.get(n).ok_or_else(|| {
+                let names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+                let available = if names.is_empty() { "none".to_string() } else { names.join(", ") };
+                CryptoParamError::InvalidOption(format!("unknown profile '{}' (available: {})", n, available))
+            })?),
+        };
+        let pick = |f: fn(&Profile) -> Option<String>| named.and_then(f).or_else(|| f(self));
+        let entries = [
+            ("--model", pick(|p| p.model.clone())),
+            ("--attack", pick(|p| p.attack.clone())),
+            ("--thresholds", pick(|p| p.thresholds.map(|[g, w]| format!("{},{}", g, w)))),
+            ("--format", pick(|p| p.format.clone())),
+        ];
+        Ok(entries.into_iter().filter_map(|(long, v)| v.map(|v| (long, v))).collect())
+    }
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_profile_overrides_top_level() {
+        let p = Profile::from_toml(
+            "model = \"matzov22\"\nformat = \"csv\"\n[profile.review]\nformat = \"markdown\"\nthresholds = [192, 128]\n",
+        )
+        .unwrap();
+        let top = p.resolve(None).unwrap();
+        assert_eq!(top, [("--model", "matzov22".to_string()), ("--format", "csv".to_string())]);
+        let review = p.resolve(Some("review")).unwrap();
+        assert!(review.contains(&("--format", "markdown".to_string())));
+        assert!(review.contains(&("--thresholds", "192,128".to_string())));
+        assert!(review.contains(&("--model", "matzov22".to_string())));
+        assert!(p.resolve(Some("ci")).unwrap_err().to_string().contains("available: review"));
+        assert!(Profile::from_toml("colour = true\n").is_err());
+        assert!(Profile::from_toml("[profile.a.profile.b]\n").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Progress reporting for long-running searches and sweeps
+
+use crate::SecurityEstimate;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Snapshot handed to progress callbacks
+#[derive(Debug, Clone)]
+pub struct Progress {
+    /// Candidates evaluated so far
+    pub evaluated: usize,
+    /// Total candidates, when known up front (sweeps); searches report `None`
+    pub total: Option<usize>,
+    /// Best candidate so far: the most secure grid point for sweeps, the
+    /// current boundary candidate for searches
+    pub best: Option<SecurityEstimate>,
+}
+
+/// Callback receiving progress snapshots
+pub type ProgressFn<'a> = &'a (dyn Fn(&Progress) + Sync);
+
+/// Callback that ignores every report
+pub fn no_progress(_: &Progress) {}
+
+/// One-line textual rendering, e.g. `[#####     ] 120/240  best ~186 bits`
+pub fn render_bar(p: &Progress, width: usize) -> String {
+    let best = match &p.best {
+        Some(b) if b.classical_bits.is_finite() => format!("  best ~{:.0} bits (n={})", b.classical_bits, b.n),
+        Some(b) => format!("  best: no attack (n={})", b.n),
+        None => String::new(),
+    };
+    match p.total {
+        Some(total) if total > 0 => {
+            let filled = (p.evaluated * width / total).min(width);
+            format!(
+                "[{}{}] {}/{}{}",
+                "#".repeat(filled),
+                " ".repeat(width - filled),
+                p.evaluated,
+                total,
+                best
+            )
+        }
+        _ => format!("{} evaluated{}", p.evaluated, best),
+    }
+}
+
+/// Least time between two redraws of a [`ProgressBar`]
+pub const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Live progress line on stderr: the [`render_bar`] text plus elapsed time
+/// and, when the total is known, rate and ETA. Redraws are throttled so
+/// callbacks from tight loops stay cheap.
+pub struct ProgressBar {
+    start: Instant,
+    width: usize,
+    /// Time of the last redraw and number of redraws so far
+    drawn: Mutex<(Option<Instant>, usize)>,
+}
+
+impl ProgressBar {
+    pub fn new(width: usize) -> Self {
+        Self { start: Instant::now(), width, drawn: Mutex::new((None, 0)) }
+    }
+
+    /// Progress line for `p` after `elapsed`; `tick` turns the spinner
+    pub fn line(&self, p: &Progress, elapsed: Duration, tick: usize) -> String {
+        let secs = elapsed.as_secs_f64();
+        let mut line = render_bar(p, self.width);
+        match p.total {
+            Some(total) if p.evaluated > 0 && secs > 0.0 => {
+                let rate = p.evaluated as f64 / secs;
+                let eta = total.saturating_sub(p.evaluated) as f64 / rate;
+                line.push_str(&format!("  {}  {:.0}/s  ETA {}", clock(secs), rate, clock(eta)));
+            }
+            Some(_) => line.push_str(&format!("  {}", clock(secs))),
+            None => line = format!("{} {}  {}", SPINNER[tick % SPINNER.len()], line, clock(secs)),
+        }
+        line
+    }
+
+    /// Redraw for `p` unless the last redraw was under [`REDRAW_INTERVAL`]
+    /// ago; the final snapshot of a known total is always drawn
+    pub fn update(&self, p: &Progress) {
+        let Ok(mut drawn) = self.drawn.lock() else {
+            return;
+        };
+        let now = Instant::now();
+        let done = p.total == Some(p.evaluated);
+        if !done && drawn.0.is_some_and(|t| now - t < REDRAW_INTERVAL) {
+            return;
+        }
+        *drawn = (Some(now), drawn.1 + 1);
+        let mut err = std::io::stderr().lock();
+        let _ = write!(err, "\r\x1b[K{}", self.line(p, now - self.start, drawn.1));
+        let _ = err.flush();
+    }
+
+    /// Clear the line so later output starts on a clean one
+    pub fn finish(&self) {
+        if self.drawn.lock().is_ok_and(|d| d.0.is_some()) {
+            eprint!("\r\x1b[K");
+        }
+    }
+}
+
+/// `m:ss`, or `h:mm:ss` from an hour on
+fn clock(secs: f64) -> String {
+    let s = if secs.is_finite() { secs.round() as u64 } else { 0 };
+    if s >= 3600 {
+        format!("{}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60)
+    } else {
+        format!("{}:{:02}", s / 60, s % 60)
+    }
+}
+
+/// Python callable receiving progress as `callback(done, total, best)`.
+///
+/// Reports may come from worker threads with the GIL released, so each
+/// call re-acquires it; the first exception the callable raises is kept
+/// and later reports are dropped, to be re-raised by [`PyProgress::finish`].
+#[cfg(feature = "python")]
+pub(crate) struct PyProgress {
+    callback: Option<PyObject>,
+    error: Mutex<Option<PyErr>>,
+}
+
+#[cfg(feature = "python")]
+impl PyProgress {
+    pub(crate) fn new(callback: Option<PyObject>) -> Self {
+        Self { callback, error: Mutex::new(None) }
+    }
+
+    pub(crate) fn is_set(&self) -> bool {
+        self.callback.is_some()
+    }
+
+    pub(crate) fn report(&self, p: &Progress) {
+        let Some(cb) = &self.callback else { return };
+        Python::with_gil(|py| {
+            let mut error = self.error.lock().unwrap();
+            if error.is_none() {
+                if let Err(e) = cb.call1(py, (p.evaluated, p.total, p.best.clone())) {
+                    *error = Some(e);
+                }
+            }
+        })
+    }
+
+    /// The callable's first exception, if it raised
+    pub(crate) fn finish(self) -> PyResult<()> {
+        match self.error.into_inner().unwrap() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bar() {
+        let p = Progress { evaluated: 5, total: Some(10), best: None };
+        assert_eq!(render_bar(&p, 10), "[#####     ] 5/10");
+        let p = Progress { evaluated: 7, total: None, best: None };
+        assert_eq!(render_bar(&p, 10), "7 evaluated");
+    }
+
+    #[test]
+    fn test_bar_line_has_eta() {
+        let bar = ProgressBar::new(10);
+        let p = Progress { evaluated: 30, total: Some(120), best: None };
+        assert_eq!(bar.line(&p, Duration::from_secs(10), 0), "[##        ] 30/120  0:10  3/s  ETA 0:30");
+        let p = Progress { evaluated: 7, total: None, best: None };
+        assert_eq!(bar.line(&p, Duration::from_secs(3725), 1), "/ 7 evaluated  1:02:05");
+    }
+}
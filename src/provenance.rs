@@ -0,0 +1,114 @@
+//! Provenance of estimates
+//!
+//! Every estimate records the crate version, the cost model and the attacks
+//! that were run, plus when it was produced, so a number quoted in a
+//! document can be traced back to the model configuration behind it.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Identifier of the core-SVP model, 0.292β
+pub const CORE_SVP_MODEL: &str = "core-svp-0.292";
+
+/// Identifier of the sieving model, 0.265β
+pub const SIEVING_MODEL: &str = "sieving-0.265";
+
+/// Identifier of the quantum core-SVP model that prices quantum bits, 0.265β
+pub const QUANTUM_MODEL: &str = "q-core-svp-0.265";
+
+/// Cost-model identifier for the `sieving` flag
+pub fn cost_model_id(sieving: bool) -> &'static str {
+    if sieving { SIEVING_MODEL } else { CORE_SVP_MODEL }
+}
+
+/// Model configuration that produced an estimate
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Provenance {
+    pub crate_version: String,
+    pub cost_model: String,
+    /// Attacks evaluated, cheapest reported
+    pub attacks: Vec<String>,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Provenance {
+    /// This build, the given cost model and attacks, stamped now
+    pub fn new(sieving: bool, attacks: &[&str]) -> Self {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            cost_model: cost_model_id(sieving).to_string(),
+            attacks: attacks.iter().map(|a| a.to_string()).collect(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+        }
+    }
+
+    /// Whether the model is the sieving one
+    pub fn sieving(&self) -> bool {
+        self.cost_model == SIEVING_MODEL
+    }
+
+    /// Inverse of the Python `to_dict`
+    #[cfg(feature = "python")]
+    pub(crate) fn from_py_dict(d: &PyDict) -> PyResult<Self> {
+        Ok(Self {
+            crate_version: crate::dict_item(d, "crate_version")?,
+            cost_model: crate::dict_item(d, "cost_model")?,
+            attacks: crate::dict_item(d, "attacks")?,
+            timestamp: crate::dict_item(d, "timestamp")?,
+        })
+    }
+
+    /// One-line summary, e.g. for report footers
+    pub fn summary(&self) -> String {
+        format!(
+            "cryptoparam {}, {}, attacks: {}, at {}",
+            self.crate_version,
+            self.cost_model,
+            self.attacks.join(", "),
+            self.timestamp
+        )
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Provenance {
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("crate_version", &self.crate_version)?;
+        d.set_item("cost_model", &self.cost_model)?;
+        d.set_item("attacks", &self.attacks)?;
+        d.set_item("timestamp", self.timestamp)?;
+        Ok(d)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Provenance({})", self.summary())
+    }
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core;
+
+    #[test]
+    fn test_estimates_carry_provenance() {
+        let p = estimate_core(256, 7681, 8.0, true).provenance;
+        assert_eq!(p.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(p.cost_model, SIEVING_MODEL);
+        assert!(p.sieving());
+        assert_eq!(p.attacks, vec!["primal_usvp".to_string()]);
+        assert!(p.timestamp > 1_600_000_000);
+    }
+}
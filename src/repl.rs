@@ -0,0 +1,165 @@
+//! Interactive prompt of `cryptoparam repl`
+//!
+//! Keeps n, q, σ and the cost model between lines, so one parameter can be
+//! changed at a time with the estimate reprinted after every change.
+
+use crate::cli::{parse_number, parse_real};
+use cryptoparam::attacks::{attack_table, lwe_attacks};
+use cryptoparam::cost_model::{self, CostModel};
+use cryptoparam::{estimate_core, SecurityEstimate};
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+Set parameters with `name value` or `name=value`, several per line:
+  n 512        q 2**14        sigma 3.19        model matzov22
+Commands:
+  show         current parameters
+  verbose      detailed breakdown of the current estimate
+  attacks      cost of every implemented attack
+  help         this text
+  quit         leave (also Ctrl-D)
+";
+
+/// Parameters being explored
+pub struct Session {
+    n: Option<usize>,
+    q: Option<u64>,
+    sigma: Option<f64>,
+    model: &'static CostModel,
+}
+
+impl Session {
+    pub fn new(n: Option<usize>, q: Option<u64>, sigma: Option<f64>, model: &'static CostModel) -> Self {
+        Self { n, q, sigma, model }
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "n" => match parse_number(value)? {
+                0 => return Err("n must be positive".to_string()),
+                n => self.n = Some(n as usize),
+            },
+            "q" => match parse_number(value)? {
+                q if q < 2 => return Err("q must be at least 2".to_string()),
+                q => self.q = Some(q),
+            },
+            "sigma" | "σ" => match parse_real(value)? {
+                s if s > 0.0 => self.sigma = Some(s),
+                _ => return Err(format!("invalid sigma '{}'", value)),
+            },
+            "model" => self.model = cost_model::by_name(value).map_err(|e| e.to_string())?,
+            _ => return Err(format!("unknown parameter '{}' (n, q, sigma or model)", name)),
+        }
+        Ok(())
+    }
+
+    /// Estimate under the current model, once n, q and σ are all set
+    fn estimate(&self) -> Result<SecurityEstimate, String> {
+        match (self.n, self.q, self.sigma) {
+            (Some(n), Some(q), Some(sigma)) => {
+                let mut r = estimate_core(n, q, sigma, false);
+                self.model.apply(&mut r);
+                Ok(r)
+            }
+            _ => Err("set n, q and sigma to estimate".to_string()),
+        }
+    }
+
+    fn show(&self) -> String {
+        let value = |v: Option<String>| v.unwrap_or_else(|| "unset".to_string());
+        format!(
+            "n = {}, q = {}, sigma = {}, model = {}\n",
+            value(self.n.map(|n| n.to_string())),
+            value(self.q.map(|q| q.to_string())),
+            value(self.sigma.map(|s| s.to_string())),
+            self.model.name
+        )
+    }
+
+    /// Output for one input line; None to quit
+    pub fn handle(&mut self, line: &str) -> Option<String> {
+        let line = line.trim();
+        let result = match line {
+            "" => return Some(String::new()),
+            "quit" | "exit" => return None,
+            "help" | "?" => Ok(HELP.to_string()),
+            "show" => Ok(self.show()),
+            "verbose" => self.estimate().map(|r| format!("{:#}\n", r)),
+            "attacks" => self.estimate().map(|r| {
+                let mut costs = lwe_attacks(r.n, r.log2_q, r.sigma, false);
+                costs.iter_mut().for_each(|c| c.reprice(self.model));
+                attack_table(&costs)
+            }),
+            // Until all three are set, echo what is
+            _ => self.assign(line).map(|_| match self.estimate() {
+                Ok(r) => {
+                    let mut out = format!("{}\n", r);
+                    for w in &r.warnings {
+                        out.push_str(&format!("warning: {}\n", w.message));
+                    }
+                    out
+                }
+                Err(_) => self.show(),
+            }),
+        };
+        Some(result.unwrap_or_else(|e| format!("error: {}\n", e)))
+    }
+
+    /// Apply `name value` / `name=value` pairs
+    fn assign(&mut self, line: &str) -> Result<(), String> {
+        let mut tokens = line.split_whitespace();
+        while let Some(token) = tokens.next() {
+            let (name, value) = match token.split_once('=') {
+                Some((name, value)) => (name, value),
+                None => (token, tokens.next().ok_or_else(|| format!("'{}' needs a value (see help)", token))?),
+            };
+            self.set(name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read lines from `input` until `quit` or end of input, writing results to
+/// `out`; `prompt` shows a `> ` prompt before each line
+pub fn run(session: &mut Session, input: impl BufRead, mut out: impl Write, prompt: bool) -> io::Result<()> {
+    if prompt {
+        write!(out, "Type 'help' for commands.\n> ")?;
+        out.flush()?;
+    }
+    for line in input.lines() {
+        let Some(text) = session.handle(&line?) else {
+            return Ok(());
+        };
+        out.write_all(text.as_bytes())?;
+        if prompt {
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+    }
+    if prompt {
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_updates_incrementally() {
+        let mut s = Session::new(None, None, None, cost_model::for_sieving(false));
+        assert_eq!(s.handle("n 512").unwrap(), "n = 512, q = unset, sigma = unset, model = core-svp\n");
+        assert!(s.handle("verbose").unwrap().contains("set n, q and sigma"));
+        let out = s.handle("q=2**14 sigma 3.2").unwrap();
+        assert_eq!(out, format!("{}\n", estimate_core(512, 1 << 14, 3.2, false)));
+        assert!(s.handle("model sieving").unwrap().contains("bits"));
+        assert!(s.handle("show").unwrap().contains("model = sieving"));
+        assert!(s.handle("sigma -1").unwrap().starts_with("error: invalid sigma"));
+        assert!(s.handle("quit").is_none());
+
+        let mut out = Vec::new();
+        run(&mut s, "n 256\nexit\nn 1\n".as_bytes(), &mut out, false).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 1);
+    }
+}
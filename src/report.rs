@@ -0,0 +1,414 @@
+//! Report rendering for estimate tables
+//!
+//! Formats one or more estimates for pasting into review documents.
+
+use crate::SecurityEstimate;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Output format of table-producing CLI commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// One human-readable line per estimate
+    #[default]
+    Text,
+    Csv,
+    Json,
+    Markdown,
+    Latex,
+}
+
+impl OutputFormat {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "latex" | "tex" => Ok(Self::Latex),
+            _ => Err(format!("unknown format {:?} (expected text, csv, json, markdown or latex)", s)),
+        }
+    }
+}
+
+fn bits_cell(bits: f64) -> String {
+    if bits.is_finite() { format!("{:.1}", bits) } else { "∞".to_string() }
+}
+
+/// β of an attack, "—" when it is infeasible
+pub fn beta_cell(beta: Option<usize>) -> String {
+    beta.map_or("—".to_string(), |b| b.to_string())
+}
+
+/// GitHub-flavored markdown table, one row per estimate.
+///
+/// With `labels`, a leading "name" column holds `labels[i]` for row i.
+/// A trailing "quantum" column appears when any row carries quantum bits.
+pub fn markdown_table(rows: &[SecurityEstimate], labels: Option<&[String]>) -> String {
+    let quantum = rows.iter().any(|r| r.quantum_bits.is_some());
+    let mut out = String::new();
+    if labels.is_some() {
+        out.push_str("| name ");
+    }
+    out.push_str(if quantum { "| n | log2 q | σ | attack | β | bits | quantum |\n" } else { "| n | log2 q | σ | attack | β | bits |\n" });
+    if labels.is_some() {
+        out.push_str("|:---");
+    }
+    out.push_str(if quantum { "|---:|---:|---:|:---|---:|---:|---:|\n" } else { "|---:|---:|---:|:---|---:|---:|\n" });
+    for (i, r) in rows.iter().enumerate() {
+        if let Some(l) = labels {
+            out.push_str(&format!("| {} ", l.get(i).map_or("", String::as_str)));
+        }
+        out.push_str(&format!(
+            "| {} | {:.1} | {} | {} | {} | {} |",
+            r.n,
+            r.log2_q,
+            r.sigma,
+            r.attack,
+            beta_cell(r.beta),
+            bits_cell(r.classical_bits)
+        ));
+        if quantum {
+            out.push_str(&format!(" {} |", r.quantum_bits.map_or(String::new(), bits_cell)));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Selectable column of [`latex_table`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    N,
+    LogQ,
+    Sigma,
+    Attack,
+    Beta,
+    D,
+    M,
+    Bits,
+    Quantum,
+}
+
+impl Column {
+    /// Columns used when none are requested
+    pub const DEFAULT: [Column; 5] = [Column::N, Column::LogQ, Column::Sigma, Column::Beta, Column::Bits];
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "n" => Ok(Self::N),
+            "logq" | "log_q" | "log2_q" => Ok(Self::LogQ),
+            "sigma" => Ok(Self::Sigma),
+            "attack" => Ok(Self::Attack),
+            "beta" => Ok(Self::Beta),
+            "d" => Ok(Self::D),
+            "m" => Ok(Self::M),
+            "bits" => Ok(Self::Bits),
+            "quantum" | "qbits" => Ok(Self::Quantum),
+            _ => Err(format!("unknown column {:?}", s)),
+        }
+    }
+
+    /// Comma-separated list, e.g. "n,logq,bits"
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, String> {
+        s.split(',').filter(|c| !c.trim().is_empty()).map(Self::parse).collect()
+    }
+
+    fn latex_header(&self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::N => "$n$",
+            Self::LogQ => "$\\log_2 q$",
+            Self::Sigma => "$\\sigma$",
+            Self::Attack => "Attack",
+            Self::Beta => "$\\beta$",
+            Self::D => "$d$",
+            Self::M => "$m$",
+            Self::Bits => "Bits",
+            Self::Quantum => "Quantum",
+        }
+    }
+
+    fn latex_cell(&self, r: &SecurityEstimate, label: &str) -> String {
+        match self {
+            Self::Name => latex_escape(label),
+            Self::N => r.n.to_string(),
+            Self::LogQ => format!("{:.1}", r.log2_q),
+            Self::Sigma => r.sigma.to_string(),
+            Self::Attack => latex_escape(&r.attack),
+            Self::Beta => r.beta.map_or("---".to_string(), |b| b.to_string()),
+            Self::D => r.d.to_string(),
+            Self::M => r.m.to_string(),
+            Self::Bits if r.classical_bits.is_finite() => format!("{:.1}", r.classical_bits),
+            Self::Bits => "$\\infty$".to_string(),
+            Self::Quantum => match r.quantum_bits {
+                Some(bits) if bits.is_finite() => format!("{:.1}", bits),
+                Some(_) => "$\\infty$".to_string(),
+                None => "--".to_string(),
+            },
+        }
+    }
+}
+
+fn latex_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '_' | '&' | '%' | '$' | '#' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// booktabs `tabular` with the given columns, one row per estimate.
+///
+/// Text columns are left-aligned, numbers right-aligned. `labels[i]`
+/// fills the [`Column::Name`] cell of row i.
+pub fn latex_table(rows: &[SecurityEstimate], labels: Option<&[String]>, columns: &[Column]) -> String {
+    let align: String = columns
+        .iter()
+        .map(|c| if matches!(c, Column::Name | Column::Attack) { 'l' } else { 'r' })
+        .collect();
+    let header: Vec<&str> = columns.iter().map(Column::latex_header).collect();
+    let mut out = format!("\\begin{{tabular}}{{{}}}\n\\toprule\n{} \\\\\n\\midrule\n", align, header.join(" & "));
+    for (i, r) in rows.iter().enumerate() {
+        let label = labels.and_then(|l| l.get(i)).map_or("", String::as_str);
+        let cells: Vec<String> = columns.iter().map(|c| c.latex_cell(r, label)).collect();
+        out.push_str(&format!("{} \\\\\n", cells.join(" & ")));
+    }
+    out.push_str("\\bottomrule\n\\end{tabular}\n");
+    out
+}
+
+fn round3(x: f64) -> f64 {
+    (x * 1000.0).round() / 1000.0
+}
+
+/// Side-by-side text table of two estimates with B − A deltas.
+///
+/// One row per parameter, then the attack's β and cost, and its quantum
+/// cost when both estimates carry one.
+pub fn compare_table(a: &SecurityEstimate, b: &SecurityEstimate, labels: [&str; 2]) -> String {
+    let delta = |x: f64, y: f64| if x.is_finite() && y.is_finite() { format!("{:+.1}", y - x) } else { "—".to_string() };
+    let bits = |r: &SecurityEstimate| if r.classical_bits.is_finite() { format!("{:.1}", r.classical_bits) } else { "∞".to_string() };
+    let mut rows: Vec<[String; 4]> = vec![
+        ["".to_string(), labels[0].to_string(), labels[1].to_string(), "Δ".to_string()],
+        ["n".to_string(), a.n.to_string(), b.n.to_string(), format!("{:+}", b.n as i64 - a.n as i64)],
+        ["log2 q".to_string(), format!("{:.1}", a.log2_q), format!("{:.1}", b.log2_q), delta(a.log2_q, b.log2_q)],
+        ["σ".to_string(), round3(a.sigma).to_string(), round3(b.sigma).to_string(), format!("{:+.3}", b.sigma - a.sigma)],
+    ];
+    let beta_delta = match (a.beta, b.beta) {
+        (Some(x), Some(y)) => format!("{:+}", y as i64 - x as i64),
+        _ => "—".to_string(),
+    };
+    rows.push([format!("{} β", a.attack), beta_cell(a.beta), beta_cell(b.beta), beta_delta]);
+    rows.push([format!("{} bits", a.attack), bits(a), bits(b), delta(a.classical_bits, b.classical_bits)]);
+    if let (Some(qa), Some(qb)) = (a.quantum_bits, b.quantum_bits) {
+        rows.push([format!("{} quantum", a.attack), bits_cell(qa), bits_cell(qb), delta(qa, qb)]);
+    }
+    let widths: Vec<usize> = (0..4).map(|c| rows.iter().map(|r| r[c].chars().count()).max().unwrap_or(0)).collect();
+    let mut out = String::new();
+    for r in &rows {
+        let cells: Vec<String> = r
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(c, (cell, &w))| {
+                let pad = w - cell.chars().count();
+                if c == 0 { format!("{}{}", cell, " ".repeat(pad)) } else { format!("{}{}", " ".repeat(pad), cell) }
+            })
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Keys of [`porcelain`], in order; stable across releases, new keys are
+/// only ever appended
+pub const PORCELAIN_KEYS: [&str; 9] = ["bits", "attack", "beta", "n", "log2_q", "sigma", "m", "d", "model"];
+
+/// One `key=value` line per estimate under [`PORCELAIN_KEYS`], separated by
+/// single spaces; infinite bits are `inf` and the β of an infeasible
+/// attack is `none`. Unlike the text output this
+/// line does not change between releases. Quantum bits, when present,
+/// follow as `quantum=`.
+pub fn porcelain(r: &SecurityEstimate) -> String {
+    let values = [
+        bits3(r.classical_bits),
+        r.attack.clone(),
+        r.beta.map_or("none".to_string(), |b| b.to_string()),
+        r.n.to_string(),
+        format!("{:.4}", r.log2_q),
+        r.sigma.to_string(),
+        r.m.to_string(),
+        r.d.to_string(),
+        r.provenance.cost_model.clone(),
+    ];
+    let mut line = PORCELAIN_KEYS.iter().zip(values).map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(" ");
+    if let Some(bits) = r.quantum_bits {
+        line.push_str(&format!(" quantum={}", bits3(bits)));
+    }
+    line
+}
+
+fn bits3(bits: f64) -> String {
+    if bits.is_finite() { format!("{:.3}", bits) } else { "inf".to_string() }
+}
+
+/// Security in bits alone, three decimals, or `inf`; followed by the
+/// quantum bits when present
+pub fn bits_only(r: &SecurityEstimate) -> String {
+    match r.quantum_bits {
+        Some(q) => format!("{} {}", bits3(r.classical_bits), bits3(q)),
+        None => bits3(r.classical_bits),
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Render estimates as a GitHub-flavored markdown table.
+///
+/// Args:
+///     estimates: List of SecurityEstimate
+///     names: Optional row labels, one per estimate (default: None)
+///
+/// Returns:
+///     Markdown text
+///
+/// Example:
+///     >>> print(to_markdown([estimate_lwe(512, 12289, 3.19)]))
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "to_markdown", signature = (estimates, names = None))]
+pub fn py_to_markdown(estimates: Vec<SecurityEstimate>, names: Option<Vec<String>>) -> PyResult<String> {
+    if names.as_ref().is_some_and(|n| n.len() != estimates.len()) {
+        return Err(crate::errors::InvalidParameters::new_err("need one name per estimate"));
+    }
+    Ok(markdown_table(&estimates, names.as_deref()))
+}
+
+/// Render estimates as a booktabs LaTeX table.
+///
+/// Args:
+///     estimates: List of SecurityEstimate
+///     names: Optional row labels, one per estimate (default: None)
+///     columns: Column names from name, n, logq, sigma, attack, beta, d, m,
+///         bits, quantum (default: n, logq, sigma, beta, bits, led by name
+///         when names are given)
+///
+/// Returns:
+///     LaTeX source; needs \usepackage{booktabs}
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "to_latex", signature = (estimates, names = None, columns = None))]
+pub fn py_to_latex(
+    estimates: Vec<SecurityEstimate>,
+    names: Option<Vec<String>>,
+    columns: Option<Vec<String>>,
+) -> PyResult<String> {
+    if names.as_ref().is_some_and(|n| n.len() != estimates.len()) {
+        return Err(crate::errors::InvalidParameters::new_err("need one name per estimate"));
+    }
+    let columns = match columns {
+        Some(c) => c
+            .iter()
+            .map(|c| Column::parse(c))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(crate::errors::InvalidParameters::new_err)?,
+        None => default_columns(names.is_some()),
+    };
+    Ok(latex_table(&estimates, names.as_deref(), &columns))
+}
+
+/// [`Column::DEFAULT`], led by the name column when rows are labelled
+pub fn default_columns(labelled: bool) -> Vec<Column> {
+    let mut columns = Vec::new();
+    if labelled {
+        columns.push(Column::Name);
+    }
+    columns.extend(Column::DEFAULT);
+    columns
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core;
+
+    #[test]
+    fn test_markdown_table() {
+        let rows = [estimate_core(256, 7681, 8.0, false), estimate_core(64, 1 << 40, 1.0, false)];
+        let md = markdown_table(&rows, None);
+        let lines: Vec<&str> = md.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "| n | log2 q | σ | attack | β | bits |");
+        assert!(lines[2].starts_with("| 256 | 12.9 | 8 | primal_usvp | 250 | 73.0 |"));
+
+        let named = markdown_table(&rows, Some(&["a".to_string(), "b".to_string()]));
+        assert!(named.lines().nth(3).unwrap().starts_with("| b | 64 |"));
+        assert_eq!(OutputFormat::parse("md"), Ok(OutputFormat::Markdown));
+    }
+
+    #[test]
+    fn test_latex_table() {
+        let rows = [estimate_core(256, 7681, 8.0, false)];
+        let tex = latex_table(&rows, Some(&["my_set".to_string()]), &Column::parse_list("name,n,attack,bits").unwrap());
+        let lines: Vec<&str> = tex.lines().collect();
+        assert_eq!(lines[0], "\\begin{tabular}{lrlr}");
+        assert_eq!(lines[2], "Name & $n$ & Attack & Bits \\\\");
+        assert_eq!(lines[4], "my\\_set & 256 & primal\\_usvp & 73.0 \\\\");
+        assert_eq!(lines.last(), Some(&"\\end{tabular}"));
+        assert!(Column::parse_list("n,foo").is_err());
+    }
+
+    #[test]
+    fn test_compare_table() {
+        let a = estimate_core(256, 7681, 8.0, false);
+        let b = estimate_core(512, 7681, 8.0, false);
+        let t = compare_table(&a, &b, ["A", "B"]);
+        let lines: Vec<&str> = t.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].starts_with("n ") && lines[1].ends_with("+256"));
+        assert!(lines[5].starts_with("primal_usvp bits") && lines[5].ends_with("+88.5"));
+    }
+
+    #[test]
+    fn test_porcelain() {
+        let r = estimate_core(256, 7681, 8.0, false);
+        assert_eq!(
+            porcelain(&r),
+            "bits=73.000 attack=primal_usvp beta=250 n=256 log2_q=12.9071 sigma=8 m=128 d=384 model=core-svp-0.292"
+        );
+        assert_eq!(bits_only(&estimate_core(64, 1 << 40, 3.2, false)), "inf");
+    }
+
+    #[test]
+    fn test_quantum_columns() {
+        let mut r = estimate_core(256, 7681, 8.0, false);
+        r.add_quantum();
+        let q = r.quantum_bits.unwrap();
+        assert!(q < r.classical_bits);
+        assert!(porcelain(&r).ends_with(&format!("model=core-svp-0.292 quantum={:.3}", q)));
+        assert_eq!(bits_only(&r), format!("73.000 {:.3}", q));
+        let md = markdown_table(std::slice::from_ref(&r), None);
+        assert!(md.starts_with("| n | log2 q | σ | attack | β | bits | quantum |"));
+        assert!(md.lines().nth(2).unwrap().ends_with(&format!("| 73.0 | {:.1} |", q)));
+        assert_eq!(Column::parse("qbits"), Ok(Column::Quantum));
+        let csv = crate::sweep::to_csv(std::slice::from_ref(&r));
+        assert!(csv.starts_with("n,q,log2_q,sigma,attack,beta,m,d,classical_bits,quantum_bits\n"));
+        assert!(r.to_string().contains(&format!("~73 bits classical, ~{:.0} quantum", q)));
+    }
+}
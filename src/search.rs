@@ -0,0 +1,1293 @@
+//! Inverse parameter search
+//!
+//! Finds parameters that reach a target security level.
+
+use crate::budget::ByteBudget;
+#[cfg(feature = "python")]
+use crate::budget::SizeTemplate;
+use crate::checkpoint::EstimateCache;
+use crate::failure::failure_log2;
+use crate::error::CryptoParamError;
+use crate::progress::{no_progress, Progress, ProgressFn};
+use crate::secret::{estimate_secret, SecretDist};
+#[cfg(feature = "python")]
+use crate::progress::PyProgress;
+use crate::{estimate_core, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::{LweParams, Sigma};
+#[cfg(feature = "python")]
+use crate::parallel::with_threads;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use rayon::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Smallest dimension the searches will consider (below this the
+/// primal model degenerates to "no attack found")
+pub const MIN_SEARCH_N: usize = 8;
+
+/// Largest dimension the searches will consider
+pub const MAX_SEARCH_N: usize = 1 << 15;
+
+// ============================================================================
+// Security Margin
+// ============================================================================
+
+/// Safety margin added on top of a target security level
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SecurityMargin {
+    None,
+    /// Absolute margin in bits (e.g. +16 bits)
+    Bits(f64),
+    /// Relative margin in percent of the target (e.g. +15%)
+    Percent(f64),
+}
+
+impl SecurityMargin {
+    /// Parse "16", "+16", "16bits" or "15%"
+    pub fn parse(s: &str) -> Result<Self, CryptoParamError> {
+        let t = s.trim().strip_prefix('+').unwrap_or(s.trim());
+        let (v, percent) = match t.strip_suffix('%') {
+            Some(p) => (p, true),
+            None => (t.strip_suffix("bits").or_else(|| t.strip_suffix("bit")).unwrap_or(t), false),
+        };
+        let invalid = || CryptoParamError::InvalidOption(format!("Invalid margin: {}", s));
+        let v: f64 = v.trim().parse().map_err(|_| invalid())?;
+        if !v.is_finite() {
+            return Err(invalid());
+        }
+        if v < 0.0 {
+            return Err(CryptoParamError::InvalidOption(format!("Margin must be non-negative: {}", s)));
+        }
+        Ok(if percent { SecurityMargin::Percent(v) } else { SecurityMargin::Bits(v) })
+    }
+
+    /// Target after the margin is applied
+    pub fn apply(&self, target_bits: f64) -> f64 {
+        match *self {
+            SecurityMargin::None => target_bits,
+            SecurityMargin::Bits(b) => target_bits + b,
+            SecurityMargin::Percent(p) => target_bits * (1.0 + p / 100.0),
+        }
+    }
+
+    /// Human-readable form recorded in search output
+    pub fn describe(&self) -> String {
+        match *self {
+            SecurityMargin::None => "none".to_string(),
+            SecurityMargin::Bits(b) => format!("+{} bits", b),
+            SecurityMargin::Percent(p) => format!("+{}%", p),
+        }
+    }
+}
+
+// ============================================================================
+// Search Result
+// ============================================================================
+
+/// Outcome of a parameter search
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchResult {
+    pub n: usize,
+    pub q: u64,
+    pub sigma: f64,
+    /// Target requested by the user
+    pub target_bits: f64,
+    /// Target actually searched for, margin included
+    pub effective_target_bits: f64,
+    /// Margin that was applied, e.g. "+16 bits"
+    pub margin: String,
+    pub estimate: SecurityEstimate,
+    /// Hamming weight of the sparse secret, when h was searched for
+    pub h: Option<usize>,
+    /// log2 decryption failure probability, when a failure bound was imposed
+    pub failure_log2: Option<f64>,
+    /// Every candidate examined, in evaluation order, when auditing was asked for
+    pub trace: Option<Vec<TraceEntry>>,
+}
+
+/// One candidate examined by a search
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceEntry {
+    pub n: usize,
+    pub q: u64,
+    pub sigma: f64,
+    pub bits: f64,
+    /// None when no attack is feasible
+    pub beta: Option<usize>,
+    /// Whether the candidate met the (margin-adjusted) target
+    pub accepted: bool,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl TraceEntry {
+    fn __repr__(&self) -> String {
+        format!(
+            "TraceEntry(n={}, q={}, sigma={}, bits={:.1}, beta={}, {})",
+            self.n,
+            self.q,
+            self.sigma,
+            self.bits,
+            self.beta.map_or("None".to_string(), |b| b.to_string()),
+            if self.accepted { "accepted" } else { "rejected" }
+        )
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SearchResult {
+    fn __repr__(&self) -> String {
+        let failure = match self.failure_log2 {
+            Some(f) => format!(", failure=2^{:.1}", f),
+            None => String::new(),
+        };
+        let h = self.h.map_or(String::new(), |h| format!(", h={}", h));
+        format!(
+            "SearchResult(n={}, q={}, sigma={}{}, bits={:.1}, target={} (margin {}, effective {:.1}){})",
+            self.n,
+            self.q,
+            self.sigma,
+            h,
+            self.estimate.classical_bits,
+            self.target_bits,
+            self.margin,
+            self.effective_target_bits,
+            failure
+        )
+    }
+
+    /// One-line summary of the audit trail, e.g. for review notes
+    fn trace_summary(&self) -> Option<String> {
+        self.trace.as_ref().map(|t| summarize_trace(t))
+    }
+}
+
+/// "14 candidates (6 accepted), n 8..=512, bits 0.6..=79.3"
+pub fn summarize_trace(trace: &[TraceEntry]) -> String {
+    let accepted = trace.iter().filter(|t| t.accepted).count();
+    let range = |f: &dyn Fn(&TraceEntry) -> f64| {
+        let lo = trace.iter().map(f).fold(f64::INFINITY, f64::min);
+        let hi = trace.iter().map(f).fold(f64::NEG_INFINITY, f64::max);
+        (lo, hi)
+    };
+    let (n_lo, n_hi) = range(&|t| t.n as f64);
+    let (q_lo, q_hi) = range(&|t| t.q as f64);
+    let (s_lo, s_hi) = range(&|t| t.sigma);
+    let (b_lo, b_hi) = range(&|t| t.bits);
+    let mut parts = vec![format!("{} candidates ({} accepted)", trace.len(), accepted)];
+    if trace.is_empty() {
+        return parts.remove(0);
+    }
+    if n_lo != n_hi {
+        parts.push(format!("n {}..={}", n_lo, n_hi));
+    }
+    if q_lo != q_hi {
+        parts.push(format!("log2 q {:.2}..={:.2}", q_lo.log2(), q_hi.log2()));
+    }
+    if s_lo != s_hi {
+        parts.push(format!("sigma {:.4}..={:.4}", s_lo, s_hi));
+    }
+    parts.push(format!("bits {:.1}..={:.1}", b_lo, b_hi));
+    parts.join(", ")
+}
+
+// ============================================================================
+// Searches
+// ============================================================================
+
+fn meets(r: &SecurityEstimate, target_bits: f64) -> bool {
+    r.classical_bits >= target_bits
+}
+
+/// Like [`meets`], but "no attack found" does not count. Used when varying n:
+/// below some dimension the embedding is too small for the primal model to
+/// apply at all, which says nothing about security.
+fn meets_with_attack(r: &SecurityEstimate, target_bits: f64) -> bool {
+    r.beta.is_some() && r.classical_bits >= target_bits
+}
+
+/// Parameter varied by a monotonic search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SearchParam {
+    N,
+    LogQ,
+    Sigma,
+    /// Hamming weight of a sparse ternary secret, with n, q and σ fixed
+    H,
+}
+
+impl SearchParam {
+    pub fn parse(s: &str) -> Result<Self, CryptoParamError> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "n" => Ok(SearchParam::N),
+            "q" | "logq" | "log_q" | "log2q" => Ok(SearchParam::LogQ),
+            "sigma" | "σ" => Ok(SearchParam::Sigma),
+            "h" | "hamming" => Ok(SearchParam::H),
+            _ => Err(CryptoParamError::InvalidOption(format!("Unknown search parameter: {}", s))),
+        }
+    }
+
+    /// Python-side name: "n", "q", "sigma" or "h"
+    pub fn name(self) -> &'static str {
+        match self {
+            SearchParam::N => "n",
+            SearchParam::LogQ => "q",
+            SearchParam::Sigma => "sigma",
+            SearchParam::H => "h",
+        }
+    }
+}
+
+/// Smallest x in [lo, hi] with `pred(x)` true, for `pred` monotone false → true.
+///
+/// Returns `None` if `pred(hi)` is false. Uses O(log(hi - lo)) evaluations.
+pub fn bisect<F: FnMut(u64) -> bool>(mut lo: u64, hi: u64, mut pred: F) -> Option<u64> {
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+    let mut hi = hi;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Some(hi)
+}
+
+/// Smallest x in [lo, hi] (to relative tolerance `rel_tol`) with `pred(x)` true,
+/// for `pred` monotone false → true. The returned x always satisfies `pred`.
+pub fn bisect_f64<F: FnMut(f64) -> bool>(
+    lo: f64,
+    hi: f64,
+    rel_tol: f64,
+    mut pred: F,
+) -> Option<f64> {
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    while hi - lo > rel_tol * hi.abs().max(lo.abs()) {
+        let mid = 0.5 * (lo + hi);
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(hi)
+}
+
+/// Boundary of a monotone predicate over [lo, hi], whichever way it runs.
+///
+/// If `pred` is false → true returns the smallest true x, if true → false the
+/// largest true x; if it holds everywhere, `lo`.
+pub fn boundary<F: FnMut(u64) -> bool>(lo: u64, hi: u64, mut pred: F) -> Option<u64> {
+    match (pred(lo), pred(hi)) {
+        (true, _) if lo == hi => Some(lo),
+        (false, true) => bisect(lo, hi, pred),
+        (true, false) => bisect(lo, hi, |y| pred(lo + (hi - y))).map(|y| lo + (hi - y)),
+        (true, true) => Some(lo),
+        (false, false) => None,
+    }
+}
+
+/// Floating-point counterpart of [`boundary`]
+pub fn boundary_f64<F: FnMut(f64) -> bool>(
+    lo: f64,
+    hi: f64,
+    rel_tol: f64,
+    mut pred: F,
+) -> Option<f64> {
+    match (pred(lo), pred(hi)) {
+        (false, true) => bisect_f64(lo, hi, rel_tol, pred),
+        (true, false) => bisect_f64(-hi, -lo, rel_tol, |y| pred(-y)).map(|y| -y),
+        (true, true) => Some(lo),
+        (false, false) => None,
+    }
+}
+
+/// Solve for one parameter, holding the other two fixed, so that security
+/// just reaches `target_bits` plus `margin`. The varied field of
+/// `(n, q, sigma)` is ignored; a search over h holds all three fixed.
+///
+/// The direction in which security moves is read off the model, so this
+/// returns the smallest sufficient n or h and the boundary q or σ on the
+/// secure side.
+pub fn solve(
+    param: SearchParam,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+) -> Result<SearchResult, CryptoParamError> {
+    solve_with_progress(param, n, q, sigma, target_bits, margin, sieving, false, &no_progress)
+        .ok_or_else(|| search_failed(param, margin.apply(target_bits)))
+}
+
+/// SearchFailed for a [`solve`] on `param` that found nothing reaching
+/// `effective_bits`
+pub fn search_failed(param: SearchParam, effective_bits: f64) -> CryptoParamError {
+    CryptoParamError::SearchFailed(match param {
+        SearchParam::N => format!("no n <= {} reaches {:.1} bits", MAX_SEARCH_N, effective_bits),
+        SearchParam::H => format!("no h <= n reaches {:.1} bits", effective_bits),
+        _ => format!("no {} reaches {:.1} bits", param.name(), effective_bits),
+    })
+}
+
+/// [`solve`] reporting after every candidate evaluated; the reported best is
+/// the latest candidate that met the target. With `audit` set the result
+/// carries the full trace of candidates examined.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_progress(
+    param: SearchParam,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+    audit: bool,
+    progress: ProgressFn,
+) -> Option<SearchResult> {
+    solve_cached(param, n, q, sigma, target_bits, margin, sieving, audit, progress, None)
+}
+
+/// [`solve_with_progress`] recording every candidate in a checkpoint file.
+///
+/// With `resume` set and `path` present, candidates already in the file
+/// are reused, so an interrupted search resumes where it stopped; the
+/// answer is identical to an uninterrupted one.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_checkpointed(
+    param: SearchParam,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+    audit: bool,
+    path: &Path,
+    resume: bool,
+    progress: ProgressFn,
+) -> io::Result<Option<SearchResult>> {
+    let cache = EstimateCache::open(path, sieving, resume)?;
+    let found = solve_cached(param, n, q, sigma, target_bits, margin, sieving, audit, progress, Some(&cache));
+    cache.finish()?;
+    Ok(found)
+}
+
+/// Candidates are estimated through `cache` when there is one; the cache
+/// is keyed on (n, q, σ), so an h search bypasses it
+#[allow(clippy::too_many_arguments)]
+fn solve_cached(
+    param: SearchParam,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+    audit: bool,
+    progress: ProgressFn,
+    cache: Option<&EstimateCache>,
+) -> Option<SearchResult> {
+    let estimate = |n: usize, q: u64, sigma: f64| match cache {
+        Some(c) => c.estimate(n, q, sigma),
+        None => estimate_core(n, q, sigma, sieving),
+    };
+    let effective = margin.apply(target_bits);
+    let evaluated = Cell::new(0usize);
+    let best: RefCell<Option<SecurityEstimate>> = RefCell::new(None);
+    let trace: RefCell<Vec<TraceEntry>> = RefCell::new(Vec::new());
+    let sparse = |h: usize| estimate_secret(n, (q as f64).log2(), sigma, SecretDist::Sparse(h), sieving);
+    let record = |n: usize, q: u64, sigma: f64, r: SecurityEstimate, ok: fn(&SecurityEstimate, f64) -> bool| {
+        let pass = ok(&r, effective);
+        if audit {
+            trace.borrow_mut().push(TraceEntry {
+                n,
+                q,
+                sigma,
+                bits: r.classical_bits,
+                beta: r.beta,
+                accepted: pass,
+            });
+        }
+        if pass {
+            *best.borrow_mut() = Some(r);
+        }
+        evaluated.set(evaluated.get() + 1);
+        progress(&Progress { evaluated: evaluated.get(), total: None, best: best.borrow().clone() });
+        pass
+    };
+    let check = |n: usize, q: u64, sigma: f64, ok: fn(&SecurityEstimate, f64) -> bool| record(n, q, sigma, estimate(n, q, sigma), ok);
+
+    let mut h = None;
+    let (n, q, sigma) = match param {
+        SearchParam::N => {
+            // Doubling to bracket the answer keeps early estimates cheap
+            let mut hi = MIN_SEARCH_N;
+            while !check(hi, q, sigma, meets_with_attack) {
+                if hi >= MAX_SEARCH_N {
+                    return None;
+                }
+                hi = (hi * 2).min(MAX_SEARCH_N);
+            }
+            let lo = (hi / 2 + 1).max(MIN_SEARCH_N);
+            let n = bisect(lo as u64, hi as u64, |x| check(x as usize, q, sigma, meets_with_attack))?;
+            (n as usize, q, sigma)
+        }
+        SearchParam::LogQ => {
+            let q = boundary(2, 1 << 62, |x| check(n, x, sigma, meets))?;
+            (n, q, sigma)
+        }
+        SearchParam::Sigma => {
+            let sigma = boundary_f64(1e-3, q as f64 / 2.0, 1e-6, |s| check(n, q, s, meets))?;
+            (n, q, sigma)
+        }
+        SearchParam::H => {
+            // A heavier secret is wider, so security grows with h
+            h = Some(bisect(1, n as u64, |x| record(n, q, sigma, sparse(x as usize), meets))? as usize);
+            (n, q, sigma)
+        }
+    };
+
+    Some(SearchResult {
+        n,
+        q,
+        sigma,
+        target_bits,
+        effective_target_bits: effective,
+        margin: margin.describe(),
+        estimate: h.map_or_else(|| estimate(n, q, sigma), sparse),
+        h,
+        failure_log2: None,
+        trace: audit.then(|| trace.into_inner()),
+    })
+}
+
+/// Decryption-failure constraint for joint searches
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FailureBound {
+    /// Largest acceptable log2 failure probability, e.g. -128
+    pub max_log2: f64,
+    /// Bits encrypted per ciphertext
+    pub message_bits: usize,
+}
+
+/// Smallest n over the candidate (q, σ) pairs that reaches `target_bits`
+/// (plus margin) while keeping decryption failure below `bound` and sizes
+/// within `budget`.
+///
+/// Failure probability and sizes grow with n, so for each pair only the
+/// smallest secure n needs checking. Ties on n go to the smaller q.
+pub fn search_joint(
+    q_values: &[u64],
+    sigma_values: &[f64],
+    target_bits: f64,
+    margin: SecurityMargin,
+    bound: FailureBound,
+    budget: ByteBudget,
+    sieving: bool,
+) -> Option<SearchResult> {
+    search_joint_with_progress(q_values, sigma_values, target_bits, margin, bound, budget, sieving, &no_progress)
+}
+
+/// [`search_joint`] reporting after every (q, σ) pair; the reported best
+/// is the smallest admissible n found so far
+#[allow(clippy::too_many_arguments)]
+pub fn search_joint_with_progress(
+    q_values: &[u64],
+    sigma_values: &[f64],
+    target_bits: f64,
+    margin: SecurityMargin,
+    bound: FailureBound,
+    budget: ByteBudget,
+    sieving: bool,
+    progress: ProgressFn,
+) -> Option<SearchResult> {
+    search_joint_cached(q_values, sigma_values, target_bits, margin, bound, budget, sieving, progress, None)
+}
+
+/// [`search_joint_with_progress`] recording every candidate of every
+/// (q, σ) pair in a checkpoint file; see [`solve_checkpointed`]
+#[allow(clippy::too_many_arguments)]
+pub fn search_joint_checkpointed(
+    q_values: &[u64],
+    sigma_values: &[f64],
+    target_bits: f64,
+    margin: SecurityMargin,
+    bound: FailureBound,
+    budget: ByteBudget,
+    sieving: bool,
+    path: &Path,
+    resume: bool,
+    progress: ProgressFn,
+) -> io::Result<Option<SearchResult>> {
+    let cache = EstimateCache::open(path, sieving, resume)?;
+    let found =
+        search_joint_cached(q_values, sigma_values, target_bits, margin, bound, budget, sieving, progress, Some(&cache));
+    cache.finish()?;
+    Ok(found)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn search_joint_cached(
+    q_values: &[u64],
+    sigma_values: &[f64],
+    target_bits: f64,
+    margin: SecurityMargin,
+    bound: FailureBound,
+    budget: ByteBudget,
+    sieving: bool,
+    progress: ProgressFn,
+    cache: Option<&EstimateCache>,
+) -> Option<SearchResult> {
+    let pairs: Vec<(u64, f64)> = q_values
+        .iter()
+        .flat_map(|&q| sigma_values.iter().map(move |&s| (q, s)))
+        .collect();
+    let evaluated = AtomicUsize::new(0);
+    let best: Mutex<Option<SecurityEstimate>> = Mutex::new(None);
+    pairs
+        .par_iter()
+        .filter_map(|&(q, sigma)| {
+            let found =
+                solve_cached(SearchParam::N, 0, q, sigma, target_bits, margin, sieving, false, &no_progress, cache);
+            let r = found.and_then(|mut r| {
+                if !budget.admits(r.n, q) {
+                    return None;
+                }
+                let failure = failure_log2(r.n, q, sigma, bound.message_bits);
+                if failure > bound.max_log2 {
+                    return None;
+                }
+                r.failure_log2 = Some(failure);
+                Some(r)
+            });
+            let mut best = best.lock().unwrap();
+            if let Some(r) = &r {
+                if best.as_ref().is_none_or(|b| (r.n, r.q) < (b.n, b.q)) {
+                    *best = Some(r.estimate.clone());
+                }
+            }
+            let evaluated = evaluated.fetch_add(1, Ordering::Relaxed) + 1;
+            progress(&Progress { evaluated, total: Some(pairs.len()), best: best.clone() });
+            r
+        })
+        .min_by(|a, b| (a.n, a.q).cmp(&(b.n, b.q)))
+}
+
+/// Limits a ranked search candidate must respect besides security
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Constraints {
+    pub failure: Option<FailureBound>,
+    pub budget: ByteBudget,
+}
+
+impl Constraints {
+    pub fn admits(&self, n: usize, q: u64, sigma: f64) -> bool {
+        self.budget.admits(n, q)
+            && self.failure.is_none_or(|b| failure_log2(n, q, sigma, b.message_bits) <= b.max_log2)
+    }
+}
+
+/// [`solve`] ranking every passing candidate the search examined that
+/// also meets `constraints`, closest to the target first: the first is
+/// the answer, the next `runner_ups` are the alternatives with more
+/// security to spare
+#[allow(clippy::too_many_arguments)]
+pub fn solve_ranked(
+    param: SearchParam,
+    n: usize,
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+    constraints: Constraints,
+    runner_ups: usize,
+) -> Option<(SearchResult, Vec<SearchResult>)> {
+    let found = solve_with_progress(param, n, q, sigma, target_bits, margin, sieving, true, &no_progress)?;
+    if found.h.is_some() {
+        // Every h candidate shares (n, q, σ): there are no runner-ups to rank
+        return constraints.admits(n, q, sigma).then(|| (SearchResult { trace: None, ..found }, Vec::new()));
+    }
+    let key = |t: &TraceEntry| (t.n, t.q, t.sigma);
+    // The boundary the plain search returns leads when it is admitted;
+    // the trace may not hold that exact point for σ
+    let boundary = TraceEntry {
+        n: found.n,
+        q: found.q,
+        sigma: found.sigma,
+        bits: found.estimate.classical_bits,
+        beta: found.estimate.beta,
+        accepted: true,
+    };
+    let mut passing: Vec<&TraceEntry> = found
+        .trace
+        .iter()
+        .flatten()
+        .filter(|t| t.accepted && key(t) != key(&boundary))
+        .collect();
+    passing.sort_by(|a, b| a.bits.total_cmp(&b.bits).then((a.n, a.q).cmp(&(b.n, b.q))));
+    passing.dedup_by(|a, b| key(a) == key(b));
+    let mut ranked = std::iter::once(&boundary)
+        .chain(passing)
+        .filter(|t| constraints.admits(t.n, t.q, t.sigma))
+        .take(runner_ups + 1)
+        .map(|t| SearchResult {
+            n: t.n,
+            q: t.q,
+            sigma: t.sigma,
+            target_bits,
+            effective_target_bits: found.effective_target_bits,
+            margin: found.margin.clone(),
+            estimate: estimate_core(t.n, t.q, t.sigma, sieving),
+            h: None,
+            failure_log2: constraints.failure.map(|b| failure_log2(t.n, t.q, t.sigma, b.message_bits)),
+            trace: None,
+        });
+    let best = ranked.next()?;
+    Some((best, ranked.collect()))
+}
+
+/// Smallest n reaching `target_bits` (plus margin) for fixed q and σ
+pub fn search_n(
+    q: u64,
+    sigma: f64,
+    target_bits: f64,
+    margin: SecurityMargin,
+    sieving: bool,
+) -> Result<SearchResult, CryptoParamError> {
+    solve(SearchParam::N, 0, q, sigma, target_bits, margin, sieving)
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Find the smallest n reaching a target security level.
+///
+/// Args:
+///     q: Modulus
+///     sigma: Error standard deviation
+///     target_bits: Required security in bits
+///     margin: Safety margin, e.g. "+16" (bits) or "15%" (default: none)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     audit: Record every candidate examined in `trace` (default: False)
+///     progress: Callable invoked as progress(done, None, best) after each
+///         candidate, best being the latest candidate meeting the target
+///     checkpoint: Path of a checkpoint file to record candidates in (default: None)
+///     resume: Reuse candidates already in `checkpoint` (default: False)
+///
+/// Returns:
+///     SearchResult with the chosen n and the margin that was applied
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "search_n",
+    signature = (
+        q, sigma, target_bits, margin = None, sieving = false, audit = false, progress = None,
+        checkpoint = None, resume = false
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_search_n(
+    py: Python,
+    q: u64,
+    sigma: Sigma,
+    target_bits: f64,
+    margin: Option<&str>,
+    sieving: bool,
+    audit: bool,
+    progress: Option<PyObject>,
+    checkpoint: Option<std::path::PathBuf>,
+    resume: bool,
+) -> PyResult<SearchResult> {
+    let sigma = sigma.0;
+    crate::error::check_modulus(q)?;
+    crate::error::check_sigma(sigma)?;
+    let margin = match margin {
+        Some(s) => SecurityMargin::parse(s)?,
+        None => SecurityMargin::None,
+    };
+    let progress = PyProgress::new(progress);
+    let found = py.allow_threads(|| {
+        let report = |p: &Progress| progress.report(p);
+        match &checkpoint {
+            Some(path) => {
+                solve_checkpointed(SearchParam::N, 0, q, sigma, target_bits, margin, sieving, audit, path, resume, &report)
+            }
+            None => Ok(solve_with_progress(SearchParam::N, 0, q, sigma, target_bits, margin, sieving, audit, &report)),
+        }
+    })
+    .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    progress.finish()?;
+    Ok(found.ok_or_else(|| search_failed(SearchParam::N, margin.apply(target_bits)))?)
+}
+
+/// Solve for one parameter so security reaches a target.
+///
+/// Args:
+///     vary: Parameter to solve for: "n", "q", "sigma" or "h", the
+///         Hamming weight of a sparse secret
+///     target_bits: Required security in bits
+///     n, q, sigma: Values of the fixed parameters, all three for "h"
+///     margin: Safety margin, e.g. "+16" (bits) or "15%" (default: none)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     audit: Record every candidate examined in `trace` (default: False)
+///     progress: Callable invoked as progress(done, None, best) after each
+///         candidate, best being the latest candidate meeting the target
+///     checkpoint: Path of a checkpoint file to record candidates in (default: None)
+///     resume: Reuse candidates already in `checkpoint` (default: False)
+///
+/// Returns:
+///     SearchResult at the boundary where the target is just met
+///
+/// Example:
+///     >>> from cryptoparam import solve
+///     >>> solve("q", 128, n=1024, sigma=3.19).q
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "solve",
+    signature = (
+        vary, target_bits, n = None, q = None, sigma = None,
+        margin = None, sieving = false, audit = false, progress = None,
+        checkpoint = None, resume = false
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_solve(
+    py: Python,
+    vary: &str,
+    target_bits: f64,
+    n: Option<usize>,
+    q: Option<u64>,
+    sigma: Option<Sigma>,
+    margin: Option<&str>,
+    sieving: bool,
+    audit: bool,
+    progress: Option<PyObject>,
+    checkpoint: Option<std::path::PathBuf>,
+    resume: bool,
+) -> PyResult<SearchResult> {
+    let sigma = sigma.map(|s| s.0);
+    let param = SearchParam::parse(vary)?;
+    let missing = |name: &str| {
+        crate::errors::InvalidParameters::new_err(format!("{} must be given when solving for {}", name, vary))
+    };
+    let n = match param {
+        SearchParam::N => 0,
+        _ => n.ok_or_else(|| missing("n"))?,
+    };
+    let q = match param {
+        SearchParam::LogQ => 0,
+        _ => q.ok_or_else(|| missing("q"))?,
+    };
+    let sigma = match param {
+        SearchParam::Sigma => 0.0,
+        _ => sigma.ok_or_else(|| missing("sigma"))?,
+    };
+    if param != SearchParam::N {
+        crate::error::check_dimension(n)?;
+    }
+    if param != SearchParam::LogQ {
+        crate::error::check_modulus(q)?;
+    }
+    if param != SearchParam::Sigma {
+        crate::error::check_sigma(sigma)?;
+    }
+    let margin = match margin {
+        Some(s) => SecurityMargin::parse(s)?,
+        None => SecurityMargin::None,
+    };
+    let progress = PyProgress::new(progress);
+    let found = py.allow_threads(|| {
+        let report = |p: &Progress| progress.report(p);
+        match &checkpoint {
+            Some(path) => solve_checkpointed(param, n, q, sigma, target_bits, margin, sieving, audit, path, resume, &report),
+            None => Ok(solve_with_progress(param, n, q, sigma, target_bits, margin, sieving, audit, &report)),
+        }
+    })
+    .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    progress.finish()?;
+    Ok(found.ok_or_else(|| search_failed(param, margin.apply(target_bits)))?)
+}
+
+/// Find parameters meeting both a security target and a failure bound.
+///
+/// Args:
+///     q_values: Candidate moduli
+///     sigma_values: Candidate error standard deviations
+///     target_bits: Required security in bits
+///     max_failure_log2: Largest acceptable log2 Pr[failure] (default: -128)
+///     message_bits: Bits encrypted per ciphertext (default: 256)
+///     template: Size layout, "lwe", "rlwe" or "mlwe:<rank>" (default: "lwe")
+///     max_ciphertext_bytes: Reject larger ciphertexts (default: no limit)
+///     max_public_key_bytes: Reject larger public keys (default: no limit)
+///     margin: Safety margin, e.g. "+16" or "15%" (default: none)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     progress: Callable invoked as progress(done, total, best) after each
+///         (q, sigma) pair, best being the smallest admissible n so far
+///     n_threads: Worker threads, None for one per core (default: None)
+///     checkpoint: Path of a checkpoint file to record candidates in (default: None)
+///     resume: Reuse candidates already in `checkpoint` (default: False)
+///
+/// Returns:
+///     SearchResult with the smallest n, its failure probability recorded
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "search_joint",
+    signature = (
+        q_values, sigma_values, target_bits, max_failure_log2 = -128.0,
+        message_bits = 256, template = "lwe", max_ciphertext_bytes = None,
+        max_public_key_bytes = None, margin = None, sieving = false, progress = None,
+        n_threads = None, checkpoint = None, resume = false
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_search_joint(
+    py: Python,
+    q_values: Vec<u64>,
+    sigma_values: Vec<Sigma>,
+    target_bits: f64,
+    max_failure_log2: f64,
+    message_bits: usize,
+    template: &str,
+    max_ciphertext_bytes: Option<f64>,
+    max_public_key_bytes: Option<f64>,
+    margin: Option<&str>,
+    sieving: bool,
+    progress: Option<PyObject>,
+    n_threads: Option<usize>,
+    checkpoint: Option<std::path::PathBuf>,
+    resume: bool,
+) -> PyResult<SearchResult> {
+    let sigma_values = sigma_values.into_iter().map(|s| s.0).collect::<Vec<f64>>();
+    q_values.iter().try_for_each(|&q| crate::error::check_modulus(q))?;
+    sigma_values.iter().try_for_each(|&s| crate::error::check_sigma(s))?;
+    let margin = match margin {
+        Some(s) => SecurityMargin::parse(s)?,
+        None => SecurityMargin::None,
+    };
+    let bound = FailureBound { max_log2: max_failure_log2, message_bits };
+    let budget = ByteBudget {
+        template: SizeTemplate::parse(template).map_err(crate::errors::InvalidParameters::new_err)?,
+        max_ciphertext_bytes,
+        max_public_key_bytes,
+    };
+    let progress = PyProgress::new(progress);
+    let found = py.allow_threads(|| {
+        with_threads(n_threads, || {
+            let report = |p: &Progress| progress.report(p);
+            match &checkpoint {
+                Some(path) => search_joint_checkpointed(
+                    &q_values, &sigma_values, target_bits, margin, bound, budget, sieving, path, resume, &report,
+                ),
+                None => Ok(search_joint_with_progress(
+                    &q_values, &sigma_values, target_bits, margin, bound, budget, sieving, &report,
+                )),
+            }
+        })
+    })
+    .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    progress.finish()?;
+    Ok(found.ok_or_else(|| {
+        CryptoParamError::SearchFailed(format!(
+            "no candidate reaches {:.1} bits with failure <= 2^{} within the size budget",
+            margin.apply(target_bits),
+            max_failure_log2
+        ))
+    })?)
+}
+
+/// Answer of `cryptoparam.search`: the chosen parameters and the
+/// runner-ups
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
+#[derive(Debug, Clone)]
+pub struct SearchOutcome {
+    /// Parameter that was solved for: "n", "q" or "sigma"
+    pub vary: String,
+    /// Chosen candidate, as the lower-level search functions return it
+    pub result: SearchResult,
+    /// Further passing candidates, closest to the target first
+    pub runner_ups: Vec<SearchResult>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SearchOutcome {
+    #[getter]
+    fn params(&self) -> LweParams {
+        LweParams { n: self.result.n, q: self.result.q, sigma: self.result.sigma }
+    }
+
+    #[getter]
+    fn estimate(&self) -> SecurityEstimate {
+        self.result.estimate.clone()
+    }
+
+    #[getter]
+    fn bits(&self) -> f64 {
+        self.result.estimate.classical_bits
+    }
+
+    #[getter]
+    fn target_bits(&self) -> f64 {
+        self.result.target_bits
+    }
+
+    /// Margin that was applied, e.g. "+16 bits"
+    #[getter]
+    fn margin(&self) -> String {
+        self.result.margin.clone()
+    }
+
+    /// Bits above the requested target
+    #[getter]
+    fn margin_bits(&self) -> f64 {
+        self.result.estimate.classical_bits - self.result.target_bits
+    }
+
+    /// {"vary", "n", "q", "sigma", "bits", "target_bits", "margin",
+    /// "margin_bits", "failure_log2", "runner_ups"}, runner-ups as
+    /// (n, q, sigma, bits) tuples
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("vary", &self.vary)?;
+        d.set_item("n", self.result.n)?;
+        d.set_item("q", self.result.q)?;
+        d.set_item("sigma", self.result.sigma)?;
+        d.set_item("bits", self.bits())?;
+        d.set_item("target_bits", self.result.target_bits)?;
+        d.set_item("margin", &self.result.margin)?;
+        d.set_item("margin_bits", self.margin_bits())?;
+        d.set_item("failure_log2", self.result.failure_log2)?;
+        let runner_ups: Vec<(usize, u64, f64, f64)> =
+            self.runner_ups.iter().map(|r| (r.n, r.q, r.sigma, r.estimate.classical_bits)).collect();
+        d.set_item("runner_ups", runner_ups)?;
+        Ok(d)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SearchOutcome(vary='{}', params=LweParams(n={}, q={}, sigma={}), bits={:.1}, target_bits={}, margin_bits={:+.1}, runner_ups={})",
+            self.vary,
+            self.result.n,
+            self.result.q,
+            self.result.sigma,
+            self.bits(),
+            self.result.target_bits,
+            self.margin_bits(),
+            self.runner_ups.len()
+        )
+    }
+}
+
+/// Constraints from a dict with the keys "max_failure_log2",
+/// "message_bits", "template", "max_ciphertext_bytes" and
+/// "max_public_key_bytes"
+#[cfg(feature = "python")]
+fn constraints_from_dict(d: &PyDict) -> PyResult<Constraints> {
+    const KEYS: [&str; 5] = ["max_failure_log2", "message_bits", "template", "max_ciphertext_bytes", "max_public_key_bytes"];
+    for key in d.keys() {
+        let name = key.str()?.to_string();
+        if !KEYS.contains(&name.as_str()) {
+            return Err(crate::errors::InvalidParameters::new_err(format!(
+                "unknown constraint '{}' (expected one of {})",
+                name,
+                KEYS.join(", ")
+            )));
+        }
+    }
+    let get = |key: &str| d.get_item(key).map(|v| v.filter(|v| !v.is_none()));
+    let message_bits = get("message_bits")?.map(|v| v.extract()).transpose()?.unwrap_or(256);
+    let template = match get("template")? {
+        Some(t) => SizeTemplate::parse(t.extract()?).map_err(crate::errors::InvalidParameters::new_err)?,
+        None => SizeTemplate::default(),
+    };
+    Ok(Constraints {
+        failure: get("max_failure_log2")?
+            .map(|v| v.extract())
+            .transpose()?
+            .map(|max_log2| FailureBound { max_log2, message_bits }),
+        budget: ByteBudget {
+            template,
+            max_ciphertext_bytes: get("max_ciphertext_bytes")?.map(|v| v.extract()).transpose()?,
+            max_public_key_bytes: get("max_public_key_bytes")?.map(|v| v.extract()).transpose()?,
+        },
+    })
+}
+
+/// Solve for one parameter with the others fixed, Python-style.
+///
+/// Args:
+///     target_bits: Required security in bits
+///     fix: Values of the parameters held fixed, e.g.
+///         {"q": 2**32, "sigma": 3.19}; all of n, q and sigma for "h"
+///     vary: Parameter to solve for: "n", "q", "sigma" or "h" (default: "n")
+///     constraints: Further limits, a dict with any of "max_failure_log2",
+///         "message_bits", "template", "max_ciphertext_bytes" and
+///         "max_public_key_bytes" (default: none)
+///     margin: Safety margin, e.g. "+16" (bits) or "15%" (default: none)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     runner_ups: Alternatives to report besides the answer (default: 3)
+///
+/// Returns:
+///     SearchOutcome with the chosen params, its margin and the runner-ups
+///
+/// Example:
+///     >>> r = search(target_bits=128, fix={"q": 2**32, "sigma": 3.19})
+///     >>> r.params, r.margin_bits
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "search",
+    signature = (
+        target_bits, fix, vary = "n", constraints = None, margin = None,
+        sieving = false, runner_ups = 3
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_search(
+    py: Python,
+    target_bits: f64,
+    fix: &PyDict,
+    vary: &str,
+    constraints: Option<&PyDict>,
+    margin: Option<&str>,
+    sieving: bool,
+    runner_ups: usize,
+) -> PyResult<SearchOutcome> {
+    let param = SearchParam::parse(vary)?;
+    let vary = param.name();
+    let fixed: Vec<&str> = ["n", "q", "sigma"].into_iter().filter(|&k| k != vary).collect();
+    for key in fix.keys() {
+        let name = key.str()?.to_string();
+        if !fixed.contains(&name.as_str()) {
+            return Err(crate::errors::InvalidParameters::new_err(format!(
+                "fix has '{}'; when solving for {} it takes {} and {}",
+                name, vary, fixed[..fixed.len() - 1].join(", "), fixed[fixed.len() - 1]
+            )));
+        }
+    }
+    let value = |key: &str| {
+        fix.get_item(key)?
+            .ok_or_else(|| crate::errors::InvalidParameters::new_err(format!("fix needs '{}' when solving for {}", key, vary)))
+    };
+    let n: usize = if param == SearchParam::N { 0 } else { value("n")?.extract()? };
+    let q: u64 = if param == SearchParam::LogQ { 0 } else { value("q")?.extract()? };
+    let sigma = if param == SearchParam::Sigma { 0.0 } else { value("sigma")?.extract::<Sigma>()?.0 };
+    if param != SearchParam::N {
+        crate::error::check_dimension(n)?;
+    }
+    if param != SearchParam::LogQ {
+        crate::error::check_modulus(q)?;
+    }
+    if param != SearchParam::Sigma {
+        crate::error::check_sigma(sigma)?;
+    }
+    let constraints = constraints.map(constraints_from_dict).transpose()?.unwrap_or_default();
+    let margin = match margin {
+        Some(s) => SecurityMargin::parse(s)?,
+        None => SecurityMargin::None,
+    };
+    let found = py.allow_threads(|| {
+        solve_ranked(param, n, q, sigma, target_bits, margin, sieving, constraints, runner_ups)
+    });
+    let (result, runner_ups) = found.ok_or_else(|| {
+        CryptoParamError::SearchFailed(format!(
+            "no {} reaches {:.1} bits within the constraints",
+            vary,
+            margin.apply(target_bits)
+        ))
+    })?;
+    Ok(SearchOutcome { vary: vary.to_string(), result, runner_ups })
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_margin_parse() {
+        assert_eq!(SecurityMargin::parse("+16").unwrap(), SecurityMargin::Bits(16.0));
+        assert_eq!(SecurityMargin::parse("15%").unwrap(), SecurityMargin::Percent(15.0));
+        assert!(SecurityMargin::parse("-3").is_err());
+        assert_eq!(SecurityMargin::parse("16bits").unwrap(), SecurityMargin::Bits(16.0));
+        for bad in ["nan", "inf", "-inf%", "16bitsbits", "16bitbits", "5%%"] {
+            assert_eq!(SecurityMargin::parse(bad).unwrap_err(), CryptoParamError::InvalidOption(format!("Invalid margin: {}", bad)));
+        }
+        assert!((SecurityMargin::Percent(10.0).apply(100.0) - 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solve_checkpoint_resume() {
+        let path = std::env::temp_dir().join(format!("cryptoparam-search-ckpt-{}.txt", std::process::id()));
+        let full = solve(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false).unwrap();
+        solve_checkpointed(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, false, &path, false, &no_progress)
+            .unwrap();
+        let candidates = std::fs::read_to_string(&path).unwrap().lines().count() - 1;
+
+        // Keep the first few candidates, as if the search had been killed
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, text.lines().take(4).map(|l| format!("{}\n", l)).collect::<String>()).unwrap();
+        let resumed =
+            solve_checkpointed(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, false, &path, true, &no_progress)
+                .unwrap()
+                .unwrap();
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((resumed.n, resumed.estimate.beta), (full.n, full.estimate.beta));
+        assert_eq!(lines, 1 + candidates);
+    }
+
+    #[test]
+    fn test_search_n_with_margin() {
+        let plain = search_n(7681, 8.0, 60.0, SecurityMargin::None, false).unwrap();
+        assert!(plain.estimate.classical_bits >= 60.0);
+        assert!(estimate_core(plain.n - 1, 7681, 8.0, false).classical_bits < 60.0);
+
+        let padded = search_n(7681, 8.0, 60.0, SecurityMargin::Bits(10.0), false).unwrap();
+        assert!(padded.n > plain.n);
+        assert!(padded.estimate.classical_bits >= 70.0);
+        assert_eq!(padded.margin, "+10 bits");
+    }
+
+    #[test]
+    fn test_search_joint_respects_failure_bound() {
+        let bound = FailureBound { max_log2: -128.0, message_bits: 256 };
+        let qs = [7681, 12289, 1 << 16, 1 << 20];
+        let r = search_joint(&qs, &[3.0], 80.0, SecurityMargin::None, bound, ByteBudget::default(), false).unwrap();
+        assert!(r.estimate.classical_bits >= 80.0);
+        assert!(r.failure_log2.unwrap() <= -128.0);
+
+        let tight = FailureBound { max_log2: -1e6, message_bits: 256 };
+        assert!(search_joint(&qs, &[3.0], 80.0, SecurityMargin::None, tight, ByteBudget::default(), false).is_none());
+    }
+
+    #[test]
+    fn test_solve_reports_progress() {
+        let calls = std::sync::Mutex::new(0usize);
+        let last_best = std::sync::Mutex::new(None);
+        let r = solve_with_progress(
+            SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, false,
+            &|p: &Progress| {
+                *calls.lock().unwrap() = p.evaluated;
+                *last_best.lock().unwrap() = p.best.as_ref().map(|b| b.n);
+            },
+        )
+        .unwrap();
+        assert!(*calls.lock().unwrap() > 5);
+        assert_eq!(*last_best.lock().unwrap(), Some(r.n));
+    }
+
+    #[test]
+    fn test_search_joint_reports_progress() {
+        let bound = FailureBound { max_log2: -40.0, message_bits: 256 };
+        let budget = ByteBudget::default();
+        let seen = std::sync::Mutex::new(Vec::new());
+        let r = search_joint_with_progress(
+            &[7681, 12289], &[3.2, 8.0], 60.0, SecurityMargin::None, bound, budget, false,
+            &|p: &Progress| seen.lock().unwrap().push((p.evaluated, p.total, p.best.as_ref().map(|b| b.n))),
+        )
+        .unwrap();
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(seen.iter().map(|s| (s.0, s.1)).collect::<Vec<_>>(), [1, 2, 3, 4].map(|k| (k, Some(4))));
+        assert_eq!(seen[3].2, Some(r.n));
+    }
+
+    #[test]
+    fn test_solve_ranked() {
+        let none = Constraints::default();
+        let plain = solve(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false).unwrap();
+        let (best, others) = solve_ranked(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, none, 3).unwrap();
+        assert_eq!(best.n, plain.n);
+        assert!(!others.is_empty() && others.len() <= 3);
+        assert!(others.iter().all(|r| r.n > best.n && r.estimate.classical_bits >= best.estimate.classical_bits));
+
+        let bound = Constraints { failure: Some(FailureBound { max_log2: -1e6, message_bits: 256 }), ..none };
+        assert!(solve_ranked(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, bound, 3).is_none());
+    }
+
+    #[test]
+    fn test_audit_trace() {
+        let r = solve_with_progress(
+            SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false, true, &no_progress,
+        )
+        .unwrap();
+        let trace = r.trace.as_ref().unwrap();
+        assert!(trace.len() > 5);
+        assert!(trace.iter().all(|t| t.accepted == (t.beta.is_some() && t.bits >= 60.0)));
+        assert!(trace.iter().any(|t| t.n == r.n && t.accepted));
+        assert!(summarize_trace(trace).starts_with(&format!("{} candidates", trace.len())));
+        assert!(solve(SearchParam::N, 0, 7681, 8.0, 60.0, SecurityMargin::None, false).unwrap().trace.is_none());
+    }
+
+    #[test]
+    fn test_bisect_primitive() {
+        assert_eq!(bisect(0, 1000, |x| x * x >= 500), Some(23));
+        assert_eq!(bisect(0, 10, |_| false), None);
+        let r = bisect_f64(0.0, 10.0, 1e-9, |x| x >= 3.25).unwrap();
+        assert!(r >= 3.25 && r - 3.25 < 1e-6);
+        assert_eq!(boundary(0, 100, |x| x <= 42), Some(42));
+        assert_eq!(boundary(0, 100, |x| x >= 42), Some(42));
+    }
+
+    #[test]
+    fn test_solve_q_and_sigma() {
+        // Security grows with q in the primal model: smallest sufficient q
+        let r = solve(SearchParam::LogQ, 512, 0, 3.19, 100.0, SecurityMargin::None, false).unwrap();
+        assert!(r.estimate.classical_bits >= 100.0);
+        assert!(estimate_core(512, r.q - 1, 3.19, false).classical_bits < 100.0);
+
+        // ... and shrinks with σ: largest tolerable σ
+        let r = solve(SearchParam::Sigma, 256, 7681, 0.0, 60.0, SecurityMargin::None, false).unwrap();
+        assert!(r.estimate.classical_bits >= 60.0);
+        assert!(estimate_core(256, 7681, r.sigma * 1.01, false).classical_bits < 60.0);
+    }
+
+    #[test]
+    fn test_solve_hamming_weight() {
+        assert_eq!(SearchParam::parse("h"), Ok(SearchParam::H));
+        let log2_q = 12289f64.log2();
+        let bits = |h: usize| estimate_secret(1024, log2_q, 3.2, SecretDist::Sparse(h), false).classical_bits;
+        // Smallest h reaching the target: h - 1 falls short
+        let r = solve(SearchParam::H, 1024, 12289, 3.2, 300.0, SecurityMargin::None, false).unwrap();
+        let h = r.h.unwrap();
+        assert!(r.estimate.classical_bits >= 300.0 && bits(h - 1) < 300.0);
+        assert_eq!(r.estimate.secret, Some(format!("sparse:{}", h)));
+        // A Gaussian secret bounds every sparse one
+        let err = solve(SearchParam::H, 1024, 12289, 3.2, 700.0, SecurityMargin::None, false).unwrap_err();
+        assert!(matches!(err, CryptoParamError::SearchFailed(_)));
+    }
+}
@@ -0,0 +1,186 @@
+//! Small-secret LWE
+//!
+//! When the secret is narrower than the error, the Bai–Galbraith embedding
+//! scales the secret coordinates of the uSVP target by ν = σ/σ_s so both
+//! halves of it have width σ. The factor ν^n is credited against m·ln q in
+//! the success condition, so a small secret never needs a larger block
+//! size than a Gaussian one.
+
+use crate::distribution::{embedding_log_scale, DiscreteGaussian, Distribution, SparseTernary, UniformInt};
+use crate::{estimate_bounded, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Distribution of the LWE secret
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SecretDist {
+    /// Same distribution as the error (normal form LWE)
+    Gaussian,
+    /// Uniform in {0, 1}
+    Binary,
+    /// Uniform in {−1, 0, 1}
+    Ternary,
+    /// Ternary with exactly h non-zero coefficients
+    Sparse(usize),
+}
+
+impl SecretDist {
+    /// Parse `gaussian`, `binary`, `ternary` or `sparse:h`
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "gaussian" | "error" => Ok(Self::Gaussian),
+            "binary" => Ok(Self::Binary),
+            "ternary" => Ok(Self::Ternary),
+            _ => match s.strip_prefix("sparse:").map(|h| h.parse::<usize>()) {
+                Some(Ok(h)) if h > 0 => Ok(Self::Sparse(h)),
+                Some(_) => Err(format!("invalid Hamming weight in '{}' (expected sparse:h with h > 0)", s)),
+                None => Err(format!("unknown secret distribution '{}' (expected gaussian, binary, ternary or sparse:h)", s)),
+            },
+        }
+    }
+
+    /// Name as accepted by [`SecretDist::parse`]
+    pub fn name(&self) -> String {
+        match self {
+            Self::Gaussian => "gaussian".to_string(),
+            Self::Binary => "binary".to_string(),
+            Self::Ternary => "ternary".to_string(),
+            Self::Sparse(h) => format!("sparse:{}", h),
+        }
+    }
+
+    /// The distribution itself, for an error of width σ
+    pub fn resolve(&self, sigma: f64) -> Box<dyn Distribution> {
+        match self {
+            Self::Gaussian => Box::new(DiscreteGaussian::new(sigma)),
+            Self::Binary => Box::new(UniformInt::new(0, 1)),
+            Self::Ternary => Box::new(UniformInt::new(-1, 1)),
+            Self::Sparse(h) => Box::new(SparseTernary::new(*h)),
+        }
+    }
+
+    /// Standard deviation of one secret coefficient in dimension n; None
+    /// for a secret drawn like the error
+    pub fn sigma(&self, n: usize) -> Option<f64> {
+        (*self != Self::Gaussian).then(|| self.resolve(1.0).std_dev(n))
+    }
+
+    /// ln ν of the embedding for error width σ; 0 when the secret is not
+    /// narrower than the error and the standard embedding is used
+    pub fn log_scale(&self, n: usize, sigma: f64) -> f64 {
+        embedding_log_scale(n, &*self.resolve(sigma), &DiscreteGaussian::new(sigma))
+    }
+
+    /// Reject a Hamming weight above the dimension
+    pub fn check(&self, n: usize) -> Result<(), String> {
+        match self {
+            Self::Sparse(h) if *h > n => Err(format!("Hamming weight {} exceeds n = {}", h, n)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// ln ν the estimate `r` was computed with, from its recorded secret
+pub fn embedding_scale(r: &SecurityEstimate) -> f64 {
+    r.secret.as_deref().and_then(|s| SecretDist::parse(s).ok()).map_or(0.0, |s| s.log_scale(r.n, r.sigma))
+}
+
+/// Primal-uSVP estimate of (n, q = 2^log2_q, σ) with the secret drawn from
+/// `secret`
+pub fn estimate_secret(n: usize, log2_q: f64, sigma: f64, secret: SecretDist, sieving: bool) -> SecurityEstimate {
+    estimate_bounded(n, log2_q, sigma, secret, None, sieving)
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Estimate LWE security with a small secret.
+///
+/// Args:
+///     n: LWE dimension
+///     q: Modulus
+///     sigma: Error standard deviation
+///     secret: "gaussian", "binary", "ternary" or "sparse:h"
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     SecurityEstimate with `secret` recorded
+///
+/// Example:
+///     >>> r = estimate_small_secret(512, 12289, 3.2, "ternary")
+///     >>> r.secret
+///     'ternary'
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "estimate_small_secret", signature = (n, q, sigma, secret, sieving = false))]
+pub fn py_estimate_small_secret(
+    py: Python,
+    n: usize,
+    q: u64,
+    sigma: Sigma,
+    secret: &str,
+    sieving: bool,
+) -> PyResult<SecurityEstimate> {
+    let sigma = sigma.0;
+    if n == 0 || q < 2 || sigma <= 0.0 {
+        return Err(crate::errors::InvalidParameters::new_err("need n > 0, q >= 2 and sigma > 0"));
+    }
+    let secret = SecretDist::parse(secret).map_err(crate::errors::UnsupportedDistribution::new_err)?;
+    secret.check(n).map_err(crate::errors::InvalidParameters::new_err)?;
+    let mut r = estimate_secret(n, (q as f64).log2(), sigma, secret, sieving);
+    r.q = q;
+    r.warnings.extend(crate::warnings::precision_warning(q));
+    crate::defaults::apply(&mut r, sieving);
+    crate::warnings::emit(py, [&r])?;
+    Ok(r)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::estimate_core_log2q;
+
+    #[test]
+    fn test_parse_secret() {
+        for name in ["gaussian", "binary", "ternary", "sparse:64"] {
+            assert_eq!(SecretDist::parse(name).unwrap().name(), name);
+        }
+        assert!(SecretDist::parse("sparse:0").is_err());
+        assert!(SecretDist::parse("uniform").unwrap_err().contains("sparse:h"));
+        assert!(SecretDist::Sparse(600).check(512).is_err());
+    }
+
+    #[test]
+    fn test_scaled_embedding() {
+        let log2_q = 12289f64.log2();
+        let gaussian = estimate_secret(512, log2_q, 3.2, SecretDist::Gaussian, false);
+        assert_eq!(gaussian.beta, estimate_core_log2q(512, log2_q, 3.2, false).beta);
+        assert!(gaussian.secret.is_none());
+        let ternary = estimate_secret(512, log2_q, 3.2, SecretDist::Ternary, false);
+        assert_eq!(ternary.secret.as_deref(), Some("ternary"));
+        assert_ne!(ternary.beta, gaussian.beta);
+        assert!((embedding_scale(&ternary) - (3.2 / (2.0f64 / 3.0).sqrt()).ln()).abs() < 1e-12);
+        // No rescaling once the secret is as wide as the error
+        assert_eq!(SecretDist::Binary.log_scale(512, 0.4), 0.0);
+    }
+
+    #[test]
+    fn test_small_secret_never_stronger() {
+        for (n, q, sigma) in [(512, 12289u64, 3.2), (1024, 12289, 3.2), (1024, 1 << 27, 3.19)] {
+            let log2_q = (q as f64).log2();
+            let gaussian = estimate_secret(n, log2_q, sigma, SecretDist::Gaussian, false).classical_bits;
+            for secret in [SecretDist::Binary, SecretDist::Ternary, SecretDist::Sparse(64)] {
+                let bits = estimate_secret(n, log2_q, sigma, secret, false).classical_bits;
+                assert!(bits <= gaussian, "{} at n = {}: {} > {}", secret.name(), n, bits, gaussian);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Short Integer Solution (SIS) hardness
+//!
+//! Lattice-reduction estimate for SIS: given A ∈ Z_q^{n×m}, find a nonzero
+//! z with A·z = 0 (mod q) and ‖z‖∞ ≤ bound. BKZ-β run on d of the m columns
+//! finds vectors of length δ^d·q^{n/d}; the output is assumed balanced, so
+//! the ℓ∞ bound is met once that length is at most bound·√d.
+
+use crate::{beta_from_delta, bkz_cost};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// SIS instance, e.g. the MSIS side of a signature scheme
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SisParams {
+    /// Rows of A (module rank times ring degree for MSIS)
+    pub n: usize,
+    /// Columns of A
+    pub m: usize,
+    pub q: u64,
+    /// ℓ∞ bound on the solution
+    pub bound: f64,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SisParams {
+    fn __repr__(&self) -> String {
+        format!("SisParams(n={}, m={}, q={}, bound={})", self.n, self.m, self.q, self.bound)
+    }
+}
+
+/// SIS estimation result
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SisEstimate {
+    pub classical_bits: f64,
+    /// None when no attack is feasible
+    pub beta: Option<usize>,
+    /// Number of columns used by the attack
+    pub d: usize,
+    pub params: SisParams,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SisEstimate {
+    fn __repr__(&self) -> String {
+        let Some(beta) = self.beta else {
+            return format!(
+                "SIS(n={}, m={}, q≈2^{:.0}, bound={}): No lattice attack found",
+                self.params.n, self.params.m, (self.params.q as f64).log2(), self.params.bound
+            );
+        };
+        format!(
+            "SIS(n={}, m={}, q≈2^{:.0}, bound={}): ~{:.0} bits (β={}, d={})",
+            self.params.n,
+            self.params.m,
+            (self.params.q as f64).log2(),
+            self.params.bound,
+            self.classical_bits,
+            beta,
+            self.d
+        )
+    }
+}
+
+/// Cheapest BKZ attack over the sublattice dimension d ∈ (n, m]
+pub fn sis_core(params: &SisParams, sieving: bool) -> SisEstimate {
+    let n = params.n as f64;
+    let log_q = (params.q as f64).ln();
+    let mut best_log_delta = 0.0;
+    let mut best_d = params.m;
+
+    if params.bound >= params.q as f64 {
+        // q·e_i is already short enough
+        best_log_delta = f64::INFINITY;
+        best_d = params.n + 1;
+    } else {
+        for d in (params.n + 1)..=params.m {
+            let d_f = d as f64;
+            let log_bound = params.bound.ln() + 0.5 * d_f.ln();
+            let log_delta = (log_bound - (n / d_f) * log_q) / d_f;
+            if log_delta > best_log_delta {
+                best_log_delta = log_delta;
+                best_d = d;
+            }
+        }
+    }
+
+    let beta = if best_log_delta > 0.0 { beta_from_delta(best_log_delta.exp()) } else { None };
+    SisEstimate {
+        classical_bits: beta.map_or(f64::INFINITY, |b| bkz_cost(b, sieving)),
+        beta,
+        d: best_d,
+        params: params.clone(),
+    }
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Estimate SIS security.
+///
+/// Args:
+///     n: Rows of A
+///     m: Columns of A
+///     q: Modulus
+///     bound: ℓ∞ bound on the solution
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     SisEstimate with bit-security and attack details
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "estimate_sis", signature = (n, m, q, bound, sieving = false))]
+pub fn py_estimate_sis(n: usize, m: usize, q: u64, bound: f64, sieving: bool) -> PyResult<SisEstimate> {
+    if n == 0 || m <= n {
+        return Err(crate::errors::InvalidParameters::new_err("need 0 < n < m"));
+    }
+    crate::error::check_modulus(q)?;
+    if bound < 1.0 {
+        return Err(crate::errors::InvalidParameters::new_err("bound must be >= 1"));
+    }
+    Ok(sis_core(&SisParams { n, m, q, bound }, sieving))
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sis_harder_with_smaller_bound() {
+        let loose = sis_core(&SisParams { n: 1024, m: 2304, q: 8380417, bound: 1e6 }, false);
+        let tight = sis_core(&SisParams { n: 1024, m: 2304, q: 8380417, bound: 1e4 }, false);
+        assert!(loose.beta < tight.beta);
+        assert!(loose.d > 1024 && loose.d <= 2304);
+    }
+
+    #[test]
+    fn test_sis_trivial_bound() {
+        let r = sis_core(&SisParams { n: 16, m: 64, q: 17, bound: 17.0 }, false);
+        assert_eq!(r.beta, Some(2));
+    }
+}
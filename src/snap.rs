@@ -0,0 +1,247 @@
+//! Snapping search results to implementable parameters
+//!
+//! Searches return the exact boundary n and q; real implementations need a
+//! supported ring dimension and an NTT-friendly prime q ≡ 1 (mod 2n). This
+//! module rounds to those, re-estimates, and reports what the rounding cost.
+
+use crate::search::SearchResult;
+use crate::{estimate_core, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::error::CryptoParamError;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+/// Power-of-two ring dimensions 2^1 ..= 2^17
+pub fn power_of_two_dims() -> Vec<usize> {
+    (1..=17).map(|k| 1usize << k).collect()
+}
+
+// ============================================================================
+// Primes
+// ============================================================================
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn pow_mod(mut b: u64, mut e: u64, m: u64) -> u64 {
+    let mut r = 1u64;
+    b %= m;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = mul_mod(r, b, m);
+        }
+        b = mul_mod(b, b, m);
+        e >>= 1;
+    }
+    r
+}
+
+/// Deterministic Miller–Rabin for all 64-bit integers
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &p in &BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in &BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Nearest primes p ≡ 1 (mod 2n) at or below and at or above `q`
+pub fn ntt_primes_around(q: u64, n: usize) -> (Option<u64>, Option<u64>) {
+    let step = 2 * n as u64;
+    // Largest value ≡ 1 (mod 2n) that is <= q
+    let start = if q >= 1 { q - (q - 1) % step } else { return (None, None) };
+
+    let mut below = None;
+    let mut p = start;
+    while p > 1 {
+        if is_prime(p) {
+            below = Some(p);
+            break;
+        }
+        p = match p.checked_sub(step) {
+            Some(v) => v,
+            None => break,
+        };
+    }
+
+    let mut above = None;
+    let mut p = if start >= q { Some(start) } else { start.checked_add(step) };
+    while let Some(v) = p {
+        if is_prime(v) {
+            above = Some(v);
+            break;
+        }
+        p = v.checked_add(step);
+    }
+    (below, above)
+}
+
+// ============================================================================
+// Snapping
+// ============================================================================
+
+/// Implementable version of a search result
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnappedResult {
+    pub n: usize,
+    pub q: u64,
+    pub sigma: f64,
+    pub estimate: SecurityEstimate,
+    /// Whether the snapped parameters still meet the search's target
+    pub secure: bool,
+    /// The unconstrained optimum that was snapped
+    pub unconstrained: SearchResult,
+    pub delta_n: i64,
+    pub delta_log_q: f64,
+    pub delta_bits: f64,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SnappedResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "SnappedResult(n={}, q={}, bits={:.1}, Δn={:+}, Δlog q={:+.2}, Δbits={:+.1}, {})",
+            self.n,
+            self.q,
+            self.estimate.classical_bits,
+            self.delta_n,
+            self.delta_log_q,
+            self.delta_bits,
+            if self.secure { "secure" } else { "BELOW TARGET" }
+        )
+    }
+}
+
+/// Round `result` to the smallest ring dimension in `ring_dims` that is
+/// >= its n, and its q to a neighbouring NTT prime for that dimension.
+///
+/// Of the primes just below and just above q, the closer one that still
+/// meets the target is taken; if neither does, the closer one is returned
+/// with `secure` unset. Returns `None` if no listed dimension is large
+/// enough or no NTT prime exists in range.
+pub fn snap(result: &SearchResult, ring_dims: &[usize], sieving: bool) -> Option<SnappedResult> {
+    let n = ring_dims.iter().copied().filter(|&d| d >= result.n).min()?;
+    let (below, above) = ntt_primes_around(result.q, n);
+    let target = result.effective_target_bits;
+
+    let log_q = (result.q as f64).log2();
+    let mut options: Vec<(u64, SecurityEstimate)> = [below, above]
+        .into_iter()
+        .flatten()
+        .map(|p| (p, estimate_core(n, p, result.sigma, sieving)))
+        .collect();
+    options.sort_by(|a, b| {
+        let da = ((a.0 as f64).log2() - log_q).abs();
+        let db = ((b.0 as f64).log2() - log_q).abs();
+        da.total_cmp(&db)
+    });
+    let pick = options
+        .iter()
+        .position(|(_, e)| e.classical_bits >= target)
+        .unwrap_or(0);
+    let (q, estimate) = options.into_iter().nth(pick)?;
+
+    Some(SnappedResult {
+        n,
+        q,
+        sigma: result.sigma,
+        secure: estimate.classical_bits >= target,
+        delta_n: n as i64 - result.n as i64,
+        delta_log_q: (q as f64).log2() - log_q,
+        delta_bits: estimate.classical_bits - result.estimate.classical_bits,
+        estimate,
+        unconstrained: result.clone(),
+    })
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Snap a search result to an implementable ring dimension and NTT prime.
+///
+/// Args:
+///     result: SearchResult from search_n / solve
+///     ring_dims: Supported ring dimensions (default: powers of two)
+///     sieving: Use aggressive sieving cost model (default: False)
+///
+/// Returns:
+///     SnappedResult with re-verified security and the deltas versus the
+///     unconstrained optimum
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "snap", signature = (result, ring_dims = None, sieving = false))]
+pub fn py_snap(result: &SearchResult, ring_dims: Option<Vec<usize>>, sieving: bool) -> PyResult<SnappedResult> {
+    let dims = ring_dims.unwrap_or_else(power_of_two_dims);
+    Ok(snap(result, &dims, sieving).ok_or_else(|| {
+        CryptoParamError::SearchFailed(format!(
+            "no supported ring dimension >= {} with an NTT prime near q={}",
+            result.n, result.q
+        ))
+    })?)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::{search_n, SecurityMargin};
+
+    #[test]
+    fn test_is_prime() {
+        assert!(is_prime(12289));
+        assert!(is_prime(3329));
+        assert!(!is_prime(7681 * 3));
+        assert!(is_prime(0xFFFF_FFFF_0000_0001)); // Goldilocks
+        assert!(!is_prime(3_215_031_751)); // strong pseudoprime to 2, 3, 5, 7
+    }
+
+    #[test]
+    fn test_ntt_primes_around() {
+        let (lo, hi) = ntt_primes_around(12289, 512);
+        assert_eq!(lo, Some(12289));
+        assert_eq!(hi, Some(12289));
+        let (lo, hi) = ntt_primes_around(12000, 256);
+        assert!(lo.unwrap() < 12000 && hi.unwrap() > 12000);
+        assert_eq!(lo.unwrap() % 512, 1);
+        assert_eq!(hi.unwrap() % 512, 1);
+    }
+
+    #[test]
+    fn test_snap_result() {
+        let r = search_n(12289, 3.0, 100.0, SecurityMargin::None, false).unwrap();
+        let s = snap(&r, &power_of_two_dims(), false).unwrap();
+        assert!(s.n.is_power_of_two() && s.n >= r.n);
+        assert_eq!(s.q % (2 * s.n as u64), 1);
+        assert!(is_prime(s.q));
+        assert_eq!(s.delta_n, s.n as i64 - r.n as i64);
+        assert_eq!(s.secure, s.estimate.classical_bits >= 100.0);
+    }
+}
@@ -0,0 +1,655 @@
+//! Python type stubs generated from the Rust sources
+//!
+//! Reads the registrations in `fn cryptoparam` of `lib.rs`, finds each
+//! `#[pyfunction]`, `#[pyclass]` and `#[pymethods]` item they name, and
+//! writes a `.pyi` with the Python names, the `signature = (...)` defaults
+//! and the argument and return types mapped from Rust. `cryptoparam.pyi`
+//! at the crate root is this output; a test keeps the two in step and,
+//! with `CRYPTOPARAM_UPDATE_STUBS` set, rewrites the file instead.
+//!
+//! This is a line-oriented reader for the way this crate writes its
+//! bindings, not a Rust parser. The `presets` and `plot` submodules are
+//! not covered.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// Argument of a function or method
+#[derive(Debug, Clone, PartialEq)]
+struct Param {
+    name: String,
+    ty: String,
+    default: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FnKind {
+    Function,
+    Method,
+    New,
+    Getter,
+    Static,
+}
+
+#[derive(Debug, Clone)]
+struct PyFn {
+    name: String,
+    kind: FnKind,
+    params: Vec<Param>,
+    ret: String,
+    doc: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PyClass {
+    name: String,
+    doc: Option<String>,
+    fields: Vec<(String, String)>,
+    methods: Vec<PyFn>,
+    /// `CompareOp` variants handled by `__richcmp__`
+    compare_ops: Vec<String>,
+}
+
+/// Items found in one source file, keyed by Rust name
+#[derive(Debug, Default)]
+struct Items {
+    functions: HashMap<String, PyFn>,
+    classes: HashMap<String, PyClass>,
+}
+
+/// `s` with string and character literals blanked and `//` comments cut,
+/// so brackets inside them are not counted
+fn code_only(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => {
+                out.push('"');
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                out.push('"');
+            }
+            '\'' if chars.get(i + 2) == Some(&'\'') => {
+                out.push_str("' '");
+                i += 2;
+            }
+            '\'' if chars.get(i + 1) == Some(&'\\') => {
+                while i + 1 < chars.len() && chars[i + 1] != '\'' {
+                    i += 1;
+                }
+                out.push_str("' '");
+                i += 1;
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => break,
+            c => out.push(c),
+        }
+        i += 1;
+    }
+    out
+}
+
+fn depth_change(s: &str, open: char, close: char) -> i64 {
+    let code = code_only(s);
+    code.matches(open).count() as i64 - code.matches(close).count() as i64
+}
+
+/// Split at commas outside brackets
+fn split_top(s: &str) -> Vec<String> {
+    let (mut parts, mut cur, mut depth) = (Vec::new(), String::new(), 0i32);
+    let mut in_str = false;
+    for c in s.chars() {
+        match c {
+            '"' => in_str = !in_str,
+            '(' | '<' | '[' | '{' if !in_str => depth += 1,
+            ')' | '>' | ']' | '}' if !in_str => depth -= 1,
+            ',' if depth == 0 && !in_str => {
+                parts.push(cur.trim().to_string());
+                cur.clear();
+                continue;
+            }
+            _ => {}
+        }
+        cur.push(c);
+    }
+    if !cur.trim().is_empty() {
+        parts.push(cur.trim().to_string());
+    }
+    parts
+}
+
+/// Text between the bracket opening at `start` and its match
+fn bracketed(s: &str, start: usize) -> &str {
+    let open = s[start..].chars().next().unwrap_or('(');
+    let close = match open {
+        '(' => ')',
+        '<' => '>',
+        '[' => ']',
+        _ => '}',
+    };
+    let (mut depth, mut in_str) = (0, false);
+    for (i, c) in s[start..].char_indices() {
+        if c == '"' {
+            in_str = !in_str;
+        } else if !in_str && c == open {
+            depth += 1;
+        } else if !in_str && c == close {
+            depth -= 1;
+            if depth == 0 {
+                return &s[start + 1..start + i];
+            }
+        }
+    }
+    &s[start + 1..]
+}
+
+/// Value of `key = "..."` in an attribute, outside any `signature = (...)`
+fn attr_string(attr: &str, key: &str) -> Option<String> {
+    let attr = match attr.find("signature") {
+        Some(i) => {
+            let open = i + attr[i..].find('(')?;
+            format!("{}{}", &attr[..i], &attr[open + bracketed(attr, open).len() + 2..])
+        }
+        None => attr.to_string(),
+    };
+    let i = attr.find(&format!("{} = \"", key))? + key.len() + 4;
+    Some(attr[i..i + attr[i..].find('"')?].to_string())
+}
+
+/// (name, default) pairs of a `signature = (...)` attribute
+fn signature(attrs: &[String]) -> Option<Vec<(String, Option<String>)>> {
+    let attr = attrs.iter().find(|a| a.starts_with("#[pyo3(") && a.contains("signature"))?;
+    let i = attr.find("signature")?;
+    let open = i + attr[i..].find('(')?;
+    Some(
+        split_top(bracketed(attr, open))
+            .into_iter()
+            .map(|p| match p.split_once('=') {
+                Some((name, default)) => (name.trim().to_string(), Some(default.trim().to_string())),
+                None => (p, None),
+            })
+            .collect(),
+    )
+}
+
+/// Python literal for a Rust default value, `...` when it is an expression
+fn python_default(rust: &str) -> String {
+    match rust {
+        "true" => "True".to_string(),
+        "false" => "False".to_string(),
+        "None" => "None".to_string(),
+        _ if rust.starts_with('"') => rust.to_string(),
+        _ if rust.parse::<f64>().is_ok() => rust.replace('_', ""),
+        _ => match rust.strip_prefix("Sigma(").and_then(|r| r.strip_suffix(')')) {
+            Some(inner) => python_default(inner),
+            None => "...".to_string(),
+        },
+    }
+}
+
+/// Python annotation for a Rust type; `classes` maps Rust to Python class
+/// names and `own` is the enclosing class, for `Self`
+fn python_type(rust: &str, classes: &HashMap<String, String>, own: Option<&str>) -> String {
+    let ty = rust.trim().trim_start_matches('&').trim();
+    let ty = ty.strip_prefix("mut ").unwrap_or(ty);
+    let ty = match ty.strip_prefix('\'') {
+        Some(rest) => rest.split_once(' ').map_or(rest, |(_, t)| t).trim(),
+        None => ty,
+    };
+    let generic = |name: &str| -> Option<Vec<String>> {
+        let rest = ty.strip_prefix(name)?.trim_start();
+        rest.starts_with('<').then(|| split_top(bracketed(rest, 0)))
+    };
+    let inner = |args: &[String], i: usize| args.get(i).map_or("Any".to_string(), |a| python_type(a, classes, own));
+    if let Some(args) = generic("PyResult").or_else(|| generic("Result")) {
+        return inner(&args, 0);
+    }
+    if let Some(args) = generic("Option") {
+        return format!("Optional[{}]", inner(&args, 0));
+    }
+    if let Some(args) = generic("Vec") {
+        return format!("List[{}]", inner(&args, 0));
+    }
+    if let Some(args) = generic("HashMap").or_else(|| generic("BTreeMap")) {
+        return format!("Dict[{}, {}]", inner(&args, 0), inner(&args, 1));
+    }
+    if let Some(args) = generic("PyRef").or_else(|| generic("PyRefMut")).or_else(|| generic("Py")) {
+        return inner(&args, args.len() - 1);
+    }
+    if ty.starts_with('(') {
+        let parts = split_top(bracketed(ty, 0));
+        if parts.is_empty() {
+            return "None".to_string();
+        }
+        let parts: Vec<String> = parts.iter().map(|p| python_type(p, classes, own)).collect();
+        return format!("Tuple[{}]", parts.join(", "));
+    }
+    if ty.starts_with('[') {
+        let elem = bracketed(ty, 0).split(';').next().unwrap_or("");
+        return format!("List[{}]", python_type(elem, classes, own));
+    }
+    match ty {
+        "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32" | "i64" | "i128" => "int".to_string(),
+        "f32" | "f64" => "float".to_string(),
+        "bool" => "bool".to_string(),
+        "str" | "String" | "&'static str" | "'static str" => "str".to_string(),
+        "PathBuf" | "std::path::PathBuf" | "Path" => "Union[str, os.PathLike]".to_string(),
+        "PyDict" => "Dict[str, Any]".to_string(),
+        "PyList" => "List[Any]".to_string(),
+        "Sigma" => "SigmaLike".to_string(),
+        "Self" => own.unwrap_or("Any").to_string(),
+        _ => {
+            let last = ty.rsplit("::").next().unwrap_or(ty);
+            classes.get(last).cloned().unwrap_or_else(|| "Any".to_string())
+        }
+    }
+}
+
+/// Argument name and Rust type
+type RustArg = (String, String);
+
+/// Parse a joined `fn` header into (name, arguments, Rust return type);
+/// receivers and `Python` tokens are dropped
+fn parse_header(header: &str) -> Option<(String, Vec<RustArg>, String)> {
+    let after = &header[header.find("fn ")? + 3..];
+    let name_end = after.find(['<', '('])?;
+    let name = after[..name_end].trim().to_string();
+    let open = name_end + after[name_end..].find('(')?;
+    let args = bracketed(after, open);
+    let rest = &after[open + args.len() + 2..];
+    let ret = match rest.find("->") {
+        Some(i) => rest[i + 2..].split(['{', ';']).next().unwrap_or("").trim().to_string(),
+        None => "()".to_string(),
+    };
+    let params = split_top(args)
+        .into_iter()
+        .filter_map(|a| {
+            let (name, ty) = a.split_once(':')?;
+            let name = name.trim().trim_start_matches("mut ").to_string();
+            let ty = ty.trim().to_string();
+            (!ty.starts_with("Python") && name != "slf").then_some((name, ty))
+        })
+        .filter(|(name, _)| !name.ends_with("self"))
+        .collect();
+    Some((name, params, ret))
+}
+
+/// Build the Python view of a function from its attributes and header
+fn py_fn(attrs: &[String], doc: &[String], header: &str, kind: FnKind) -> Option<(String, PyFn)> {
+    let (rust_name, args, ret) = parse_header(header)?;
+    let kind = if attrs.iter().any(|a| a == "#[new]") {
+        FnKind::New
+    } else if attrs.iter().any(|a| a.starts_with("#[getter")) {
+        FnKind::Getter
+    } else if attrs.iter().any(|a| a == "#[staticmethod]") {
+        FnKind::Static
+    } else {
+        kind
+    };
+    let mut name = attrs.iter().find_map(|a| attr_string(a, "name")).unwrap_or_else(|| rust_name.clone());
+    if kind == FnKind::Getter {
+        name = name.strip_prefix("get_").map_or(name.clone(), str::to_string);
+    }
+    let types: HashMap<String, String> = args.iter().cloned().collect();
+    let params = match signature(attrs) {
+        Some(sig) => sig
+            .into_iter()
+            .map(|(name, default)| Param {
+                ty: types.get(&name).cloned().unwrap_or_else(|| "Any".to_string()),
+                name,
+                default: default.map(|d| python_default(&d)),
+            })
+            .collect(),
+        // Without a signature PyO3 makes trailing Option arguments optional
+        None => {
+            let required = args.iter().rposition(|(_, ty)| !ty.starts_with("Option<")).map_or(0, |i| i + 1);
+            args.iter()
+                .enumerate()
+                .map(|(i, (name, ty))| Param {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    default: (i >= required).then(|| "None".to_string()),
+                })
+                .collect()
+        }
+    };
+    let summary = doc.iter().take_while(|l| !l.is_empty()).cloned().collect::<Vec<_>>().join(" ");
+    let doc = (!summary.is_empty()).then_some(summary);
+    Some((rust_name, PyFn { name, kind, params, ret, doc }))
+}
+
+/// `#[cfg_attr(feature = "python", X)]` read as `#[X]`
+fn unwrap_cfg_attr(attr: &str) -> String {
+    match attr.strip_prefix("#[cfg_attr(feature = \"python\", ").and_then(|a| a.strip_suffix(")]")) {
+        Some(inner) => format!("#[{}]", inner),
+        None => attr.to_string(),
+    }
+}
+
+/// Collect an attribute or header that may span lines, starting at `i`;
+/// returns the joined text and the index of its last line
+fn join_until(lines: &[&str], i: usize, done: impl Fn(&str) -> bool) -> (String, usize) {
+    let mut text = lines[i].trim().to_string();
+    let mut j = i;
+    while !done(&text) && j + 1 < lines.len() {
+        j += 1;
+        text.push(' ');
+        text.push_str(lines[j].trim());
+    }
+    (text, j)
+}
+
+fn scan(source: &str) -> Items {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut items = Items::default();
+    let (mut attrs, mut doc): (Vec<String>, Vec<String>) = (Vec::new(), Vec::new());
+    let mut depth = 0i64;
+    // (Rust class name, depth of its impl body) inside #[pymethods]
+    let mut methods_of: Option<(String, i64)> = None;
+    // Inside the body of `__richcmp__`, collecting the operators it handles
+    let mut in_richcmp = false;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        let in_methods = methods_of.as_ref().is_some_and(|(_, d)| *d == depth);
+        if depth == 0 || in_methods {
+            if let Some(d) = line.strip_prefix("///") {
+                doc.push(d.strip_prefix(' ').unwrap_or(d).trim_end().to_string());
+                i += 1;
+                continue;
+            }
+            if line.starts_with("#[") {
+                let (attr, j) = join_until(&lines, i, |t| depth_change(t, '[', ']') <= 0);
+                attrs.push(unwrap_cfg_attr(&attr));
+                i = j + 1;
+                continue;
+            }
+            let is_fn = line.starts_with("fn ") || line.starts_with("pub fn ") || line.starts_with("pub(crate) fn ");
+            if is_fn && (in_methods || attrs.iter().any(|a| a == "#[pyfunction]")) {
+                let (header, j) = join_until(&lines, i, |t| {
+                    let code = code_only(t);
+                    code.contains('{') && depth_change(&code, '(', ')') == 0 || code.ends_with(';')
+                });
+                let kind = if in_methods { FnKind::Method } else { FnKind::Function };
+                if let Some((rust_name, f)) = py_fn(&attrs, &doc, &header, kind) {
+                    in_richcmp = f.name == "__richcmp__";
+                    match &methods_of {
+                        Some((class, _)) if in_methods => {
+                            items.classes.entry(class.clone()).or_default().methods.push(f);
+                        }
+                        _ => {
+                            items.functions.insert(rust_name, f);
+                        }
+                    }
+                }
+                depth += depth_change(&header, '{', '}');
+                attrs.clear();
+                doc.clear();
+                i = j + 1;
+                continue;
+            }
+            if line.starts_with("pub struct ") && attrs.iter().any(|a| a.starts_with("#[pyclass")) {
+                let rust_name = line["pub struct ".len()..].split([' ', '{', '(', '<']).next().unwrap_or("").to_string();
+                let py_name = attrs.iter().find_map(|a| attr_string(a, "name")).unwrap_or_else(|| rust_name.clone());
+                let summary = doc.iter().take_while(|l| !l.is_empty()).cloned().collect::<Vec<_>>().join(" ");
+                let get_all = attrs.iter().any(|a| a.starts_with("#[pyclass") && a.contains("get_all"));
+                let class = items.classes.entry(rust_name).or_default();
+                class.name = py_name;
+                class.doc = (!summary.is_empty()).then_some(summary);
+                let mut field_attrs: Vec<String> = Vec::new();
+                let mut j = i + 1;
+                while j < lines.len() && line.ends_with('{') {
+                    let l = lines[j].trim();
+                    if l.starts_with('}') {
+                        break;
+                    }
+                    if l.starts_with("#[") {
+                        field_attrs.push(unwrap_cfg_attr(l));
+                    } else if let Some(field) = l.strip_prefix("pub ").filter(|f| f.contains(':')) {
+                        if get_all || field_attrs.iter().any(|a| a.starts_with("#[pyo3(get")) {
+                            let (name, ty) = field.split_once(':').unwrap_or_default();
+                            class.fields.push((name.trim().to_string(), ty.trim().trim_end_matches(',').to_string()));
+                        }
+                        field_attrs.clear();
+                    } else if !l.starts_with("///") {
+                        field_attrs.clear();
+                    }
+                    j += 1;
+                }
+                attrs.clear();
+                doc.clear();
+                i = j + 1;
+                continue;
+            }
+            if line.starts_with("impl ") && attrs.iter().any(|a| a == "#[pymethods]") {
+                let class = line["impl ".len()..].split([' ', '{', '<']).next().unwrap_or("").to_string();
+                methods_of = Some((class, depth + 1));
+            }
+            if !line.is_empty() {
+                attrs.clear();
+                doc.clear();
+            }
+        }
+        if let (true, Some((class, _))) = (in_richcmp, &methods_of) {
+            let ops = &mut items.classes.entry(class.clone()).or_default().compare_ops;
+            // `op.matches(ordering)` supports every operator
+            if line.contains("op.matches(") {
+                ops.clear();
+                ops.extend(["Eq", "Ne", "Lt", "Le", "Gt", "Ge"].map(String::from));
+            }
+            for (i, _) in line.match_indices("CompareOp::") {
+                let op: String = line[i + "CompareOp::".len()..].chars().take_while(char::is_ascii_alphabetic).collect();
+                if !ops.contains(&op) {
+                    ops.push(op);
+                }
+            }
+        }
+        depth += depth_change(line, '{', '}');
+        if methods_of.as_ref().is_some_and(|(_, d)| depth <= *d) {
+            in_richcmp = false;
+        }
+        if methods_of.as_ref().is_some_and(|(_, d)| depth < *d) {
+            methods_of = None;
+        }
+        i += 1;
+    }
+    items
+}
+
+/// `create_exception!` classes as (name, Python base) in source order
+fn exceptions(source: &str) -> Vec<(String, String)> {
+    let code = source.replace('\n', " ");
+    code.match_indices("create_exception!(")
+        .filter_map(|(i, m)| {
+            let args = split_top(bracketed(&code, i + m.len() - 1));
+            let base = args.get(2)?.rsplit("::").next()?.trim();
+            let base = base.strip_prefix("Py").filter(|b| b.ends_with("Error") || b.ends_with("Warning")).unwrap_or(base);
+            Some((args.get(1)?.to_string(), base.to_string()))
+        })
+        .collect()
+}
+
+/// Registrations in `fn cryptoparam`, in order: (is class, module, name)
+fn registrations(lib: &str) -> Vec<(bool, String, String)> {
+    let start = lib.find("fn cryptoparam(").unwrap_or(0);
+    let body = &lib[start..];
+    let body = &body[..body.find("\n}").unwrap_or(body.len())];
+    body.lines()
+        .filter_map(|l| {
+            let l = l.trim();
+            let (is_class, path) = if let Some(i) = l.find("add_class::<") {
+                (true, &l[i + "add_class::<".len()..l.find(">()")?])
+            } else if let Some(i) = l.find("wrap_pyfunction!(") {
+                (false, &l[i + "wrap_pyfunction!(".len()..l.find(", m)")?])
+            } else if l.starts_with("errors::register") {
+                (false, "errors::*")
+            } else {
+                return None;
+            };
+            let (module, name) = path.rsplit_once("::").unwrap_or(("lib", path));
+            Some((is_class, module.to_string(), name.to_string()))
+        })
+        .collect()
+}
+
+fn write_fn(out: &mut String, f: &PyFn, classes: &HashMap<String, String>, own: Option<&str>, indent: &str) {
+    let mut params: Vec<String> = Vec::new();
+    match f.kind {
+        FnKind::Function | FnKind::Static => {}
+        _ => params.push("self".to_string()),
+    }
+    for p in &f.params {
+        if p.name == "*" {
+            params.push(p.name.clone());
+            continue;
+        }
+        let ty = python_type(&p.ty, classes, own);
+        params.push(match &p.default {
+            Some(d) => format!("{}: {} = {}", p.name, ty, d),
+            None => format!("{}: {}", p.name, ty),
+        });
+    }
+    let (name, ret) = match f.kind {
+        FnKind::New => ("__init__".to_string(), "None".to_string()),
+        _ => (f.name.clone(), python_type(&f.ret, classes, own)),
+    };
+    match f.kind {
+        FnKind::Getter => out.push_str(&format!("{}@property\n", indent)),
+        FnKind::Static => out.push_str(&format!("{}@staticmethod\n", indent)),
+        _ => {}
+    }
+    out.push_str(&format!("{}def {}({}) -> {}:", indent, name, params.join(", "), ret));
+    match &f.doc {
+        Some(doc) => out.push_str(&format!("\n{}    \"\"\"{}\"\"\"\n", indent, doc.replace('"', "'"))),
+        None => out.push_str(" ...\n"),
+    }
+}
+
+/// Stub text for the module defined under `src`
+pub fn generate(src: &Path) -> io::Result<String> {
+    let mut by_module: HashMap<String, Items> = HashMap::new();
+    let mut class_names: HashMap<String, String> = HashMap::new();
+    let mut sources: HashMap<String, String> = HashMap::new();
+    for entry in std::fs::read_dir(src)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "rs") {
+            let module = path.file_stem().and_then(|s| s.to_str()).unwrap_or("").to_string();
+            let text = std::fs::read_to_string(&path)?;
+            let items = scan(&text);
+            for (rust, class) in &items.classes {
+                if !class.name.is_empty() {
+                    class_names.insert(rust.clone(), class.name.clone());
+                }
+            }
+            sources.insert(module.clone(), text);
+            by_module.insert(module, items);
+        }
+    }
+    let lib = sources.get("lib").ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "lib.rs"))?;
+    let mut out = String::from(
+        "# Type stubs for the cryptoparam extension module.\n\
+         #\n\
+         # Generated from the Rust sources by the stubgen module; regenerate with\n\
+         # `CRYPTOPARAM_UPDATE_STUBS=1 cargo test committed_stubs`, do not edit by hand.\n\n\
+         import os\n\
+         from decimal import Decimal\n\
+         from fractions import Fraction\n\
+         from typing import Any, Dict, List, Optional, Tuple, Union\n\n\
+         # σ arguments: any real number, or a string such as \"3.19\" or \"16/5\"\n\
+         SigmaLike = Union[float, Fraction, Decimal, str]\n",
+    );
+    for (is_class, module, name) in registrations(lib) {
+        out.push('\n');
+        if name == "*" {
+            for (exc, base) in exceptions(sources.get(&module).map_or("", String::as_str)) {
+                out.push_str(&format!("class {}({}): ...\n", exc, base));
+            }
+            continue;
+        }
+        let Some(items) = by_module.get(&module) else { continue };
+        if !is_class {
+            if let Some(f) = items.functions.get(&name) {
+                write_fn(&mut out, f, &class_names, None, "");
+            }
+            continue;
+        }
+        let Some(class) = items.classes.get(&name) else { continue };
+        out.push_str(&format!("class {}:\n", class.name));
+        if let Some(doc) = &class.doc {
+            out.push_str(&format!("    \"\"\"{}\"\"\"\n\n", doc.replace('"', "'")));
+        }
+        for (field, ty) in &class.fields {
+            out.push_str(&format!("    {}: {}\n", field, python_type(ty, &class_names, Some(&class.name))));
+        }
+        for f in &class.methods {
+            if f.name != "__richcmp__" {
+                write_fn(&mut out, f, &class_names, Some(&class.name), "    ");
+                continue;
+            }
+            for op in &class.compare_ops {
+                let (dunder, other) = match op.as_str() {
+                    "Eq" => ("__eq__", "object"),
+                    "Ne" => ("__ne__", "object"),
+                    "Lt" => ("__lt__", class.name.as_str()),
+                    "Le" => ("__le__", class.name.as_str()),
+                    "Gt" => ("__gt__", class.name.as_str()),
+                    "Ge" => ("__ge__", class.name.as_str()),
+                    _ => continue,
+                };
+                out.push_str(&format!("    def {}(self, other: {}) -> bool: ...\n", dunder, other));
+            }
+        }
+        if class.fields.is_empty() && class.methods.is_empty() && class.doc.is_none() {
+            out.push_str("    ...\n");
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_types() {
+        let classes = HashMap::from([("Warning".to_string(), "EstimateWarning".to_string())]);
+        let ty = |t: &str| python_type(t, &classes, Some("LweParams"));
+        assert_eq!(ty("PyResult<Vec<Option<u64>>>"), "List[Optional[int]]");
+        assert_eq!(ty("&'py PyAny"), "Any");
+        assert_eq!(ty("Vec<crate::warnings::Warning>"), "List[EstimateWarning]");
+        assert_eq!(ty("PyResult<Self>"), "LweParams");
+        assert_eq!(ty("(usize, f64)"), "Tuple[int, float]");
+        assert_eq!(ty("PyResult<()>"), "None");
+        assert_eq!(python_default("false"), "False");
+        assert_eq!(python_default("DEFAULT_MAX_POINTS"), "...");
+        assert_eq!((ty("Option<Sigma>"), python_default("Sigma(3.19)")), ("Optional[SigmaLike]".to_string(), "3.19".to_string()));
+    }
+
+    #[test]
+    fn test_committed_stubs_are_current() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let stubs = generate(&src).unwrap();
+        assert!(stubs.contains(
+            "def estimate_lwe(n: int, q: int, sigma: SigmaLike, sieving: bool = False, trace: bool = False, samples: Optional[int] = None) -> SecurityEstimate:"
+        ));
+        assert!(stubs.contains("class InvalidParameters(CryptoParamError): ..."));
+        assert!(stubs.contains("class EstimateWarning:"));
+        if std::env::var_os("CRYPTOPARAM_UPDATE_STUBS").is_some() {
+            std::fs::write(Path::new(env!("CARGO_MANIFEST_DIR")).join("cryptoparam.pyi"), &stubs).unwrap();
+            return;
+        }
+        assert_eq!(
+            stubs,
+            include_str!("../cryptoparam.pyi"),
+            "cryptoparam.pyi is stale; run `CRYPTOPARAM_UPDATE_STUBS=1 cargo test committed_stubs`"
+        );
+    }
+}
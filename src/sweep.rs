@@ -0,0 +1,665 @@
+//! Grid sweeps over parameter ranges
+//!
+//! Estimates every (n, q, σ) combination of a grid in parallel.
+
+#[cfg(feature = "python")]
+use crate::attacks::{lwe_attacks_with, memory_bits, plugin_costs, AttackCost, AttackOutcome, LWE_ATTACKS};
+use crate::checkpoint::{point_key, Checkpoint, PointKey};
+use crate::cost_model::CostModel;
+use crate::error::CryptoParamError;
+#[cfg(feature = "python")]
+use crate::parallel::with_threads;
+use crate::progress::{Progress, ProgressFn};
+#[cfg(feature = "python")]
+use crate::progress::PyProgress;
+use crate::{estimate_core, SecurityEstimate};
+#[cfg(feature = "python")]
+use crate::secret::SecretDist;
+#[cfg(feature = "python")]
+use crate::Sigma;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// Grid points computed between checkpoint flushes and progress reports
+pub const CHUNK_SIZE: usize = 64;
+
+// ============================================================================
+// Core Sweep
+// ============================================================================
+
+/// Estimate every point of the n × q × σ grid.
+///
+/// Rows come back in grid order (n outermost, σ innermost).
+pub fn sweep(
+    n_values: &[usize],
+    q_values: &[u64],
+    sigma_values: &[f64],
+    sieving: bool,
+) -> Vec<SecurityEstimate> {
+    grid(n_values, q_values, sigma_values)
+        .par_iter()
+        .map(|&(n, q, sigma)| estimate_core(n, q, sigma, sieving))
+        .collect()
+}
+
+/// Estimate element-wise rows (n[i], q[i], σ[i]) rather than a grid;
+/// an input of length 1 is repeated to the length of the others
+pub fn estimate_batch(
+    n_values: &[usize],
+    q_values: &[u64],
+    sigma_values: &[f64],
+    sieving: bool,
+) -> Result<Vec<SecurityEstimate>, CryptoParamError> {
+    let len = n_values.len().max(q_values.len()).max(sigma_values.len());
+    for (name, l) in [("n", n_values.len()), ("q", q_values.len()), ("sigma", sigma_values.len())] {
+        if l != len && l != 1 {
+            return Err(CryptoParamError::InvalidOption(format!("{} has {} values; expected {} or 1", name, l, len)));
+        }
+    }
+    let at = |i: usize| {
+        let pick = |l: usize| if l == 1 { 0 } else { i };
+        (n_values[pick(n_values.len())], q_values[pick(q_values.len())], sigma_values[pick(sigma_values.len())])
+    };
+    Ok((0..len)
+        .into_par_iter()
+        .map(|i| {
+            let (n, q, sigma) = at(i);
+            estimate_core(n, q, sigma, sieving)
+        })
+        .collect())
+}
+
+/// [`sweep`] reporting progress after every [`CHUNK_SIZE`] points
+pub fn sweep_with_progress(
+    n_values: &[usize],
+    q_values: &[u64],
+    sigma_values: &[f64],
+    sieving: bool,
+    progress: ProgressFn,
+) -> Vec<SecurityEstimate> {
+    let grid = grid(n_values, q_values, sigma_values);
+    let mut results = HashMap::new();
+    run_chunks(&grid, sieving, &mut results, None, progress)
+        .expect("no checkpoint, no I/O");
+    collect(&grid, &results)
+}
+
+/// [`sweep`] that records progress in a checkpoint file.
+///
+/// With `resume` set and `path` present, points already in the file are
+/// reused; otherwise the file is started afresh. Output is identical to an
+/// uninterrupted [`sweep`]. `model` is the cost model the caller will
+/// reprice the rows with, recorded so a resume under another is refused.
+#[allow(clippy::too_many_arguments)]
+pub fn sweep_checkpointed(
+    n_values: &[usize],
+    q_values: &[u64],
+    sigma_values: &[f64],
+    sieving: bool,
+    model: Option<&CostModel>,
+    path: &Path,
+    resume: bool,
+    progress: ProgressFn,
+) -> io::Result<Vec<SecurityEstimate>> {
+    let grid = grid(n_values, q_values, sigma_values);
+    let (mut ckpt, mut results) = Checkpoint::open(path, sieving, model, resume)?;
+    run_chunks(&grid, sieving, &mut results, Some(&mut ckpt), progress)?;
+    Ok(collect(&grid, &results))
+}
+
+/// Evaluate the grid points missing from `results`, chunk by chunk
+fn run_chunks(
+    grid: &[(usize, u64, f64)],
+    sieving: bool,
+    results: &mut HashMap<PointKey, SecurityEstimate>,
+    mut ckpt: Option<&mut Checkpoint>,
+    progress: ProgressFn,
+) -> io::Result<()> {
+    let todo: Vec<(usize, u64, f64)> = grid
+        .iter()
+        .filter(|&&(n, q, sigma)| !results.contains_key(&point_key(n, q, sigma)))
+        .copied()
+        .collect();
+    let mut best: Option<SecurityEstimate> = results
+        .values()
+        .max_by(|a, b| a.classical_bits.total_cmp(&b.classical_bits))
+        .cloned();
+    let report = |evaluated: usize, best: &Option<SecurityEstimate>| {
+        progress(&Progress { evaluated, total: Some(grid.len()), best: best.clone() })
+    };
+    let mut evaluated = grid.len() - todo.len();
+    report(evaluated, &best);
+
+    for chunk in todo.chunks(CHUNK_SIZE) {
+        let rows: Vec<SecurityEstimate> = chunk
+            .par_iter()
+            .map(|&(n, q, sigma)| estimate_core(n, q, sigma, sieving))
+            .collect();
+        if let Some(c) = ckpt.as_deref_mut() {
+            c.append(&rows)?;
+        }
+        for r in rows {
+            if best.as_ref().is_none_or(|b| r.classical_bits > b.classical_bits) {
+                best = Some(r.clone());
+            }
+            results.insert(point_key(r.n, r.q, r.sigma), r);
+        }
+        evaluated += chunk.len();
+        report(evaluated, &best);
+    }
+    Ok(())
+}
+
+fn collect(
+    grid: &[(usize, u64, f64)],
+    results: &HashMap<PointKey, SecurityEstimate>,
+) -> Vec<SecurityEstimate> {
+    grid.iter()
+        .map(|&(n, q, sigma)| results[&point_key(n, q, sigma)].clone())
+        .collect()
+}
+
+/// Column header of [`to_csv`]; stable across releases
+pub const CSV_COLUMNS: &str = "n,q,log2_q,sigma,attack,beta,m,d,classical_bits";
+
+/// Column appended to [`CSV_COLUMNS`] when rows carry quantum bits
+pub const CSV_QUANTUM_COLUMN: &str = "quantum_bits";
+
+/// One CSV row per estimate under [`CSV_COLUMNS`], plus
+/// [`CSV_QUANTUM_COLUMN`] when the rows have it; infinite bits are `inf`
+pub fn to_csv(rows: &[SecurityEstimate]) -> String {
+    let mut out = String::from(CSV_COLUMNS);
+    if rows.iter().any(|r| r.quantum_bits.is_some()) {
+        out.push(',');
+        out.push_str(CSV_QUANTUM_COLUMN);
+    }
+    out.push('\n');
+    for r in rows {
+        out.push_str(&csv_row(r));
+        out.push('\n');
+    }
+    out
+}
+
+/// A single [`to_csv`] row, without the newline
+pub fn csv_row(r: &SecurityEstimate) -> String {
+    let mut row = format!(
+        "{},{},{:.4},{},{},{},{},{},{:.3}",
+        r.n,
+        r.q,
+        r.log2_q,
+        r.sigma,
+        r.attack,
+        r.beta.map_or(String::new(), |b| b.to_string()),
+        r.m,
+        r.d,
+        r.classical_bits
+    );
+    if let Some(bits) = r.quantum_bits {
+        row.push_str(&format!(",{:.3}", bits));
+    }
+    row
+}
+
+/// JSON array with one object per estimate, keyed by [`CSV_COLUMNS`] plus
+/// "provenance" and "warnings"; infinite bits are `null`
+pub fn to_json(rows: &[SecurityEstimate]) -> String {
+    let rows: Vec<serde_json::Value> = rows.iter().map(json_row).collect();
+    serde_json::to_string_pretty(&rows).unwrap_or_default() + "\n"
+}
+
+/// A single [`to_json`] object
+pub fn json_row(r: &SecurityEstimate) -> serde_json::Value {
+    let mut row = serde_json::json!({
+        "n": r.n,
+        "q": r.q,
+        "log2_q": r.log2_q,
+        "sigma": r.sigma,
+        "attack": r.attack,
+        "beta": r.beta,
+        "m": r.m,
+        "d": r.d,
+        "classical_bits": r.classical_bits.is_finite().then_some(r.classical_bits),
+    });
+    if let Some(noise) = &r.noise {
+        row["noise"] = noise.as_str().into();
+    }
+    if let Some(bits) = r.quantum_bits {
+        row["quantum_bits"] = bits.is_finite().then_some(bits).into();
+    }
+    let p = &r.provenance;
+    row["provenance"] = serde_json::json!({
+        "crate_version": p.crate_version,
+        "cost_model": p.cost_model,
+        "attacks": p.attacks,
+        "timestamp": p.timestamp,
+    });
+    row["warnings"] = r.warnings.iter().map(|w| serde_json::json!({ "code": w.code, "message": w.message })).collect();
+    row
+}
+
+fn grid(n_values: &[usize], q_values: &[u64], sigma_values: &[f64]) -> Vec<(usize, u64, f64)> {
+    let mut grid = Vec::with_capacity(n_values.len() * q_values.len() * sigma_values.len());
+    for &n in n_values {
+        for &q in q_values {
+            for &sigma in sigma_values {
+                grid.push((n, q, sigma));
+            }
+        }
+    }
+    grid
+}
+
+// ============================================================================
+// Python API
+// ============================================================================
+
+/// Table of sweep results, one row per grid point
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SweepTable {
+    pub rows: Vec<SecurityEstimate>,
+}
+
+/// Column-oriented dict of `rows`, one list per field
+#[cfg(feature = "python")]
+fn columns_dict<'py>(py: Python<'py>, rows: &[SecurityEstimate]) -> PyResult<&'py PyDict> {
+    let d = PyDict::new(py);
+    d.set_item("n", rows.iter().map(|r| r.n).collect::<Vec<_>>())?;
+    d.set_item("q", rows.iter().map(|r| r.q).collect::<Vec<_>>())?;
+    d.set_item("log2_q", rows.iter().map(|r| r.log2_q).collect::<Vec<_>>())?;
+    d.set_item("sigma", rows.iter().map(|r| r.sigma).collect::<Vec<_>>())?;
+    d.set_item("classical_bits", rows.iter().map(|r| r.classical_bits).collect::<Vec<_>>())?;
+    d.set_item("beta", rows.iter().map(|r| r.beta).collect::<Vec<_>>())?;
+    d.set_item("d", rows.iter().map(|r| r.d).collect::<Vec<_>>())?;
+    d.set_item("m", rows.iter().map(|r| r.m).collect::<Vec<_>>())?;
+    d.set_item("attack", rows.iter().map(|r| r.attack.clone()).collect::<Vec<_>>())?;
+    Ok(d)
+}
+
+/// Every attack priced on the parameters of `r`: the built-in ones, the
+/// headline taken from `r` itself, then the registered ones
+#[cfg(feature = "python")]
+fn attack_breakdown(py: Python, r: &SecurityEstimate) -> PyResult<Vec<AttackOutcome>> {
+    let secret = match &r.secret {
+        Some(s) => SecretDist::parse(s).ok(),
+        None => Some(SecretDist::Gaussian),
+    };
+    let model = crate::cost_model::by_id(&r.provenance.cost_model)
+        .unwrap_or_else(|| crate::cost_model::for_sieving(r.provenance.sieving()));
+    let mut outcomes = Vec::new();
+    for (i, attack) in LWE_ATTACKS.iter().enumerate() {
+        if *attack == r.attack {
+            outcomes.push(match r.beta {
+                Some(beta) => AttackOutcome::Feasible(AttackCost {
+                    attack: r.attack.clone(),
+                    bits: r.classical_bits,
+                    beta,
+                    d: r.d,
+                    samples: r.m,
+                    memory_bits: memory_bits(beta),
+                }),
+                None => AttackOutcome::Infeasible { attack: r.attack.clone() },
+            });
+        } else if let Some(secret) = secret {
+            // Only the secrets the built-in attacks model can be redone
+            let mut o = lwe_attacks_with(r.n, r.log2_q, r.sigma, secret, r.max_samples, r.provenance.sieving()).remove(i);
+            o.reprice(model);
+            outcomes.push(o);
+        }
+    }
+    let config = PyDict::new(py);
+    config.set_item("cost_model", model.name)?;
+    config.set_item("secret", r.secret.as_deref().unwrap_or("gaussian"))?;
+    config.set_item("m_max", r.max_samples)?;
+    let params = crate::LweParams { n: r.n, q: r.q, sigma: r.sigma };
+    outcomes.extend(plugin_costs(py, params, config, None)?.into_iter().map(AttackOutcome::Feasible));
+    Ok(outcomes)
+}
+
+/// [`columns_dict`] with each estimate expanded into one row per attack,
+/// `headline` marking the attack that sets the estimate's security
+#[cfg(feature = "python")]
+fn attack_columns_dict<'py>(py: Python<'py>, rows: &[SecurityEstimate]) -> PyResult<&'py PyDict> {
+    let mut expanded: Vec<(&SecurityEstimate, AttackOutcome)> = Vec::new();
+    for r in rows {
+        expanded.extend(attack_breakdown(py, r)?.into_iter().map(|o| (r, o)));
+    }
+    let d = PyDict::new(py);
+    d.set_item("n", expanded.iter().map(|(r, _)| r.n).collect::<Vec<_>>())?;
+    d.set_item("q", expanded.iter().map(|(r, _)| r.q).collect::<Vec<_>>())?;
+    d.set_item("log2_q", expanded.iter().map(|(r, _)| r.log2_q).collect::<Vec<_>>())?;
+    d.set_item("sigma", expanded.iter().map(|(r, _)| r.sigma).collect::<Vec<_>>())?;
+    d.set_item("attack", expanded.iter().map(|(_, o)| o.attack().to_string()).collect::<Vec<_>>())?;
+    d.set_item("classical_bits", expanded.iter().map(|(_, o)| o.bits()).collect::<Vec<_>>())?;
+    d.set_item("beta", expanded.iter().map(|(_, o)| o.beta()).collect::<Vec<_>>())?;
+    d.set_item("d", expanded.iter().map(|(_, o)| o.cost().map(|c| c.d)).collect::<Vec<_>>())?;
+    d.set_item("m", expanded.iter().map(|(_, o)| o.cost().map(|c| c.samples)).collect::<Vec<_>>())?;
+    d.set_item("headline", expanded.iter().map(|(r, o)| o.attack() == r.attack).collect::<Vec<_>>())?;
+    Ok(d)
+}
+
+/// DataFrame with one row per (parameter set, attack); pandas is imported
+/// lazily so it stays an optional dependency
+#[cfg(feature = "python")]
+fn dataframe<'py>(py: Python<'py>, rows: &[SecurityEstimate]) -> PyResult<&'py PyAny> {
+    let pandas = py
+        .import("pandas")
+        .map_err(|_| pyo3::exceptions::PyImportError::new_err("to_pandas needs pandas (pip install pandas)"))?;
+    pandas.getattr("DataFrame")?.call1((attack_columns_dict(py, rows)?,))
+}
+
+/// `pyarrow` or one of its submodules, imported lazily like pandas
+#[cfg(feature = "python")]
+fn pyarrow<'py>(py: Python<'py>, module: &str) -> PyResult<&'py PyModule> {
+    py.import(module)
+        .map_err(|_| pyo3::exceptions::PyImportError::new_err("Arrow export needs pyarrow (pip install pyarrow)"))
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SweepTable {
+    /// Column-oriented view, e.g. for `pandas.DataFrame(table.columns())`
+    fn columns<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        columns_dict(py, &self.rows)
+    }
+
+    /// pandas DataFrame with one row per parameter set and attack, the
+    /// `headline` column marking the attack behind each row's estimate
+    fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        dataframe(py, &self.rows)
+    }
+
+    /// pyarrow Table with the columns of `columns()`
+    fn to_arrow<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        pyarrow(py, "pyarrow")?.getattr("table")?.call1((columns_dict(py, &self.rows)?,))
+    }
+
+    /// Write the table as Parquet to `path`
+    fn to_parquet(&self, py: Python, path: std::path::PathBuf) -> PyResult<()> {
+        let table = self.to_arrow(py)?;
+        pyarrow(py, "pyarrow.parquet")?.getattr("write_table")?.call1((table, path))?;
+        Ok(())
+    }
+
+    /// CSV text with stable column names; also written to `path` if given
+    #[pyo3(signature = (path = None))]
+    fn to_csv(&self, path: Option<std::path::PathBuf>) -> PyResult<String> {
+        let text = to_csv(&self.rows);
+        if let Some(path) = path {
+            std::fs::write(&path, &text).map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+        }
+        Ok(text)
+    }
+
+    /// GitHub-flavored markdown table
+    fn to_markdown(&self) -> String {
+        crate::report::markdown_table(&self.rows, None)
+    }
+
+    fn __len__(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn __getitem__(&self, idx: isize) -> PyResult<SecurityEstimate> {
+        let len = self.rows.len() as isize;
+        let i = if idx < 0 { idx + len } else { idx };
+        if i < 0 || i >= len {
+            return Err(pyo3::exceptions::PyIndexError::new_err("row index out of range"));
+        }
+        Ok(self.rows[i as usize].clone())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SweepTable({} rows)", self.rows.len())
+    }
+
+    /// Jupyter rich display
+    fn _repr_html_(&self) -> String {
+        crate::html::rows_html(&self.rows)
+    }
+}
+
+/// Values of one `estimate_lwe_batch` argument: a scalar, or any sequence
+/// such as a list or a numpy array
+#[cfg(feature = "python")]
+fn batch_values<'py, T: FromPyObject<'py>>(obj: &'py PyAny) -> PyResult<Vec<T>> {
+    if let Ok(v) = obj.extract::<T>() {
+        return Ok(vec![v]);
+    }
+    obj.iter()?.map(|v| v?.extract::<T>()).collect()
+}
+
+/// Estimate many parameter sets element-wise in one call.
+///
+/// Where `sweep` takes the grid of all combinations, this pairs up the
+/// i-th n, q and sigma, as numpy arithmetic would; a scalar or a length-1
+/// input is repeated. The estimates run in parallel in Rust with the GIL
+/// released.
+///
+/// Args:
+///     n: LWE dimensions (sequence, numpy array or scalar)
+///     q: Moduli (sequence, numpy array or scalar)
+///     sigma: Error standard deviations (sequence, numpy array or scalar)
+///     sieving: Use aggressive sieving cost model (default: False)
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     Dict of columns n, q, log2_q, sigma, classical_bits, beta, d, m,
+///     attack; numpy arrays when numpy is installed, lists otherwise
+///
+/// Example:
+///     >>> r = estimate_lwe_batch([512, 768, 1024], 12289, 3.2)
+///     >>> len(r["classical_bits"])
+///     3
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "estimate_lwe_batch", signature = (n, q, sigma, sieving = false, n_threads = None))]
+pub fn py_estimate_lwe_batch<'py>(
+    py: Python<'py>,
+    n: &'py PyAny,
+    q: &'py PyAny,
+    sigma: &'py PyAny,
+    sieving: bool,
+    n_threads: Option<usize>,
+) -> PyResult<&'py PyDict> {
+    let (n, q, sigma): (Vec<usize>, Vec<u64>, Vec<f64>) = (batch_values(n)?, batch_values(q)?, batch_values(sigma)?);
+    if let Some(i) = n.iter().position(|&n| n == 0) {
+        return Err(crate::errors::InvalidParameters::new_err(format!("n[{}] must be positive", i)));
+    }
+    if let Some(i) = q.iter().position(|&q| q < 2) {
+        return Err(crate::errors::InvalidParameters::new_err(format!("q[{}] must be >= 2", i)));
+    }
+    if let Some(i) = sigma.iter().position(|&s| s <= 0.0) {
+        return Err(crate::errors::InvalidParameters::new_err(format!("sigma[{}] must be positive", i)));
+    }
+    let mut rows = py
+        .allow_threads(|| with_threads(n_threads, || estimate_batch(&n, &q, &sigma, sieving)))?;
+    rows.iter_mut().for_each(|r| crate::defaults::apply(r, sieving));
+    crate::warnings::emit(py, &rows)?;
+    let columns = columns_dict(py, &rows)?;
+    // numpy stays optional, like pandas
+    if let Ok(numpy) = py.import("numpy") {
+        let asarray = numpy.getattr("asarray")?;
+        for (name, values) in columns.iter() {
+            columns.set_item(name, asarray.call1((values,))?)?;
+        }
+    }
+    Ok(columns)
+}
+
+/// Convert a list of estimates to a pandas DataFrame.
+///
+/// Args:
+///     estimates: List of SecurityEstimate
+///
+/// Returns:
+///     DataFrame with one row per parameter set and attack; columns n, q,
+///     log2_q, sigma, attack, classical_bits, beta, d, m and headline, the
+///     last true for the attack that sets the estimate's security
+///
+/// Raises:
+///     ImportError: pandas is not installed
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "to_pandas")]
+pub fn py_to_pandas<'py>(py: Python<'py>, estimates: Vec<SecurityEstimate>) -> PyResult<&'py PyAny> {
+    dataframe(py, &estimates)
+}
+
+/// Estimate security across a parameter grid.
+///
+/// Args:
+///     n_range: Iterable of LWE dimensions
+///     q_range: Iterable of moduli
+///     sigma_range: Iterable of error standard deviations
+///     sieving: Use aggressive sieving cost model (default: False)
+///     checkpoint: Path of a checkpoint file to record progress in (default: None)
+///     resume: Reuse points already in `checkpoint` (default: False)
+///     progress: Callable invoked as progress(done, total, best) after each
+///         chunk of points, best being the most secure estimate so far
+///     n_threads: Worker threads, None for one per core (default: None)
+///
+/// Returns:
+///     SweepTable with one row per (n, q, sigma) combination
+///
+/// Example:
+///     >>> from cryptoparam import sweep
+///     >>> t = sweep(range(256, 1025, 256), [12289], [3.19, 8.0])
+///     >>> len(t)  # 8
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(
+    name = "sweep",
+    signature = (
+        n_range, q_range, sigma_range, sieving = false,
+        checkpoint = None, resume = false, progress = None, n_threads = None
+    )
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_sweep(
+    py: Python,
+    n_range: Vec<usize>,
+    q_range: Vec<u64>,
+    sigma_range: Vec<Sigma>,
+    sieving: bool,
+    checkpoint: Option<std::path::PathBuf>,
+    resume: bool,
+    progress: Option<PyObject>,
+    n_threads: Option<usize>,
+) -> PyResult<SweepTable> {
+    let sigma_range = sigma_range.into_iter().map(|s| s.0).collect::<Vec<f64>>();
+    n_range.iter().try_for_each(|&n| crate::error::check_dimension(n))?;
+    q_range.iter().try_for_each(|&q| crate::error::check_modulus(q))?;
+    sigma_range.iter().try_for_each(|&s| crate::error::check_sigma(s))?;
+    let progress = PyProgress::new(progress);
+    let report = |p: &Progress| progress.report(p);
+    let mut rows = py.allow_threads(|| {
+        with_threads(n_threads, || match &checkpoint {
+            Some(path) => {
+                sweep_checkpointed(&n_range, &q_range, &sigma_range, sieving, None, path, resume, &report)
+            }
+            None if progress.is_set() => {
+                Ok(sweep_with_progress(&n_range, &q_range, &sigma_range, sieving, &report))
+            }
+            None => Ok(sweep(&n_range, &q_range, &sigma_range, sieving)),
+        })
+    })
+    .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    progress.finish()?;
+    rows.iter_mut().for_each(|r| crate::defaults::apply(r, sieving));
+    crate::warnings::emit(py, &rows)?;
+    Ok(SweepTable { rows })
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::no_progress;
+
+    #[test]
+    fn test_batch_pairs_elementwise() {
+        let rows = estimate_batch(&[128, 256], &[7681], &[4.0, 8.0], false).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[1].n, rows[1].q, rows[1].sigma), (256, 7681, 8.0));
+        assert_eq!(rows[1].classical_bits, estimate_core(256, 7681, 8.0, false).classical_bits);
+        assert!(estimate_batch(&[128, 256], &[7681, 12289, 3329], &[4.0], false).unwrap_err().to_string().contains("n has 2 values; expected 3 or 1"));
+    }
+
+    #[test]
+    fn test_sweep_grid_order() {
+        let rows = sweep(&[128, 256], &[7681, 12289], &[4.0, 8.0], false);
+        assert_eq!(rows.len(), 8);
+        assert_eq!((rows[0].n, rows[0].q, rows[0].sigma), (128, 7681, 4.0));
+        assert_eq!((rows[7].n, rows[7].q, rows[7].sigma), (256, 12289, 8.0));
+        assert_eq!(rows[5].beta, estimate_core(256, 7681, 8.0, false).beta);
+    }
+
+    #[test]
+    fn test_sweep_progress_reports() {
+        let seen = std::sync::Mutex::new(Vec::new());
+        let ns: Vec<usize> = (100..100 + CHUNK_SIZE + 1).collect();
+        sweep_with_progress(&ns, &[7681], &[8.0], false, &|p: &Progress| {
+            seen.lock().unwrap().push((p.evaluated, p.total, p.best.as_ref().map(|b| b.n)));
+        });
+        let seen = seen.into_inner().unwrap();
+        let total = Some(CHUNK_SIZE + 1);
+        assert_eq!(seen.first().unwrap(), &(0, total, None));
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen.last().unwrap().0, CHUNK_SIZE + 1);
+        assert!(seen.last().unwrap().2.is_some());
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let rows = sweep(&[128, 256], &[7681], &[8.0], false);
+        let csv = to_csv(&rows);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_COLUMNS);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("128,7681,12.9071,8,primal_usvp,"));
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&rows)).unwrap();
+        assert_eq!(json[1]["n"], 256);
+        assert_eq!(json[1]["attack"], "primal_usvp");
+        assert_eq!(json[1]["provenance"]["cost_model"], rows[1].provenance.cost_model.as_str());
+        assert_eq!(json[1]["provenance"]["attacks"][0], "primal_usvp");
+        assert_eq!(json[1]["warnings"], serde_json::json!([]));
+        let narrow: serde_json::Value = serde_json::from_str(&to_json(&sweep(&[256], &[7681], &[0.5], false))).unwrap();
+        assert_eq!(narrow[0]["warnings"][0]["code"], "small_sigma");
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let path = std::env::temp_dir().join(format!("cryptoparam-ckpt-{}.txt", std::process::id()));
+        let full = sweep(&[128, 256], &[7681], &[3.19, 8.0], false);
+
+        // Simulate an interrupted run that only got through n = 128
+        sweep_checkpointed(&[128], &[7681], &[3.19, 8.0], false, None, &path, false, &no_progress).unwrap();
+        let resumed =
+            sweep_checkpointed(&[128, 256], &[7681], &[3.19, 8.0], false, None, &path, true, &no_progress)
+                .unwrap();
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        let other_model = crate::cost_model::by_name("matzov22").ok();
+        assert!(Checkpoint::resume(&path, false, other_model).is_err());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines, 1 + 4);
+        assert_eq!(resumed.len(), full.len());
+        for (a, b) in resumed.iter().zip(&full) {
+            assert_eq!((a.n, a.q, a.sigma, a.beta, a.m), (b.n, b.q, b.sigma, b.beta, b.m));
+        }
+        assert!(Checkpoint::resume(&path, true, None).is_err());
+    }
+}
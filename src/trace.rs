@@ -0,0 +1,117 @@
+//! Optimization traces
+//!
+//! The primal attack minimises β over the number of samples m. A trace
+//! records every m the optimizer considered with its β and cost, so the
+//! position of the optimum and the flatness around it can be inspected.
+
+use crate::{bkz_cost, estimate_core, primal_beta_scaled, sample_range, SecurityEstimate};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+
+/// One candidate of the (m, β) search
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", get_all))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchStep {
+    pub m: usize,
+    /// Lattice dimension m + n
+    pub d: usize,
+    pub beta: usize,
+    pub bits: f64,
+}
+
+#[cfg(feature = "python")]
+impl SearchStep {
+    pub(crate) fn from_py_dict(d: &PyDict) -> PyResult<Self> {
+        Ok(Self {
+            m: crate::dict_item(d, "m")?,
+            d: crate::dict_item(d, "d")?,
+            beta: crate::dict_item(d, "beta")?,
+            bits: crate::dict_item(d, "bits")?,
+        })
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SearchStep {
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("m", self.m)?;
+        d.set_item("d", self.d)?;
+        d.set_item("beta", self.beta)?;
+        d.set_item("bits", self.bits)?;
+        Ok(d)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SearchStep(m={}, d={}, beta={}, bits={:.1})", self.m, self.d, self.beta, self.bits)
+    }
+}
+
+/// Every sample count the primal-uSVP optimizer considers, in search order.
+/// Counts for which no block size can succeed are skipped, as in the search.
+pub fn primal_usvp_trace(n: usize, log2_q: f64, sigma: f64, sieving: bool) -> Vec<SearchStep> {
+    primal_usvp_trace_with(n, log2_q, sigma, 0.0, None, sieving)
+}
+
+/// [`primal_usvp_trace`] of the embedding with the secret scaled by
+/// e^log_nu and at most `max_samples` samples
+pub fn primal_usvp_trace_with(
+    n: usize,
+    log2_q: f64,
+    sigma: f64,
+    log_nu: f64,
+    max_samples: Option<usize>,
+    sieving: bool,
+) -> Vec<SearchStep> {
+    let log_q = log2_q * std::f64::consts::LN_2;
+    let log_sigma = sigma.ln();
+    sample_range(n, max_samples)
+        .filter_map(|m| {
+            primal_beta_scaled(n, m, log_q, log_sigma, log_nu).map(|beta| SearchStep { m, d: m + n, beta, bits: bkz_cost(beta, sieving) })
+        })
+        .collect()
+}
+
+/// [`estimate_core`] carrying the full search trace
+pub fn estimate_core_traced(n: usize, q: u64, sigma: f64, sieving: bool) -> SecurityEstimate {
+    let mut r = estimate_core(n, q, sigma, sieving);
+    r.trace = Some(primal_usvp_trace(n, r.log2_q, sigma, sieving));
+    r
+}
+
+/// CSV with header `m,d,beta,bits`; infinite bits are `inf`
+pub fn to_csv(steps: &[SearchStep]) -> String {
+    let mut out = String::from("m,d,beta,bits\n");
+    for s in steps {
+        out.push_str(&format!("{},{},{},{:.3}\n", s.m, s.d, s.beta, s.bits));
+    }
+    out
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_contains_optimum() {
+        let r = estimate_core_traced(256, 7681, 8.0, false);
+        let trace = r.trace.as_ref().unwrap();
+        let best = trace.iter().min_by_key(|s| s.beta).unwrap();
+        assert_eq!(Some(best.beta), r.beta);
+        // The search keeps the first m reaching the minimum
+        assert_eq!(best.m, r.m);
+        assert!(trace.windows(2).all(|w| w[0].m < w[1].m));
+        assert!(estimate_core(256, 7681, 8.0, false).trace.is_none());
+        let csv = to_csv(&trace[..2]);
+        assert_eq!(csv.lines().next(), Some("m,d,beta,bits"));
+        assert_eq!(csv.lines().count(), 3);
+    }
+}
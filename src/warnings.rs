@@ -0,0 +1,128 @@
+//! Structured warnings attached to estimates
+//!
+//! Flags results that deserve a second look instead of returning
+//! questionable numbers silently.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyDict;
+
+/// Error widths below this may admit Arora–Ge style algebraic attacks
+pub const SMALL_SIGMA: f64 = 3.0;
+
+/// Largest modulus f64 represents exactly
+pub const F64_EXACT_Q: u64 = 1 << 53;
+
+/// One caveat about an estimate; `EstimateWarning` in Python, where
+/// `Warning` is a builtin
+#[cfg_attr(feature = "python", pyclass(module = "cryptoparam", name = "EstimateWarning", get_all))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Warning {
+    /// Stable machine-readable identifier, e.g. "small_sigma"
+    pub code: String,
+    pub message: String,
+}
+
+impl Warning {
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        Self { code: code.to_string(), message: message.into() }
+    }
+
+    #[cfg(feature = "python")]
+    pub(crate) fn from_py_dict(d: &PyDict) -> PyResult<Self> {
+        Ok(Self { code: crate::dict_item(d, "code")?, message: crate::dict_item(d, "message")? })
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Warning {
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("code", &self.code)?;
+        d.set_item("message", &self.message)?;
+        Ok(d)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("EstimateWarning({}: {})", self.code, self.message)
+    }
+
+    fn __str__(&self) -> String {
+        self.message.clone()
+    }
+}
+
+/// Issue the warnings of `rows` through Python's `warnings.warn` as
+/// `CryptoParamWarning`, so they reach log pipelines (see
+/// `logging.captureWarnings`). Each code is issued once per call, with a
+/// count when several rows share it.
+#[cfg(feature = "python")]
+pub(crate) fn emit<'a>(py: Python, rows: impl IntoIterator<Item = &'a crate::SecurityEstimate>) -> PyResult<()> {
+    let mut seen: Vec<(&Warning, usize)> = Vec::new();
+    for w in rows.into_iter().flat_map(|r| &r.warnings) {
+        match seen.iter_mut().find(|(s, _)| s.code == w.code) {
+            Some((_, count)) => *count += 1,
+            None => seen.push((w, 1)),
+        }
+    }
+    let category = py.get_type::<crate::errors::CryptoParamWarning>();
+    for (w, count) in seen {
+        let message = match count {
+            1 => format!("{}: {}", w.code, w.message),
+            _ => format!("{}: {} (and {} more estimates)", w.code, w.message, count - 1),
+        };
+        PyErr::warn(py, category, &message, 1)?;
+    }
+    Ok(())
+}
+
+/// Caveats for a primal-uSVP estimate with the given outcome
+pub fn lwe_warnings(n: usize, sigma: f64, beta: Option<usize>, m: usize) -> Vec<Warning> {
+    let mut w = Vec::new();
+    if sigma < SMALL_SIGMA {
+        w.push(Warning::new(
+            "small_sigma",
+            format!("σ = {} < {}: Arora–Ge style attacks may apply given many samples (not modeled)", sigma, SMALL_SIGMA),
+        ));
+    }
+    if beta.is_some() && m + 1 >= crate::MAX_SAMPLES_FACTOR * n {
+        w.push(Warning::new(
+            "m_capped",
+            format!("optimal sample count hit the search cap m < {}n; more samples may be cheaper", crate::MAX_SAMPLES_FACTOR),
+        ));
+    }
+    if beta.is_none() {
+        w.push(Warning::new(
+            "no_attack",
+            format!("no block size below {} succeeds; security reported as infinite", crate::MAX_BETA),
+        ));
+    }
+    w
+}
+
+/// Caveat for a modulus too wide for exact f64 arithmetic
+pub fn precision_warning(q: u64) -> Option<Warning> {
+    crate::error::exact_log2_q(q).err().map(|e| Warning::new("f64_precision", e.to_string()))
+}
+
+// ============================================================================
+// Rust Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::estimate_core;
+
+    #[test]
+    fn test_warning_codes() {
+        let codes = |r: &crate::SecurityEstimate| r.warnings.iter().map(|w| w.code.clone()).collect::<Vec<_>>();
+        assert!(codes(&estimate_core(256, 7681, 8.0, false)).is_empty());
+        assert_eq!(codes(&estimate_core(256, 7681, 2.5, false)), vec!["small_sigma"]);
+        let big = estimate_core(64, u64::MAX, 3.2, false);
+        assert!(codes(&big).contains(&"f64_precision".to_string()));
+        assert!(codes(&big).contains(&"no_attack".to_string()));
+    }
+}
@@ -1,14 +1,60 @@
 """Tests for CryptoParam Python bindings."""
 
+import asyncio
 import pytest
+import warnings
+import cryptoparam.presets
 from cryptoparam import (
     estimate_lwe, 
+    estimate_lwe_batch,
+    estimate_many,
+    Estimator,
+    config,
+    estimate_async,
+    search_async,
+    CryptoParamWarning,
+    CryptoParamError,
+    InvalidParameters,
+    SearchFailed,
+    UnsupportedDistribution,
+    attacks,
+    register_attack,
+    unregister_attack,
+    AttackCost,
+    bgv_params,
+    ckks_params,
+    curve_log_q,
+    curve_n,
+    descriptor_schema,
     estimate, 
+    estimate_small_secret,
+    failure_probability,
+    from_lattice_estimator,
     LweParams, 
     SecurityEstimate,
     get_delta, 
     get_beta, 
-    get_bkz_cost
+    get_bkz_cost,
+    he_standard_check,
+    he_standard_max_log_q,
+    html_report,
+    import_openfhe,
+    import_seal,
+    load_descriptor,
+    modulus_chain,
+    optimize,
+    sage_script,
+    search_joint,
+    search_n,
+    search,
+    snap,
+    solve,
+    sweep,
+    to_latex,
+    to_markdown,
+    to_pandas,
+    verify,
+    SchemeDescriptor,
 )
 
 
@@ -30,6 +76,314 @@ class TestBasicAPI:
         assert r.m > 0
         assert r.attack == "primal_usvp"
     
+    def test_estimate_provenance(self):
+        p = estimate_lwe(256, 7681, 8.0, sieving=True).provenance
+        assert p.cost_model == "sieving-0.265"
+        assert p.attacks == ["primal_usvp"]
+        assert p.timestamp > 0
+        assert estimate_lwe(256, 7681, 8.0).to_dict()["provenance"]["cost_model"] == "core-svp-0.292"
+    
+    def test_estimate_equality_and_ordering(self):
+        weak = estimate_lwe(256, 7681, 8.0)
+        strong = estimate_lwe(512, 12289, 3.0)
+        assert weak == estimate_lwe(256, 7681, 8.0)
+        assert weak != strong
+        assert sorted([strong, weak]) == [weak, strong]
+        assert len({weak, strong, estimate_lwe(256, 7681, 8.0)}) == 2
+        assert LweParams(256, 7681, 8.0) == LweParams(256, 7681, 8.0)
+        assert {LweParams(256, 7681, 8.0): 1}[LweParams(256, 7681, 8.0)] == 1
+        assert weak != "not an estimate"
+    
+    def test_repr_html(self):
+        html = estimate_lwe(256, 7681, 8.0)._repr_html_()
+        assert "<table>" in html and "primal_usvp" in html
+        table = sweep([256, 512], [7681], [8.0])
+        assert table._repr_html_().count("<tr>") == 3
+    
+    def test_to_pandas(self):
+        pd = pytest.importorskip("pandas")
+        df = sweep([256, 512], [7681], [8.0]).to_pandas()
+        assert isinstance(df, pd.DataFrame)
+        assert list(df["n"]) == [256, 512]
+        assert len(to_pandas([estimate_lwe(256, 7681, 8.0)])) == 1
+
+    def test_to_pandas_row_per_attack(self):
+        import sys, types
+        # DataFrame(columns) hands back the columns, so this runs without pandas
+        real = sys.modules.get("pandas")
+        sys.modules["pandas"] = types.SimpleNamespace(DataFrame=lambda columns: columns)
+        register_attack("toy", lambda p, cfg: p.n / 4)
+        try:
+            df = sweep([256, 512], [7681], [8.0]).to_pandas()
+        finally:
+            unregister_attack("toy")
+            if real is None:
+                del sys.modules["pandas"]
+            else:
+                sys.modules["pandas"] = real
+        assert df["n"] == [256] * 4 + [512] * 4
+        assert df["attack"] == ["primal_usvp", "dual", "dual_hybrid", "toy"] * 2
+        assert df["classical_bits"][3::4] == [64.0, 128.0]
+        assert df["headline"] == [True, False, False, False] * 2
+    
+    def test_to_parquet(self):
+        pq = pytest.importorskip("pyarrow.parquet")
+        import os, tempfile
+        table = sweep([256, 512], [7681], [8.0])
+        assert table.to_arrow().num_rows == 2
+        path = os.path.join(tempfile.mkdtemp(), "sweep.parquet")
+        table.to_parquet(path)
+        assert pq.read_table(path).column("n").to_pylist() == [256, 512]
+    
+    def test_plot_helpers(self):
+        matplotlib = pytest.importorskip("matplotlib")
+        matplotlib.use("Agg")
+        import cryptoparam.plot
+        ax = cryptoparam.plot.plot_security_vs_n(7681, 8.0, 200, 300, target_bits=80)
+        assert ax.get_xlabel() == "n"
+        frontier = optimize([7681, 12289], [3.0], 80, top=3)
+        ax = cryptoparam.plot.plot_tradeoff(frontier)
+        assert ax.get_ylabel() == "security (bits)"
+    
+    def test_estimate_trace(self):
+        r = estimate_lwe(256, 7681, 8.0, trace=True)
+        best = min(r.trace, key=lambda s: s.beta)
+        assert (best.m, best.beta) == (r.m, r.beta)
+        assert estimate_lwe(256, 7681, 8.0).trace is None
+        back = SecurityEstimate.from_dict(r.to_dict())
+        assert len(back.trace) == len(r.trace)
+    
+    def test_attacks(self):
+        costs = attacks(256, 7681, 8.0)
+        r = estimate_lwe(256, 7681, 8.0)
+        assert [c.attack for c in costs] == [r.attack, "dual", "dual_hybrid"]
+        assert costs[0].bits == r.classical_bits
+        assert costs[0].samples == r.m
+        assert costs[0].memory_bits > 0
+
+    def test_explain(self):
+        r = estimate_lwe(512, 12289, 3.2)
+        text = r.explain()
+        assert f"d = m + n = {r.m} + 512 = {r.d}" in text
+        assert "target δ₀" in text
+
+    def test_small_secret(self):
+        r = estimate_small_secret(512, 12289, 3.2, "ternary")
+        assert r.secret == "ternary"
+        assert r.to_dict()["secret"] == "ternary"
+        assert estimate_small_secret(512, 12289, 3.2, "gaussian").secret is None
+        with pytest.raises(ValueError):
+            estimate_small_secret(512, 12289, 3.2, "sparse:1000")
+
+    def test_bounded_samples(self):
+        free = estimate_lwe(512, 12289, 3.2)
+        r = estimate_lwe(512, 12289, 3.2, samples=free.m // 2, trace=True)
+        assert r.m <= free.m // 2 and r.max_samples == free.m // 2
+        assert max(s.m for s in r.trace) <= free.m // 2
+        assert estimate_lwe(512, 12289, 3.2).max_samples is None
+
+    def test_estimate_batch(self):
+        r = estimate_lwe_batch([512, 1024], 12289, (3.2, 8.0))
+        assert list(r["n"]) == [512, 1024]
+        assert list(r["sigma"]) == [3.2, 8.0]
+        assert r["classical_bits"][1] == estimate_lwe(1024, 12289, 8.0).classical_bits
+        with pytest.raises(ValueError):
+            estimate_lwe_batch([512, 1024], [12289, 3329, 7681], 3.2)
+        with pytest.raises(ValueError):
+            estimate_lwe_batch([512, 0], 12289, 3.2)
+
+    def test_estimate_many(self):
+        params = [(256 + 64 * i, 12289, 3.2) for i in range(20)] + [LweParams(512, 7681, 8.0)]
+        rs = estimate_many(params, n_threads=2)
+        assert [r.n for r in rs] == [p[0] for p in params[:-1]] + [512]
+        assert rs[3].classical_bits == estimate_lwe(448, 12289, 3.2).classical_bits
+        with pytest.raises(ValueError):
+            estimate_many([(0, 12289, 3.2)])
+        with pytest.raises(TypeError):
+            estimate_many(["kyber512"])
+
+    def test_estimator(self):
+        est = Estimator(cost_model="matzov22", quantum=True, m_max=400)
+        r = est.estimate(LweParams(512, 12289, 3.2))
+        assert r.quantum_bits is not None and r.max_samples == 400
+        assert est.estimate((512, 12289, 3.2)).classical_bits == r.classical_bits
+        assert est.estimate_lwe(512, 12289, 3.2).classical_bits == r.classical_bits
+        assert len(est.estimate_many([(256, 7681, 8.0), (512, 12289, 3.2)])) == 2
+        assert est.cost_model == "matzov22" and est.attacks == ["primal_usvp", "dual", "dual_hybrid"]
+        assert "quantum=True" in repr(est)
+        assert Estimator().estimate((256, 7681, 8.0)).classical_bits == estimate_lwe(256, 7681, 8.0).classical_bits
+        with pytest.raises(ValueError):
+            Estimator(attacks=["bkw"])
+        with pytest.raises(ValueError):
+            Estimator(cost_model="fast")
+
+    def test_exception_hierarchy(self):
+        for cls in (InvalidParameters, UnsupportedDistribution, SearchFailed):
+            assert issubclass(cls, CryptoParamError)
+        assert issubclass(CryptoParamError, ValueError)
+        with pytest.raises(InvalidParameters):
+            estimate_lwe(0, 12289, 3.2)
+        with pytest.raises(UnsupportedDistribution):
+            estimate_small_secret(512, 12289, 3.2, "uniform")
+        with pytest.raises(UnsupportedDistribution):
+            Estimator(secret="uniform")
+        with pytest.raises(SearchFailed):
+            search_n(12289, 3.2, 100000.0)
+
+    def test_every_library_error_is_a_cryptoparam_error(self):
+        bad_arguments = [
+            lambda: search_n(7681, 8.0, 60, margin="lots"),
+            lambda: solve("n", 60, q=7681, sigma=8.0, margin="lots"),
+            lambda: modulus_chain(8192, 3, scale_bits=100),
+            lambda: bgv_params(1, 3),
+            lambda: import_seal('{"poly_modulus_degree": 8192}'),
+            lambda: cryptoparam.presets.export("xml"),
+        ]
+        for call in bad_arguments:
+            with pytest.raises(InvalidParameters):
+                call()
+        for call in (lambda: bgv_params(2, 1, target_bits=1e6), lambda: ckks_params(60.0, 1024, 2)):
+            with pytest.raises(SearchFailed):
+                call()
+        try:
+            optimize([12289], [3.2], 128.0, template="nonsense")
+        except CryptoParamError:
+            pass
+        else:
+            raise AssertionError("optimize accepted a bad template")
+
+    def test_params_copy_update(self):
+        p = LweParams(n=512, q=12289, sigma=3.2)
+        assert p.with_n(1024) == LweParams(1024, 12289, 3.2)
+        assert p.with_q(7681).q == 7681 and p.with_sigma(8.0).sigma == 8.0
+        assert p.replace(n=768, sigma=4.0) == LweParams(768, 12289, 4.0)
+        assert p.replace() == p and p.n == 512
+        with pytest.raises(InvalidParameters):
+            p.replace(q=1)
+        with pytest.raises(TypeError):
+            p.replace(768)
+
+    def test_config_context_manager(self):
+        plain = estimate_lwe(512, 12289, 3.2)
+        with config(model="matzov22", quantum=True) as c:
+            assert (c.cost_model, c.quantum) == ("matzov22", True)
+            r = estimate_lwe(512, 12289, 3.2)
+            assert r.provenance.cost_model == "matzov22"
+            assert r.quantum_bits is not None
+            with config(quantum=False):
+                inner = sweep([512], [12289], [3.2])[0]
+                assert (inner.provenance.cost_model, inner.quantum_bits) == ("matzov22", None)
+            assert estimate_many([(512, 12289, 3.2)])[0].quantum_bits is not None
+        after = estimate_lwe(512, 12289, 3.2)
+        assert (after.classical_bits, after.quantum_bits) == (plain.classical_bits, None)
+        with pytest.raises(InvalidParameters):
+            config(model="nope")
+    
+    def test_async_api(self):
+        async def main():
+            r, found = await asyncio.gather(estimate_async(512, 12289, 3.2), search_async(7681, 8.0, 60))
+            with pytest.raises(InvalidParameters):
+                await estimate_async(0, 12289, 3.2)
+            return r, found
+        r, found = asyncio.run(main())
+        assert r.classical_bits == estimate_lwe(512, 12289, 3.2).classical_bits
+        assert found.n == search_n(7681, 8.0, 60).n
+        with pytest.raises(RuntimeError):
+            estimate_async(512, 12289, 3.2)
+    
+    def test_register_attack(self):
+        seen = []
+        def toy(params, config):
+            seen.append((params.n, config["cost_model"]))
+            return AttackCost("ignored", params.n / 8, beta=100, d=params.n)
+        register_attack("toy", toy)
+        try:
+            costs = attacks(512, 12289, 3.2)
+            assert [c.attack for c in costs] == ["primal_usvp", "dual", "dual_hybrid", "toy"]
+            assert costs[3].bits == 64.0 and costs[2].memory_bits > 0
+            r = Estimator(quantum=True).estimate((512, 12289, 3.2))
+            assert (r.attack, r.classical_bits, r.beta) == ("toy", 64.0, 100)
+            assert r.quantum_bits is not None
+            primal_only = Estimator(attacks=["primal_usvp"]).estimate_many([(512, 12289, 3.2)])[0]
+            assert primal_only.attack == "primal_usvp"
+            assert seen[-1] == (512, "core-svp")
+            register_attack("toy", lambda p, cfg: 1e6)
+            assert Estimator(attacks=["toy"]).estimate_lwe(512, 12289, 3.2).attack == "toy"
+            register_attack("broken", lambda p, cfg: "many")
+            with pytest.raises(TypeError):
+                attacks(512, 12289, 3.2)
+        finally:
+            unregister_attack("toy")
+            unregister_attack("broken")
+        assert not unregister_attack("toy")
+        with pytest.raises(InvalidParameters):
+            register_attack("primal_usvp", toy)
+        with pytest.raises(ValueError):
+            Estimator(attacks=["toy"])
+    
+    def test_dict_params(self):
+        r = estimate({"n": 256, "q": 7681, "sigma": 8})
+        assert r.classical_bits == estimate_lwe(256, 7681, 8.0).classical_bits
+        assert Estimator().estimate({"n": 256, "q": 7681, "sigma": 8.0}).n == 256
+        assert estimate_many([{"n": 256, "q": 7681, "sigma": 8.0}])[0].n == 256
+        with pytest.raises(InvalidParameters, match="unknown key 'sgima'"):
+            estimate({"n": 256, "q": 7681, "sgima": 8})
+        with pytest.raises(InvalidParameters, match="need 'sigma'"):
+            estimate({"n": 256, "q": 7681})
+        with pytest.raises(TypeError, match="'q' must be an int"):
+            estimate({"n": 256, "q": "7681", "sigma": 8})
+        with pytest.raises(InvalidParameters):
+            estimate({"n": 0, "q": 7681, "sigma": 8})
+    
+    def test_exact_sigma_inputs(self):
+        from decimal import Decimal
+        from fractions import Fraction
+        assert LweParams(256, 7681, "3.19").sigma == 3.19
+        assert LweParams(256, 7681, Decimal("3.19")).sigma == 3.19
+        assert LweParams(256, 7681, Fraction(319, 100)).sigma == 3.19
+        assert estimate_lwe(256, 7681, "16/5").sigma == 3.2
+        assert sweep([256], [7681], ["8", Fraction(8)])[1].sigma == 8.0
+        assert search_n(7681, "8.0", 60).n == search_n(7681, 8.0, 60).n
+        assert LweParams(256, 7681, 8.0).replace(sigma="3.19").sigma == 3.19
+        assert estimate({"n": 256, "q": 7681, "sigma": "3.19"}).sigma == 3.19
+        with pytest.raises(InvalidParameters, match="invalid sigma"):
+            estimate_lwe(256, 7681, "three")
+        with pytest.raises(TypeError, match="sigma must be a real number"):
+            estimate_lwe(256, 7681, [3.19])
+        for sigma in (float("nan"), float("inf"), "1e400", "nan", "inf"):
+            with pytest.raises(InvalidParameters, match="invalid sigma"):
+                estimate_lwe(256, 7681, sigma)
+            with pytest.raises(InvalidParameters, match="invalid sigma"):
+                LweParams(512, 12289, sigma)
+    
+    def test_copy_and_deepcopy(self):
+        import copy
+        p = LweParams(256, 7681, 8.0)
+        assert copy.copy(p) == p and copy.deepcopy(p) == p
+        r = estimate_lwe(256, 7681, 2.5, trace=True)
+        for c in (copy.copy(r), copy.deepcopy({"r": r})["r"]):
+            assert c is not r
+            assert c.to_dict() == r.to_dict()
+    
+    def test_python_warnings(self):
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter("always")
+            estimate_lwe(256, 7681, 8.0)
+            assert caught == []
+            estimate_lwe(256, 7681, 2.5)
+            sweep([256, 512], [7681], [2.5])
+        assert [w.category for w in caught] == [CryptoParamWarning, CryptoParamWarning]
+        assert str(caught[0].message).startswith("small_sigma: ")
+        assert "(and 1 more estimates)" in str(caught[1].message)
+        assert issubclass(CryptoParamWarning, UserWarning)
+
+    def test_estimate_warnings(self):
+        assert estimate_lwe(256, 7681, 8.0).warnings == []
+        w = estimate_lwe(256, 7681, 2.5).warnings
+        assert [x.code for x in w] == ["small_sigma"]
+        assert "Arora" in w[0].message
+        assert estimate_lwe(256, 7681, 2.5).to_dict()["warnings"][0]["code"] == "small_sigma"
+    
     def test_estimate_with_params_object(self):
         params = LweParams(n=256, q=7681, sigma=8.0)
         r = estimate(params)
@@ -41,6 +395,35 @@ class TestBasicAPI:
         # Same beta, different cost
         assert r_core.beta == r_sieve.beta
         assert r_sieve.classical_bits < r_core.classical_bits
+    
+    def test_dict_roundtrip(self):
+        import json
+        r = estimate_lwe(256, 7681, 8.0)
+        d = r.to_dict()
+        assert d["beta"] == r.beta and d["attack"] == "primal_usvp"
+        back = SecurityEstimate.from_dict(json.loads(json.dumps(d)))
+        assert (back.n, back.q, back.beta, back.log2_q) == (r.n, r.q, r.beta, r.log2_q)
+        p = LweParams.from_dict({"n": 512, "q": 12289, "sigma": 3.19, "note": "x"})
+        assert p.to_dict() == {"n": 512, "q": 12289, "sigma": 3.19}
+        with pytest.raises(KeyError):
+            LweParams.from_dict({"n": 512, "q": 12289})
+        with pytest.raises(ValueError):
+            LweParams.from_dict({"n": 0, "q": 12289, "sigma": 3.19})
+    
+    def test_pickle_roundtrip(self):
+        import pickle
+        r = estimate_lwe(256, 7681, 8.0)
+        back = pickle.loads(pickle.dumps(r))
+        assert isinstance(back, SecurityEstimate)
+        assert back.to_dict() == r.to_dict()
+        p = pickle.loads(pickle.dumps(LweParams(512, 12289, 3.19)))
+        assert (p.n, p.q, p.sigma) == (512, 12289, 3.19)
+    
+    def test_named_constructors(self):
+        p = LweParams.kyber512_flattened()
+        assert (p.n, p.q) == (512, 3329)
+        assert LweParams.tfhe_lwe_default().n == 630
+        assert LweParams.frodo640().q == 2**15
 
 
 class TestMonotonicity:
@@ -50,7 +433,7 @@ class TestMonotonicity:
         prev = 0
         for n in [64, 128, 256, 512, 1024]:
             r = estimate_lwe(n, 12289, 8.0)
-            if r.beta < 10000:
+            if r.beta is not None:
                 assert r.classical_bits > prev
                 prev = r.classical_bits
     
@@ -58,7 +441,7 @@ class TestMonotonicity:
         prev = float('inf')
         for sigma in [2.0, 4.0, 8.0, 16.0, 32.0]:
             r = estimate_lwe(256, 7681, sigma)
-            if r.beta < 10000:
+            if r.beta is not None:
                 assert r.classical_bits < prev
                 prev = r.classical_bits
 
@@ -139,6 +522,488 @@ class TestRepr:
         assert "7681" in s
 
 
+class TestSearch:
+    """Test inverse parameter search."""
+    
+    def test_search_n_reaches_target(self):
+        r = search_n(7681, 8.0, 60)
+        assert r.estimate.classical_bits >= 60
+        assert r.margin == "none"
+    
+    def test_search_n_margin_recorded(self):
+        plain = search_n(7681, 8.0, 60)
+        r = search_n(7681, 8.0, 60, margin="+16")
+        assert r.n > plain.n
+        assert r.effective_target_bits == 76
+        assert r.margin == "+16 bits"
+        assert "+16 bits" in repr(r)
+    
+    def test_solve_matches_search_n(self):
+        assert solve("n", 60, q=7681, sigma=8.0).n == search_n(7681, 8.0, 60).n
+    
+    def test_solve_sigma(self):
+        r = solve("sigma", 60, n=256, q=7681)
+        assert r.estimate.classical_bits >= 60
+    
+    def test_solve_hamming_weight(self):
+        r = solve("h", 300, n=1024, q=12289, sigma=3.2)
+        assert r.estimate.secret == f"sparse:{r.h}" and r.estimate.classical_bits >= 300
+        assert solve("n", 60, q=7681, sigma=8.0).h is None
+        assert search(300, fix={"n": 1024, "q": 12289, "sigma": 3.2}, vary="h").result.h == r.h
+    
+    def test_solve_missing_fixed_param_raises(self):
+        with pytest.raises(ValueError):
+            solve("n", 60, q=7681)
+    
+    def test_search_wrapper(self):
+        r = search(target_bits=60, fix={"q": 7681, "sigma": 8.0})
+        assert r.params == LweParams(search_n(7681, 8.0, 60).n, 7681, 8.0)
+        assert r.vary == "n" and r.margin_bits == r.bits - 60 >= 0
+        assert 0 < len(r.runner_ups) <= 3
+        assert all(u.n > r.params.n for u in r.runner_ups)
+        assert r.to_dict()["runner_ups"][0][0] == r.runner_ups[0].n
+        assert repr(r).startswith("SearchOutcome(vary='n', params=LweParams(")
+        s = search(60, fix={"n": 256, "q": 7681}, vary="sigma", runner_ups=0, margin="+2")
+        assert s.runner_ups == [] and s.margin == "+2 bits"
+        assert s.params.sigma == solve("sigma", 60, n=256, q=7681, margin="+2").sigma
+        c = search(60, fix={"q": 7681, "sigma": 3.0}, constraints={"max_failure_log2": -128})
+        assert c.result.failure_log2 <= -128
+        with pytest.raises(InvalidParameters, match="fix has 'n'"):
+            search(60, fix={"n": 256, "q": 7681, "sigma": 8.0})
+        with pytest.raises(InvalidParameters, match="needs 'sigma'"):
+            search(60, fix={"q": 7681})
+        with pytest.raises(InvalidParameters, match="Invalid margin"):
+            search(60, fix={"q": 7681, "sigma": 8.0}, margin="lots")
+        with pytest.raises(InvalidParameters, match="Unknown search parameter"):
+            search(60, fix={"q": 7681, "sigma": 8.0}, vary="m")
+        with pytest.raises(InvalidParameters, match="unknown constraint"):
+            search(60, fix={"q": 7681, "sigma": 8.0}, constraints={"max_bytes": 10})
+        with pytest.raises(SearchFailed):
+            search(60, fix={"q": 7681, "sigma": 8.0}, constraints={"max_ciphertext_bytes": 1})
+    
+    def test_search_joint_failure_bound(self):
+        r = search_joint([7681, 12289, 2**16], [3.0], 80, max_failure_log2=-128)
+        assert r.estimate.classical_bits >= 80
+        assert r.failure_log2 <= -128
+        assert r.failure_log2 == failure_probability(r.n, r.q, r.sigma)
+
+    def test_search_joint_checkpoint_resume(self):
+        import os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "joint.ckpt")
+        full = search_joint([7681, 12289], [3.0], 80)
+        # An interrupted run that only got through the first modulus
+        search_joint([7681], [3.0], 80, checkpoint=path)
+        before = len(open(path).readlines())
+        r = search_joint([7681, 12289], [3.0], 80, checkpoint=path, resume=True)
+        assert (r.n, r.q) == (full.n, full.q)
+        assert len(open(path).readlines()) > before
+        with pytest.raises(IOError):
+            search_joint([7681], [3.0], 80, sieving=True, checkpoint=path, resume=True)
+    
+    def test_audit_trace(self):
+        r = search_n(7681, 8.0, 60, audit=True)
+        assert len(r.trace) > 5
+        assert any(t.n == r.n and t.accepted for t in r.trace)
+        assert r.trace_summary().startswith(f"{len(r.trace)} candidates")
+        assert search_n(7681, 8.0, 60).trace is None
+    
+    def test_invalid_margin_raises(self):
+        with pytest.raises(InvalidParameters):
+            search_n(7681, 8.0, 60, margin="lots")
+    
+    def test_snap_to_ntt_prime(self):
+        r = search_n(7681, 8.0, 60)
+        s = snap(r)
+        assert s.n >= r.n and s.n & (s.n - 1) == 0
+        assert s.q % (2 * s.n) == 1
+        assert s.delta_n == s.n - r.n
+        assert s.unconstrained.n == r.n
+    
+    def test_snap_custom_dims(self):
+        r = search_n(7681, 8.0, 60)
+        assert snap(r, ring_dims=[r.n + 7]).n == r.n + 7
+        with pytest.raises(ValueError):
+            snap(r, ring_dims=[r.n - 1])
+
+
+class TestModulusChain:
+    """Test RNS modulus chain builder."""
+    
+    def test_chain_layout(self):
+        c = modulus_chain(8192, 4, scale_bits=40, base_bits=60)
+        assert c.level_bits == [60, 40, 40, 40, 40]
+        assert c.special_bits == [60]
+        assert c.total_log_q == 280
+        assert c.estimate.log2_q == 280
+    
+    def test_chain_invalid_prime_size_raises(self):
+        with pytest.raises(ValueError):
+            modulus_chain(8192, 4, scale_bits=70)
+    
+    def test_ckks_params(self):
+        p = ckks_params(20, 4096, 5)
+        assert p.slots >= 4096
+        assert p.precision_bits >= 20
+        assert p.chain.depth == 5
+        assert p.chain.level_bits[1] == p.scale_bits
+        with pytest.raises(ValueError):
+            ckks_params(60, 4096, 5)
+
+    
+    def test_bgv_params(self):
+        p = bgv_params(17, 3, slots=4096)
+        assert p.n >= 4096
+        assert p.t % (2 * p.n) == 1
+        assert p.t.bit_length() == 17
+        assert p.log_q > bgv_params(17, 1, slots=4096).log_q
+
+
+
+class TestOptimize:
+    """Test cost-weighted optimization."""
+    
+    def test_optimize_returns_cheapest_first(self):
+        c = optimize([3329, 7681, 12289], [3.0, 8.0], 80, top=3)
+        assert len(c) == 3
+        assert c[0].cost <= c[1].cost <= c[2].cost
+        assert all(x.estimate.classical_bits >= 80 for x in c)
+    
+    def test_byte_budget_rejects_large(self):
+        free = optimize([3329, 7681, 12289], [3.0, 8.0], 80, top=10)
+        cap = min(c.ciphertext_bytes for c in free)
+        capped = optimize([3329, 7681, 12289], [3.0, 8.0], 80, top=10, max_ciphertext_bytes=cap)
+        assert 0 < len(capped) < len(free)
+        assert all(c.ciphertext_bytes <= cap for c in capped)
+    
+    def test_byte_budget_template(self):
+        c = optimize([3329], [3.0], 80, template="mlwe:2")[0]
+        assert c.ciphertext_bytes == 1.5 * c.n * 12 / 8
+        with pytest.raises(ValueError):
+            optimize([3329], [3.0], 80, template="ntru")
+
+
+
+class TestCurves:
+    """Test security curve generators."""
+    
+    def test_curve_n_sorted_and_bounded(self):
+        pts = curve_n(7681, 8.0, 200, 260)
+        ns = [p[0] for p in pts]
+        assert ns == sorted(ns)
+        assert ns[0] == 200 and ns[-1] == 260
+        assert pts[-1][2] == estimate_lwe(260, 7681, 8.0).beta
+    
+    def test_curve_log_q_range(self):
+        pts = curve_log_q(512, 3.19, 8, 16, max_points=64)
+        assert pts[0][0] == 8 and pts[-1][0] == 16
+        assert len(pts) <= 64
+
+
+class TestSweep:
+    """Test grid sweeps."""
+    
+    def test_sweep_rows_match_estimates(self):
+        t = sweep(range(128, 385, 128), [7681], [4.0, 8.0])
+        assert len(t) == 6
+        r = t[-1]
+        assert (r.n, r.q, r.sigma) == (384, 7681, 8.0)
+        assert r.beta == estimate_lwe(384, 7681, 8.0).beta
+    
+    def test_sweep_progress_callback(self):
+        calls = []
+        sweep(range(100, 200), [7681], [8.0], progress=lambda done, total, best: calls.append((done, total, best)))
+        assert calls[0][:2] == (0, 100)
+        assert calls[-1][:2] == (100, 100)
+        assert isinstance(calls[-1][2], SecurityEstimate)
+    
+    def test_sweep_progress_exception_propagates(self):
+        def boom(done, total, best):
+            raise RuntimeError("stop")
+        with pytest.raises(RuntimeError):
+            sweep([128], [7681], [8.0], progress=boom)
+    
+    def test_search_progress_callback(self):
+        calls = []
+        r = search_n(7681, 8.0, 60, progress=lambda done, total, best: calls.append((done, total, best)))
+        assert [c[0] for c in calls] == list(range(1, len(calls) + 1))
+        assert calls[-1][1] is None
+        assert calls[-1][2].n == r.n
+        calls = []
+        solve("sigma", 60, n=256, q=7681, progress=lambda *a: calls.append(a))
+        assert len(calls) > 5
+        calls = []
+        search_joint([7681, 12289], [3.0], 60, max_failure_log2=-40, progress=lambda *a: calls.append(a))
+        assert sorted(c[:2] for c in calls) == [(1, 2), (2, 2)]
+    
+    def test_search_progress_exception_propagates(self):
+        def boom(done, total, best):
+            raise RuntimeError("stop")
+        with pytest.raises(RuntimeError):
+            search_n(7681, 8.0, 60, progress=boom)
+    
+    def test_sweep_thread_count_does_not_change_results(self):
+        a = sweep(range(128, 257, 32), [7681], [8.0], n_threads=1)
+        b = sweep(range(128, 257, 32), [7681], [8.0], n_threads=4)
+        assert [r.beta for r in a] == [r.beta for r in b]
+    
+    def test_sweep_columns(self):
+        cols = sweep([256], [7681, 12289], [8.0]).columns()
+        assert cols["q"] == [7681, 12289]
+        assert len(cols["classical_bits"]) == 2
+    
+    def test_sweep_to_markdown(self):
+        md = sweep([128, 256], [7681], [8.0]).to_markdown().splitlines()
+        assert md[0] == "| n | log2 q | σ | attack | β | bits |"
+        assert len(md) == 4
+        named = to_markdown([estimate_lwe(256, 7681, 8.0)], names=["toy"])
+        assert named.splitlines()[2].startswith("| toy | 256 |")
+    
+    def test_to_latex(self):
+        r = estimate_lwe(256, 7681, 8.0)
+        tex = to_latex([r], names=["toy_set"], columns=["name", "n", "bits"]).splitlines()
+        assert tex[0] == "\\begin{tabular}{lrr}"
+        assert tex[4] == "toy\\_set & 256 & 73.0 \\\\"
+        assert "\\midrule" in to_latex([r])
+        with pytest.raises(ValueError):
+            to_latex([r], columns=["nope"])
+    
+    def test_html_report(self):
+        import os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "report.html")
+        html = html_report(256, 7681, 8.0, path=path)
+        assert html.startswith("<!DOCTYPE html>")
+        assert html.count("<svg") == 2
+        with open(path, encoding="utf-8") as f:
+            assert f.read() == html
+    
+    def test_sweep_to_csv(self):
+        lines = sweep([128, 256], [7681], [8.0]).to_csv().splitlines()
+        assert lines[0] == "n,q,log2_q,sigma,attack,beta,m,d,classical_bits"
+        assert len(lines) == 3
+        assert lines[2].startswith("256,7681,")
+
+
+class TestPresets:
+    """Test built-in parameter sets."""
+    
+    def test_kyber_descriptors(self):
+        p = cryptoparam.presets.kyber768
+        assert (p.n, p.k, p.q, p.eta) == (256, 3, 3329, 2)
+        assert p.scheme == "ML-KEM"
+        assert p.lwe_params().n == 768
+    
+    def test_kyber_estimate(self):
+        bits = [getattr(cryptoparam.presets, f"kyber{s}").estimate().classical_bits
+                for s in (512, 768, 1024)]
+        assert bits[0] < bits[1] < bits[2]
+    
+    def test_from_import(self):
+        from cryptoparam.presets import kyber512
+        assert kyber512.k == 2
+    
+    def test_dilithium_both_sides(self):
+        p = cryptoparam.presets.dilithium3
+        assert p.sis.n == 6 * 256 and p.sis.m == 12 * 256
+        assert p.estimate().classical_bits > 0
+        assert p.sis_estimate().beta is not None
+        assert cryptoparam.presets.kyber768.sis_estimate() is None
+    
+    def test_falcon_vs_kyber(self):
+        falcon = cryptoparam.presets.falcon512
+        assert falcon.problem == "NTRU"
+        kyber = cryptoparam.presets.kyber512
+        assert falcon.nist_level == kyber.nist_level == 1
+        assert falcon.estimate().n == 512
+    
+    def test_frodo_samples_and_table(self):
+        p = cryptoparam.presets.frodo976
+        assert p.samples == 984
+        assert p.error_cdf[-1] == 32767
+        assert abs(p.sigma - 2.3) < 0.02
+        assert cryptoparam.presets.kyber768.error_cdf is None
+    
+    def test_saber_lwr(self):
+        p = cryptoparam.presets.lightsaber
+        assert p.problem == "MLWR"
+        assert (p.q, p.p) == (2**13, 2**10)
+        assert abs(p.sigma - (63 / 12) ** 0.5) < 1e-12
+        assert cryptoparam.presets.saber.k == 3
+    
+    def test_legacy_group(self):
+        p = cryptoparam.presets.newhope1024
+        assert p.group == "legacy"
+        assert (p.n, p.q, p.eta) == (1024, 12289, 8)
+        assert cryptoparam.presets.lac128.q == 251
+        assert cryptoparam.presets.r5nd_1kem_0d.problem == "RLWR"
+    
+    def test_tfhe_lwe_and_glwe(self):
+        lwe = cryptoparam.presets.tfhe_rs_integer_lwe
+        glwe = cryptoparam.presets.tfhe_rs_integer_glwe
+        assert lwe.problem == "LWE" and glwe.problem == "GLWE"
+        assert lwe.scheme == glwe.scheme == "TFHE-rs integer"
+        assert glwe.estimate().log2_q == 64
+    
+    def test_registry(self):
+        names = [p.name for p in cryptoparam.presets.list()]
+        assert len(names) == len(set(names))
+        kem = cryptoparam.presets.list(scheme="ML-KEM")
+        assert [p.name for p in kem] == ["kyber512", "kyber768", "kyber1024"]
+        assert all(p.nist_level == 3 for p in cryptoparam.presets.list(nist_level=3))
+        assert all(p.group == "legacy" for p in cryptoparam.presets.list(group="legacy"))
+        assert cryptoparam.presets.get("Saber").k == 3
+        with pytest.raises(KeyError):
+            cryptoparam.presets.get("nope")
+    
+    def test_export(self):
+        import json
+        rows = json.loads(cryptoparam.presets.export())
+        assert len(rows) == len(cryptoparam.presets.list())
+        assert rows[0]["model"] == "core-svp"
+        csv = cryptoparam.presets.export("csv", sieving=True)
+        assert csv.splitlines()[0].startswith("name,scheme,group,")
+        with pytest.raises(ValueError):
+            cryptoparam.presets.export("xml")
+    
+    def test_nearest(self):
+        d = cryptoparam.presets.nearest(1000, 3329, 1.0, count=2)
+        assert len(d) == 2
+        assert d[0].preset.name == "kyber1024"
+        assert d[0].delta_n == -24
+        assert d[0].distance <= d[1].distance
+
+
+class TestHeStandard:
+    """Test the HomomorphicEncryption.org standard tables."""
+    
+    def test_max_log_q(self):
+        assert he_standard_max_log_q(8192) == 218
+        assert he_standard_max_log_q(8192, 192, "gaussian") == 154
+        assert he_standard_max_log_q(512) is None
+    
+    def test_check(self):
+        c = he_standard_check(8192, 219)
+        assert not c.table_ok
+        assert c.table_max_log_q == 218
+        assert c.estimate.log2_q == 219
+        with pytest.raises(ValueError):
+            he_standard_check(8192, 200, secret="binary")
+
+
+class TestVerify:
+    """Test scheme-descriptor verification."""
+    
+    def test_verify_toml(self):
+        import os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "kyber.toml")
+        with open(path, "w") as f:
+            f.write('name = "kyber768"\nproblem = "MLWE"\nn = 256\nk = 3\n'
+                    'q = 3329\neta = 2\nclaimed_bits = 128\n')
+        r = verify(path)
+        assert r.name == "kyber768"
+        assert r.margin_bits == r.estimated_bits - 128
+        assert r.passed == (r.margin_bits >= 0)
+        assert load_descriptor(path).k == 3
+    
+    def test_verify_json_fail(self):
+        import json, os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "toy.json")
+        with open(path, "w") as f:
+            json.dump({"n": 256, "q": 7681, "sigma": 8.0, "claimed_bits": 10000}, f)
+        r = verify(path)
+        assert not r.passed and r.margin_bits < 0
+    
+    def test_verify_model_and_category(self):
+        import os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "kyber.toml")
+        with open(path, "w") as f:
+            f.write('n = 256\nk = 3\nq = 3329\neta = 2\nclaimed_bits = 128\ncategory = 3\n')
+        r = verify(path, model="matzov22")
+        assert r.model == "matzov22" and r.claimed_category == 3
+        assert r.estimated_bits > verify(path).estimated_bits
+        with pytest.raises(ValueError, match="cost model"):
+            verify(path, model="nope")
+    
+    def test_schema_validation(self):
+        import json, os, tempfile
+        schema = json.loads(descriptor_schema())
+        assert "claimed_bits" in schema["required"]
+        path = os.path.join(tempfile.mkdtemp(), "bad.json")
+        with open(path, "w") as f:
+            json.dump({"n": 0, "q": 3329, "eta": 2, "claimed_bits": 128}, f)
+        with pytest.raises(ValueError, match="/n"):
+            load_descriptor(path)
+    
+    def test_verify_dict(self):
+        fields = {"name": "kyber768", "n": 256, "k": 3, "q": 3329, "eta": 2, "claimed_bits": 128}
+        r = verify(fields)
+        assert r.name == "kyber768"
+        d = SchemeDescriptor.from_dict(fields)
+        assert d.k == 3
+        assert verify(d).estimated_bits == r.estimated_bits
+        with pytest.raises(InvalidParameters, match="unknown key 'claimed'"):
+            verify({"n": 256, "q": 3329, "eta": 2, "claimed": 128})
+        with pytest.raises(InvalidParameters, match="/n"):
+            SchemeDescriptor.from_dict({"n": "256", "q": 3329, "eta": 2, "claimed_bits": 128})
+    
+    def test_copy_descriptor_and_report(self):
+        import copy
+        d = SchemeDescriptor.from_dict({"n": 256, "k": 3, "q": 3329, "eta": 2, "claimed_bits": 128})
+        assert repr(copy.deepcopy(d)) == repr(d)
+        report = d.verify()
+        clone = copy.deepcopy([report, report])
+        assert clone[0] is not report and repr(clone[0]) == repr(report)
+        assert [repr(a) for a in copy.copy(report).attacks] == [repr(a) for a in report.attacks]
+        assert repr(copy.copy(report.attacks[0])) == repr(report.attacks[0])
+    
+    def test_bad_descriptor_raises(self):
+        with pytest.raises(ValueError):
+            verify("/nonexistent.json")
+
+
+class TestHeImport:
+    """Test SEAL / OpenFHE parameter import."""
+    
+    def test_seal_json(self):
+        d = import_seal('{"scheme": "CKKS", "poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}')
+        assert d.n == 8192
+        assert d.log_q == 200.0
+        assert d.claimed_bits == 128
+        assert d.verify().claimed_bits == 128
+    
+    def test_openfhe_file(self):
+        import os, tempfile
+        path = os.path.join(tempfile.mkdtemp(), "ccparams.json")
+        with open(path, "w") as f:
+            f.write('{"ringDim": 16384, "multiplicativeDepth": 10, "securityLevel": "HEStd_192_classic"}')
+        d = import_openfhe(path)
+        assert d.n == 16384
+        assert d.log_q == 560.0
+        assert d.claimed_bits == 192
+    
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            import_seal('{"poly_modulus_degree": 8192, "coeff_modulus": []}')
+    
+    def test_lattice_estimator_roundtrip(self):
+        d = import_seal('{"poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}')
+        s = d.to_lattice_estimator()
+        assert s.startswith("LWE.Parameters(n=8192, q=2^200, ")
+        back = from_lattice_estimator(s, claimed_bits=128)
+        assert back.n == 8192
+        assert back.log_q == 200.0
+        assert back.name == d.name
+        with pytest.raises(ValueError):
+            from_lattice_estimator("LWE.Parameters(n=512)")
+    
+    def test_sage_script(self):
+        script = sage_script(512, 12289, 3.19)
+        assert "from estimator import *" in script
+        assert "lwe = LWE.Parameters(n=512, q=12289, " in script
+        assert 'mode="classical"' in script
+        d = import_seal('{"poly_modulus_degree": 8192, "coeff_modulus": [60, 40, 40, 60]}')
+        assert "q=2^200" in d.to_sage_script(sieving=True)
+
+
 class TestMatchesPythonMVP:
     """Ensure results match our pure Python implementation."""
     